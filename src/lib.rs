@@ -5,11 +5,18 @@ extern crate failure;
 extern crate futures;
 extern crate grpcio;
 extern crate hashbrown;
+extern crate memmap;
 extern crate protobuf;
 extern crate regex;
 
 pub mod protos;
 pub mod server;
 
-pub use protos::kv_server_grpc::create_kv_server;
-pub use server::{hash, Engine, HashEngine, HashEngineBuilder, HashScanner, Scanner, Server};
+pub use protos::{
+    kv_server_grpc::create_kv_server, kv_server_v2_grpc::create_kv_server as create_kv_server_v2,
+};
+pub use server::{
+    btree, hash, lsm_tree, BTreeEngine, BTreeEngineBuilder, BTreeScanner, Engine, HashEngine,
+    HashEngineBuilder, HashScanner, LSMTreeEngine, LSMTreeEngineBuilder, LSMTreeScanner,
+    MergeProgress, Order, RangeScanner, Scanner, Server, Stats, Transaction, Value, WriteBatch,
+};
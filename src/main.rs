@@ -5,32 +5,43 @@ use std::{
     io::{self, Read},
     sync::Arc,
     thread::spawn,
+    time::Duration,
 };
 // --- external ---
 use futures::{sync::oneshot, Future};
 use grpcio::{Environment, ServerBuilder};
 // --- custom ---
 use kv_server::{
-    create_kv_server,
-    hash::{MergePolicy, Options},
+    create_kv_server, create_kv_server_v2,
+    hash::{Durability, MergePolicy, MergeThresholds, Options},
     HashEngineBuilder, Server,
 };
 
 fn main() {
-    let service = create_kv_server(Server::new(
+    let kv_server = Server::new(
         HashEngineBuilder::new()
             .storage_dir("tests/data/test-grpc")
             .options(Options {
                 //            file_size_limit: 2,
                 file_size_limit: 5 * 0x100000,
                 keep_old_files: false,
-                merge_policy: MergePolicy::Test,
+                merge_policy: MergePolicy::Auto(MergeThresholds {
+                    window: Some((2, 5)),
+                    ..Default::default()
+                }),
+                truncate_torn_tail: true,
+                durability: Durability::GroupCommit {
+                    window: Duration::from_millis(1),
+                },
+                reap_interval: Some(Duration::from_secs(1)),
+                ..Default::default()
             })
             .build()
             .unwrap(),
-    ));
+    );
     let mut server = ServerBuilder::new(Arc::new(Environment::new(1)))
-        .register_service(service)
+        .register_service(create_kv_server(kv_server.clone()))
+        .register_service(create_kv_server_v2(kv_server))
         .bind("127.0.0.1", 23333)
         .build()
         .unwrap();
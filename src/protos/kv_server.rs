@@ -468,43 +468,2528 @@ impl ::protobuf::reflect::ProtobufValue for Response {
     }
 }
 
+#[derive(PartialEq, Clone, Default)]
+pub struct PutRequest {
+    // message fields
+    pub key: ::std::string::String,
+    pub value: ::std::string::String,
+    pub return_previous: bool,
+    pub ttl_ms: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PutRequest {
+    fn default() -> &'a PutRequest {
+        <PutRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PutRequest {
+    pub fn new() -> PutRequest {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // string value = 2;
+
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+
+    // bool return_previous = 3;
+
+
+    pub fn get_return_previous(&self) -> bool {
+        self.return_previous
+    }
+    pub fn clear_return_previous(&mut self) {
+        self.return_previous = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_return_previous(&mut self, v: bool) {
+        self.return_previous = v;
+    }
+
+    // uint64 ttl_ms = 4;
+
+
+    pub fn get_ttl_ms(&self) -> u64 {
+        self.ttl_ms
+    }
+    pub fn clear_ttl_ms(&mut self) {
+        self.ttl_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl_ms(&mut self, v: u64) {
+        self.ttl_ms = v;
+    }
+}
+
+impl ::protobuf::Message for PutRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.return_previous = tmp;
+                }
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttl_ms = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.value);
+        }
+        if self.return_previous != false {
+            my_size += 2;
+        }
+        if self.ttl_ms != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.ttl_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_string(2, &self.value)?;
+        }
+        if self.return_previous != false {
+            os.write_bool(3, self.return_previous)?;
+        }
+        if self.ttl_ms != 0 {
+            os.write_uint64(4, self.ttl_ms)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PutRequest {
+        PutRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key",
+                    |m: &PutRequest| { &m.key },
+                    |m: &mut PutRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    |m: &PutRequest| { &m.value },
+                    |m: &mut PutRequest| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "return_previous",
+                    |m: &PutRequest| { &m.return_previous },
+                    |m: &mut PutRequest| { &mut m.return_previous },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttl_ms",
+                    |m: &PutRequest| { &m.ttl_ms },
+                    |m: &mut PutRequest| { &mut m.ttl_ms },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PutRequest>(
+                    "PutRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PutRequest {
+        static mut instance: ::protobuf::lazy::Lazy<PutRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PutRequest,
+        };
+        unsafe {
+            instance.get(PutRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PutRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.return_previous = false;
+        self.ttl_ms = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PutRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PutRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct PutResponse {
+    // message fields
+    pub status: Status,
+    pub error: ::std::string::String,
+    pub existed: bool,
+    pub previous_value: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PutResponse {
+    fn default() -> &'a PutResponse {
+        <PutResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PutResponse {
+    pub fn new() -> PutResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // bool existed = 3;
+
+
+    pub fn get_existed(&self) -> bool {
+        self.existed
+    }
+    pub fn clear_existed(&mut self) {
+        self.existed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_existed(&mut self, v: bool) {
+        self.existed = v;
+    }
+
+    // string previous_value = 4;
+
+
+    pub fn get_previous_value(&self) -> &str {
+        &self.previous_value
+    }
+    pub fn clear_previous_value(&mut self) {
+        self.previous_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_previous_value(&mut self, v: ::std::string::String) {
+        self.previous_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_previous_value(&mut self) -> &mut ::std::string::String {
+        &mut self.previous_value
+    }
+
+    // Take field
+    pub fn take_previous_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.previous_value, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PutResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.existed = tmp;
+                }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.previous_value)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.existed != false {
+            my_size += 2;
+        }
+        if !self.previous_value.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.previous_value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.existed != false {
+            os.write_bool(3, self.existed)?;
+        }
+        if !self.previous_value.is_empty() {
+            os.write_string(4, &self.previous_value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PutResponse {
+        PutResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Status>>(
+                    "status",
+                    |m: &PutResponse| { &m.status },
+                    |m: &mut PutResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &PutResponse| { &m.error },
+                    |m: &mut PutResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "existed",
+                    |m: &PutResponse| { &m.existed },
+                    |m: &mut PutResponse| { &mut m.existed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "previous_value",
+                    |m: &PutResponse| { &m.previous_value },
+                    |m: &mut PutResponse| { &mut m.previous_value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PutResponse>(
+                    "PutResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PutResponse {
+        static mut instance: ::protobuf::lazy::Lazy<PutResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PutResponse,
+        };
+        unsafe {
+            instance.get(PutResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PutResponse {
+    fn clear(&mut self) {
+        self.status = Status::OK;
+        self.error.clear();
+        self.existed = false;
+        self.previous_value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PutResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PutResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct GetRequest {
+    // message fields
+    pub key: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetRequest {
+    fn default() -> &'a GetRequest {
+        <GetRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetRequest {
+    pub fn new() -> GetRequest {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GetRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetRequest {
+        GetRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key",
+                    |m: &GetRequest| { &m.key },
+                    |m: &mut GetRequest| { &mut m.key },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<GetRequest>(
+                    "GetRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GetRequest {
+        static mut instance: ::protobuf::lazy::Lazy<GetRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GetRequest,
+        };
+        unsafe {
+            instance.get(GetRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GetRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct GetResponse {
+    // message fields
+    pub status: Status,
+    pub error: ::std::string::String,
+    pub found: bool,
+    pub value: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetResponse {
+    fn default() -> &'a GetResponse {
+        <GetResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetResponse {
+    pub fn new() -> GetResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // bool found = 3;
+
+
+    pub fn get_found(&self) -> bool {
+        self.found
+    }
+    pub fn clear_found(&mut self) {
+        self.found = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_found(&mut self, v: bool) {
+        self.found = v;
+    }
+
+    // string value = 4;
+
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GetResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.found = tmp;
+                }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.found != false {
+            my_size += 2;
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.found != false {
+            os.write_bool(3, self.found)?;
+        }
+        if !self.value.is_empty() {
+            os.write_string(4, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetResponse {
+        GetResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Status>>(
+                    "status",
+                    |m: &GetResponse| { &m.status },
+                    |m: &mut GetResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &GetResponse| { &m.error },
+                    |m: &mut GetResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "found",
+                    |m: &GetResponse| { &m.found },
+                    |m: &mut GetResponse| { &mut m.found },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    |m: &GetResponse| { &m.value },
+                    |m: &mut GetResponse| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<GetResponse>(
+                    "GetResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GetResponse {
+        static mut instance: ::protobuf::lazy::Lazy<GetResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GetResponse,
+        };
+        unsafe {
+            instance.get(GetResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GetResponse {
+    fn clear(&mut self) {
+        self.status = Status::OK;
+        self.error.clear();
+        self.found = false;
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct DeleteRequest {
+    // message fields
+    pub key: ::std::string::String,
+    pub return_previous: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteRequest {
+    fn default() -> &'a DeleteRequest {
+        <DeleteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteRequest {
+    pub fn new() -> DeleteRequest {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // bool return_previous = 2;
+
+
+    pub fn get_return_previous(&self) -> bool {
+        self.return_previous
+    }
+    pub fn clear_return_previous(&mut self) {
+        self.return_previous = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_return_previous(&mut self, v: bool) {
+        self.return_previous = v;
+    }
+}
+
+impl ::protobuf::Message for DeleteRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                }
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.return_previous = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if self.return_previous != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if self.return_previous != false {
+            os.write_bool(2, self.return_previous)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteRequest {
+        DeleteRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key",
+                    |m: &DeleteRequest| { &m.key },
+                    |m: &mut DeleteRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "return_previous",
+                    |m: &DeleteRequest| { &m.return_previous },
+                    |m: &mut DeleteRequest| { &mut m.return_previous },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DeleteRequest>(
+                    "DeleteRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DeleteRequest {
+        static mut instance: ::protobuf::lazy::Lazy<DeleteRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DeleteRequest,
+        };
+        unsafe {
+            instance.get(DeleteRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DeleteRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.return_previous = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct DeleteResponse {
+    // message fields
+    pub status: Status,
+    pub error: ::std::string::String,
+    pub existed: bool,
+    pub previous_value: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteResponse {
+    fn default() -> &'a DeleteResponse {
+        <DeleteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteResponse {
+    pub fn new() -> DeleteResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // bool existed = 3;
+
+
+    pub fn get_existed(&self) -> bool {
+        self.existed
+    }
+    pub fn clear_existed(&mut self) {
+        self.existed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_existed(&mut self, v: bool) {
+        self.existed = v;
+    }
+
+    // string previous_value = 4;
+
+
+    pub fn get_previous_value(&self) -> &str {
+        &self.previous_value
+    }
+    pub fn clear_previous_value(&mut self) {
+        self.previous_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_previous_value(&mut self, v: ::std::string::String) {
+        self.previous_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_previous_value(&mut self) -> &mut ::std::string::String {
+        &mut self.previous_value
+    }
+
+    // Take field
+    pub fn take_previous_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.previous_value, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DeleteResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.existed = tmp;
+                }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.previous_value)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.existed != false {
+            my_size += 2;
+        }
+        if !self.previous_value.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.previous_value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.existed != false {
+            os.write_bool(3, self.existed)?;
+        }
+        if !self.previous_value.is_empty() {
+            os.write_string(4, &self.previous_value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteResponse {
+        DeleteResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Status>>(
+                    "status",
+                    |m: &DeleteResponse| { &m.status },
+                    |m: &mut DeleteResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &DeleteResponse| { &m.error },
+                    |m: &mut DeleteResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "existed",
+                    |m: &DeleteResponse| { &m.existed },
+                    |m: &mut DeleteResponse| { &mut m.existed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "previous_value",
+                    |m: &DeleteResponse| { &m.previous_value },
+                    |m: &mut DeleteResponse| { &mut m.previous_value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DeleteResponse>(
+                    "DeleteResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DeleteResponse {
+        static mut instance: ::protobuf::lazy::Lazy<DeleteResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DeleteResponse,
+        };
+        unsafe {
+            instance.get(DeleteResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DeleteResponse {
+    fn clear(&mut self) {
+        self.status = Status::OK;
+        self.error.clear();
+        self.existed = false;
+        self.previous_value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct MergeRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MergeRequest {
+    fn default() -> &'a MergeRequest {
+        <MergeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MergeRequest {
+    pub fn new() -> MergeRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for MergeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MergeRequest {
+        MergeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<MergeRequest>(
+                    "MergeRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static MergeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<MergeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MergeRequest,
+        };
+        unsafe {
+            instance.get(MergeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for MergeRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MergeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MergeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct MergeResponse {
+    // message fields
+    pub status: Status,
+    pub error: ::std::string::String,
+    pub files_merged: u64,
+    pub files_total: u64,
+    pub bytes_rewritten: u64,
+    pub bytes_reclaimed: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MergeResponse {
+    fn default() -> &'a MergeResponse {
+        <MergeResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MergeResponse {
+    pub fn new() -> MergeResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // uint64 files_merged = 3;
+
+
+    pub fn get_files_merged(&self) -> u64 {
+        self.files_merged
+    }
+    pub fn clear_files_merged(&mut self) {
+        self.files_merged = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_files_merged(&mut self, v: u64) {
+        self.files_merged = v;
+    }
+
+    // uint64 files_total = 4;
+
+
+    pub fn get_files_total(&self) -> u64 {
+        self.files_total
+    }
+    pub fn clear_files_total(&mut self) {
+        self.files_total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_files_total(&mut self, v: u64) {
+        self.files_total = v;
+    }
+
+    // uint64 bytes_rewritten = 5;
+
+
+    pub fn get_bytes_rewritten(&self) -> u64 {
+        self.bytes_rewritten
+    }
+    pub fn clear_bytes_rewritten(&mut self) {
+        self.bytes_rewritten = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bytes_rewritten(&mut self, v: u64) {
+        self.bytes_rewritten = v;
+    }
+
+    // uint64 bytes_reclaimed = 6;
+
+
+    pub fn get_bytes_reclaimed(&self) -> u64 {
+        self.bytes_reclaimed
+    }
+    pub fn clear_bytes_reclaimed(&mut self) {
+        self.bytes_reclaimed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bytes_reclaimed(&mut self, v: u64) {
+        self.bytes_reclaimed = v;
+    }
+}
+
+impl ::protobuf::Message for MergeResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.files_merged = tmp;
+                }
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.files_total = tmp;
+                }
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.bytes_rewritten = tmp;
+                }
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.bytes_reclaimed = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.files_merged != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.files_merged, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.files_total != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.files_total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.bytes_rewritten != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.bytes_rewritten, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.bytes_reclaimed != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.bytes_reclaimed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.files_merged != 0 {
+            os.write_uint64(3, self.files_merged)?;
+        }
+        if self.files_total != 0 {
+            os.write_uint64(4, self.files_total)?;
+        }
+        if self.bytes_rewritten != 0 {
+            os.write_uint64(5, self.bytes_rewritten)?;
+        }
+        if self.bytes_reclaimed != 0 {
+            os.write_uint64(6, self.bytes_reclaimed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MergeResponse {
+        MergeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Status>>(
+                    "status",
+                    |m: &MergeResponse| { &m.status },
+                    |m: &mut MergeResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &MergeResponse| { &m.error },
+                    |m: &mut MergeResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "files_merged",
+                    |m: &MergeResponse| { &m.files_merged },
+                    |m: &mut MergeResponse| { &mut m.files_merged },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "files_total",
+                    |m: &MergeResponse| { &m.files_total },
+                    |m: &mut MergeResponse| { &mut m.files_total },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "bytes_rewritten",
+                    |m: &MergeResponse| { &m.bytes_rewritten },
+                    |m: &mut MergeResponse| { &mut m.bytes_rewritten },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "bytes_reclaimed",
+                    |m: &MergeResponse| { &m.bytes_reclaimed },
+                    |m: &mut MergeResponse| { &mut m.bytes_reclaimed },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<MergeResponse>(
+                    "MergeResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static MergeResponse {
+        static mut instance: ::protobuf::lazy::Lazy<MergeResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MergeResponse,
+        };
+        unsafe {
+            instance.get(MergeResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for MergeResponse {
+    fn clear(&mut self) {
+        self.status = Status::OK;
+        self.error.clear();
+        self.files_merged = 0;
+        self.files_total = 0;
+        self.bytes_rewritten = 0;
+        self.bytes_reclaimed = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MergeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MergeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq, Clone, Default)]
 pub struct ScanRequest {
     // message fields
-    pub range: i64,
+    pub range: i64,
+    pub regex: ::std::string::String,
+    pub cursor: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ScanRequest {
+    fn default() -> &'a ScanRequest {
+        <ScanRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScanRequest {
+    pub fn new() -> ScanRequest {
+        ::std::default::Default::default()
+    }
+
+    // int64 range = 2;
+
+
+    pub fn get_range(&self) -> i64 {
+        self.range
+    }
+    pub fn clear_range(&mut self) {
+        self.range = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_range(&mut self, v: i64) {
+        self.range = v;
+    }
+
+    // string regex = 3;
+
+
+    pub fn get_regex(&self) -> &str {
+        &self.regex
+    }
+    pub fn clear_regex(&mut self) {
+        self.regex.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_regex(&mut self, v: ::std::string::String) {
+        self.regex = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_regex(&mut self) -> &mut ::std::string::String {
+        &mut self.regex
+    }
+
+    // Take field
+    pub fn take_regex(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.regex, ::std::string::String::new())
+    }
+
+    // bytes cursor = 4;
+
+
+    pub fn get_cursor(&self) -> &[u8] {
+        &self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: ::std::vec::Vec<u8>) {
+        self.cursor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cursor(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.cursor
+    }
+
+    // Take field
+    pub fn take_cursor(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.cursor, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for ScanRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.range = tmp;
+                }
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.regex)?;
+                }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.cursor)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.range != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.range, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.regex.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.regex);
+        }
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.cursor);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.range != 0 {
+            os.write_int64(2, self.range)?;
+        }
+        if !self.regex.is_empty() {
+            os.write_string(3, &self.regex)?;
+        }
+        if !self.cursor.is_empty() {
+            os.write_bytes(4, &self.cursor)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScanRequest {
+        ScanRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "range",
+                    |m: &ScanRequest| { &m.range },
+                    |m: &mut ScanRequest| { &mut m.range },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "regex",
+                    |m: &ScanRequest| { &m.regex },
+                    |m: &mut ScanRequest| { &mut m.regex },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "cursor",
+                    |m: &ScanRequest| { &m.cursor },
+                    |m: &mut ScanRequest| { &mut m.cursor },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ScanRequest>(
+                    "ScanRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ScanRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ScanRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ScanRequest,
+        };
+        unsafe {
+            instance.get(ScanRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ScanRequest {
+    fn clear(&mut self) {
+        self.range = 0;
+        self.regex.clear();
+        self.cursor.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScanRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScanRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct KeyBound {
+    // message fields
+    pub key: ::std::string::String,
+    pub inclusive: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KeyBound {
+    fn default() -> &'a KeyBound {
+        <KeyBound as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyBound {
+    pub fn new() -> KeyBound {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // bool inclusive = 2;
+
+
+    pub fn get_inclusive(&self) -> bool {
+        self.inclusive
+    }
+    pub fn clear_inclusive(&mut self) {
+        self.inclusive = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_inclusive(&mut self, v: bool) {
+        self.inclusive = v;
+    }
+}
+
+impl ::protobuf::Message for KeyBound {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                }
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.inclusive = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if self.inclusive != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if self.inclusive != false {
+            os.write_bool(2, self.inclusive)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KeyBound {
+        KeyBound::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key",
+                    |m: &KeyBound| { &m.key },
+                    |m: &mut KeyBound| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "inclusive",
+                    |m: &KeyBound| { &m.inclusive },
+                    |m: &mut KeyBound| { &mut m.inclusive },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<KeyBound>(
+                    "KeyBound",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KeyBound {
+        static mut instance: ::protobuf::lazy::Lazy<KeyBound> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const KeyBound,
+        };
+        unsafe {
+            instance.get(KeyBound::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KeyBound {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.inclusive = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KeyBound {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyBound {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct RangeScanRequest {
+    // message fields
+    pub start: ::protobuf::SingularPtrField<KeyBound>,
+    pub end: ::protobuf::SingularPtrField<KeyBound>,
+    pub order: Order,
+    pub limit: i64,
     pub regex: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a ScanRequest {
-    fn default() -> &'a ScanRequest {
-        <ScanRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RangeScanRequest {
+    fn default() -> &'a RangeScanRequest {
+        <RangeScanRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl ScanRequest {
-    pub fn new() -> ScanRequest {
+impl RangeScanRequest {
+    pub fn new() -> RangeScanRequest {
         ::std::default::Default::default()
     }
 
-    // int64 range = 2;
+    // .kv_server.KeyBound start = 1;
 
 
-    pub fn get_range(&self) -> i64 {
-        self.range
+    pub fn get_start(&self) -> &KeyBound {
+        self.start.as_ref().unwrap_or_else(|| KeyBound::default_instance())
     }
-    pub fn clear_range(&mut self) {
-        self.range = 0;
+    pub fn clear_start(&mut self) {
+        self.start.clear();
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_range(&mut self, v: i64) {
-        self.range = v;
+    pub fn set_start(&mut self, v: KeyBound) {
+        self.start = ::protobuf::SingularPtrField::some(v);
     }
 
-    // string regex = 3;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_start(&mut self) -> &mut KeyBound {
+        if self.start.is_none() {
+            self.start.set_default();
+        }
+        self.start.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_start(&mut self) -> KeyBound {
+        self.start.take().unwrap_or_else(|| KeyBound::new())
+    }
+
+    // .kv_server.KeyBound end = 2;
+
+
+    pub fn get_end(&self) -> &KeyBound {
+        self.end.as_ref().unwrap_or_else(|| KeyBound::default_instance())
+    }
+    pub fn clear_end(&mut self) {
+        self.end.clear();
+    }
+
+    pub fn has_end(&self) -> bool {
+        self.end.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: KeyBound) {
+        self.end = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_end(&mut self) -> &mut KeyBound {
+        if self.end.is_none() {
+            self.end.set_default();
+        }
+        self.end.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_end(&mut self) -> KeyBound {
+        self.end.take().unwrap_or_else(|| KeyBound::new())
+    }
+
+    // .kv_server.Order order = 3;
+
+
+    pub fn get_order(&self) -> Order {
+        self.order
+    }
+    pub fn clear_order(&mut self) {
+        self.order = Order::ASC;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_order(&mut self, v: Order) {
+        self.order = v;
+    }
+
+    // int64 limit = 4;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+
+    // string regex = 5;
 
 
     pub fn get_regex(&self) -> &str {
@@ -531,8 +3016,18 @@ impl ScanRequest {
     }
 }
 
-impl ::protobuf::Message for ScanRequest {
+impl ::protobuf::Message for RangeScanRequest {
     fn is_initialized(&self) -> bool {
+        for v in &self.start {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.end {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -540,14 +3035,23 @@ impl ::protobuf::Message for ScanRequest {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.start)?;
+                }
                 2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.end)?;
+                }
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.order, 3, &mut self.unknown_fields)?
+                }
+                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_int64()?;
-                    self.range = tmp;
+                    self.limit = tmp;
                 }
-                3 => {
+                5 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.regex)?;
                 }
                 _ => {
@@ -562,11 +3066,22 @@ impl ::protobuf::Message for ScanRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.range != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.range, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.start.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.end.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.order != Order::ASC {
+            my_size += ::protobuf::rt::enum_size(3, self.order);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.limit, ::protobuf::wire_format::WireTypeVarint);
         }
         if !self.regex.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.regex);
+            my_size += ::protobuf::rt::string_size(5, &self.regex);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -574,11 +3089,24 @@ impl ::protobuf::Message for ScanRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.range != 0 {
-            os.write_int64(2, self.range)?;
+        if let Some(ref v) = self.start.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.end.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.order != Order::ASC {
+            os.write_enum(3, self.order.value())?;
+        }
+        if self.limit != 0 {
+            os.write_int64(4, self.limit)?;
         }
         if !self.regex.is_empty() {
-            os.write_string(3, &self.regex)?;
+            os.write_string(5, &self.regex)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -610,8 +3138,8 @@ impl ::protobuf::Message for ScanRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> ScanRequest {
-        ScanRequest::new()
+    fn new() -> RangeScanRequest {
+        RangeScanRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -622,18 +3150,33 @@ impl ::protobuf::Message for ScanRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KeyBound>>(
+                    "start",
+                    |m: &RangeScanRequest| { &m.start },
+                    |m: &mut RangeScanRequest| { &mut m.start },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KeyBound>>(
+                    "end",
+                    |m: &RangeScanRequest| { &m.end },
+                    |m: &mut RangeScanRequest| { &mut m.end },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Order>>(
+                    "order",
+                    |m: &RangeScanRequest| { &m.order },
+                    |m: &mut RangeScanRequest| { &mut m.order },
+                ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "range",
-                    |m: &ScanRequest| { &m.range },
-                    |m: &mut ScanRequest| { &mut m.range },
+                    "limit",
+                    |m: &RangeScanRequest| { &m.limit },
+                    |m: &mut RangeScanRequest| { &mut m.limit },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "regex",
-                    |m: &ScanRequest| { &m.regex },
-                    |m: &mut ScanRequest| { &mut m.regex },
+                    |m: &RangeScanRequest| { &m.regex },
+                    |m: &mut RangeScanRequest| { &mut m.regex },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ScanRequest>(
-                    "ScanRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<RangeScanRequest>(
+                    "RangeScanRequest",
                     fields,
                     file_descriptor_proto(),
                 )
@@ -641,32 +3184,35 @@ impl ::protobuf::Message for ScanRequest {
         }
     }
 
-    fn default_instance() -> &'static ScanRequest {
-        static mut instance: ::protobuf::lazy::Lazy<ScanRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static RangeScanRequest {
+        static mut instance: ::protobuf::lazy::Lazy<RangeScanRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ScanRequest,
+            ptr: 0 as *const RangeScanRequest,
         };
         unsafe {
-            instance.get(ScanRequest::new)
+            instance.get(RangeScanRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ScanRequest {
+impl ::protobuf::Clear for RangeScanRequest {
     fn clear(&mut self) {
-        self.range = 0;
+        self.start.clear();
+        self.end.clear();
+        self.order = Order::ASC;
+        self.limit = 0;
         self.regex.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ScanRequest {
+impl ::std::fmt::Debug for RangeScanRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ScanRequest {
+impl ::protobuf::reflect::ProtobufValue for RangeScanRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -678,6 +3224,7 @@ pub struct ScanResponse {
     pub status: Status,
     pub key: ::std::string::String,
     pub value: ::std::string::String,
+    pub cursor: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -760,6 +3307,32 @@ impl ScanResponse {
     pub fn take_value(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.value, ::std::string::String::new())
     }
+
+    // bytes cursor = 4;
+
+
+    pub fn get_cursor(&self) -> &[u8] {
+        &self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: ::std::vec::Vec<u8>) {
+        self.cursor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cursor(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.cursor
+    }
+
+    // Take field
+    pub fn take_cursor(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.cursor, ::std::vec::Vec::new())
+    }
 }
 
 impl ::protobuf::Message for ScanResponse {
@@ -780,6 +3353,9 @@ impl ::protobuf::Message for ScanResponse {
                 3 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
                 }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.cursor)?;
+                }
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 }
@@ -801,6 +3377,9 @@ impl ::protobuf::Message for ScanResponse {
         if !self.value.is_empty() {
             my_size += ::protobuf::rt::string_size(3, &self.value);
         }
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.cursor);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -816,6 +3395,9 @@ impl ::protobuf::Message for ScanResponse {
         if !self.value.is_empty() {
             os.write_string(3, &self.value)?;
         }
+        if !self.cursor.is_empty() {
+            os.write_bytes(4, &self.cursor)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -873,6 +3455,11 @@ impl ::protobuf::Message for ScanResponse {
                     |m: &ScanResponse| { &m.value },
                     |m: &mut ScanResponse| { &mut m.value },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "cursor",
+                    |m: &ScanResponse| { &m.cursor },
+                    |m: &mut ScanResponse| { &mut m.cursor },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ScanResponse>(
                     "ScanResponse",
                     fields,
@@ -898,6 +3485,7 @@ impl ::protobuf::Clear for ScanResponse {
         self.status = Status::OK;
         self.key.clear();
         self.value.clear();
+        self.cursor.clear();
         self.unknown_fields.clear();
     }
 }
@@ -974,12 +3562,67 @@ impl ::protobuf::reflect::ProtobufValue for Operation {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Order {
+    ASC = 0,
+    DESC = 1,
+}
+
+impl ::protobuf::ProtobufEnum for Order {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Order> {
+        match value {
+            0 => ::std::option::Option::Some(Order::ASC),
+            1 => ::std::option::Option::Some(Order::DESC),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Order] = &[
+            Order::ASC,
+            Order::DESC,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("Order", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for Order {}
+
+impl ::std::default::Default for Order {
+    fn default() -> Self {
+        Order::ASC
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Order {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
 pub enum Status {
     OK = 0,
     Err = 1,
     NotFound = 2,
     InvalidRegex = 3,
+    Corruption = 5,
 }
 
 impl ::protobuf::ProtobufEnum for Status {
@@ -993,6 +3636,7 @@ impl ::protobuf::ProtobufEnum for Status {
             1 => ::std::option::Option::Some(Status::Err),
             2 => ::std::option::Option::Some(Status::NotFound),
             3 => ::std::option::Option::Some(Status::InvalidRegex),
+            5 => ::std::option::Option::Some(Status::Corruption),
             _ => ::std::option::Option::None
         }
     }
@@ -1003,6 +3647,7 @@ impl ::protobuf::ProtobufEnum for Status {
             Status::Err,
             Status::NotFound,
             Status::InvalidRegex,
+            Status::Corruption,
         ];
         values
     }
@@ -1035,87 +3680,55 @@ impl ::protobuf::reflect::ProtobufValue for Status {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0fkv_server.proto\x12\tkv_server\"e\n\x07Request\x122\n\toperation\
-    \x18\x01\x20\x01(\x0e2\x14.kv_server.OperationR\toperation\x12\x10\n\x03\
-    key\x18\x02\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x03\x20\x01(\tR\
-    \x05value\"K\n\x08Response\x12)\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv\
-    _server.StatusR\x06status\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05valu\
-    e\"9\n\x0bScanRequest\x12\x14\n\x05range\x18\x02\x20\x01(\x03R\x05range\
-    \x12\x14\n\x05regex\x18\x03\x20\x01(\tR\x05regex\"a\n\x0cScanResponse\
-    \x12)\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.StatusR\x06status\
-    \x12\x10\n\x03key\x18\x02\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x03\
-    \x20\x01(\tR\x05value*1\n\tOperation\x12\x07\n\x03PUT\x10\0\x12\x07\n\
-    \x03GET\x10\x01\x12\x07\n\x03DEL\x10\x02\x12\t\n\x05MERGE\x10\x03*9\n\
-    \x06Status\x12\x06\n\x02OK\x10\0\x12\x07\n\x03Err\x10\x01\x12\x0c\n\x08N\
-    otFound\x10\x02\x12\x10\n\x0cInvalidRegex\x10\x032{\n\x08KVServer\x122\n\
-    \x05Serve\x12\x12.kv_server.Request\x1a\x13.kv_server.Response\"\0\x12;\
-    \n\x04Scan\x12\x16.kv_server.ScanRequest\x1a\x17.kv_server.ScanResponse\
-    \"\00\x01J\xdd\n\n\x06\x12\x04\0\0.\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\
-    \n\x08\n\x01\x02\x12\x03\x02\0\x12\n\n\n\x02\x06\0\x12\x04\x04\0\t\x01\n\
-    \n\n\x03\x06\0\x01\x12\x03\x04\x08\x10\n\x0c\n\x04\x06\0\x02\0\x12\x04\
-    \x05\x04\x06\x05\n\x0c\n\x05\x06\0\x02\0\x01\x12\x03\x05\x08\r\n\x0c\n\
-    \x05\x06\0\x02\0\x02\x12\x03\x05\x0f\x16\n\x0c\n\x05\x06\0\x02\0\x03\x12\
-    \x03\x05!)\n\x0c\n\x04\x06\0\x02\x01\x12\x04\x07\x04\x08\x05\n\x0c\n\x05\
-    \x06\0\x02\x01\x01\x12\x03\x07\x08\x0c\n\x0c\n\x05\x06\0\x02\x01\x02\x12\
-    \x03\x07\x0e\x19\n\x0c\n\x05\x06\0\x02\x01\x06\x12\x03\x07$*\n\x0c\n\x05\
-    \x06\0\x02\x01\x03\x12\x03\x07+7\n\n\n\x02\x05\0\x12\x04\x0b\0\x10\x01\n\
-    \n\n\x03\x05\0\x01\x12\x03\x0b\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\
-    \x0c\x04\x0c\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x0c\x04\x07\n\x0c\n\x05\
-    \x05\0\x02\0\x02\x12\x03\x0c\n\x0b\n\x0b\n\x04\x05\0\x02\x01\x12\x03\r\
-    \x04\x0c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\r\x04\x07\n\x0c\n\x05\x05\
-    \0\x02\x01\x02\x12\x03\r\n\x0b\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x0e\x04\
-    \x0c\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x0e\x04\x07\n\x0c\n\x05\x05\0\
-    \x02\x02\x02\x12\x03\x0e\n\x0b\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x0f\x04\
-    \x0e\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x0f\x04\t\n\x0c\n\x05\x05\0\
-    \x02\x03\x02\x12\x03\x0f\x0c\r\n\n\n\x02\x05\x01\x12\x04\x12\0\x18\x01\n\
-    \n\n\x03\x05\x01\x01\x12\x03\x12\x05\x0b\n\x0b\n\x04\x05\x01\x02\0\x12\
-    \x03\x13\x04\x0b\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x13\x04\x06\n\x0c\
-    \n\x05\x05\x01\x02\0\x02\x12\x03\x13\t\n\n\x0b\n\x04\x05\x01\x02\x01\x12\
-    \x03\x14\x04\x0c\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\x14\x04\x07\n\
-    \x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\x14\n\x0b\n\x0b\n\x04\x05\x01\x02\
-    \x02\x12\x03\x15\x04\x11\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03\x15\x04\
-    \x0c\n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03\x15\x0f\x10\n\"\n\x04\x05\
-    \x01\x02\x03\x12\x03\x16\x04\x15\"\x15\x20\x20\x20\x20MergeLocked\x20=\
-    \x204;\n\n\x0c\n\x05\x05\x01\x02\x03\x01\x12\x03\x16\x04\x10\n\x0c\n\x05\
-    \x05\x01\x02\x03\x02\x12\x03\x16\x13\x14\n\n\n\x02\x04\0\x12\x04\x1a\0\
-    \x1e\x01\n\n\n\x03\x04\0\x01\x12\x03\x1a\x08\x0f\n\x0b\n\x04\x04\0\x02\0\
-    \x12\x03\x1b\x04\x1c\n\r\n\x05\x04\0\x02\0\x04\x12\x04\x1b\x04\x1a\x11\n\
-    \x0c\n\x05\x04\0\x02\0\x06\x12\x03\x1b\x04\r\n\x0c\n\x05\x04\0\x02\0\x01\
-    \x12\x03\x1b\x0e\x17\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x1b\x1a\x1b\n\
-    \x0b\n\x04\x04\0\x02\x01\x12\x03\x1c\x04\x13\n\r\n\x05\x04\0\x02\x01\x04\
-    \x12\x04\x1c\x04\x1b\x1c\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x1c\x04\n\
-    \n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x1c\x0b\x0e\n\x0c\n\x05\x04\0\x02\
-    \x01\x03\x12\x03\x1c\x11\x12\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x1d\x04\
-    \x15\n\r\n\x05\x04\0\x02\x02\x04\x12\x04\x1d\x04\x1c\x13\n\x0c\n\x05\x04\
-    \0\x02\x02\x05\x12\x03\x1d\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\
-    \x1d\x0b\x10\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x1d\x13\x14\n\n\n\x02\
-    \x04\x01\x12\x04\x20\0#\x01\n\n\n\x03\x04\x01\x01\x12\x03\x20\x08\x10\n\
-    \x0b\n\x04\x04\x01\x02\0\x12\x03!\x04\x16\n\r\n\x05\x04\x01\x02\0\x04\
-    \x12\x04!\x04\x20\x12\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03!\x04\n\n\x0c\
-    \n\x05\x04\x01\x02\0\x01\x12\x03!\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x03\
-    \x12\x03!\x14\x15\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\"\x04\x15\n\r\n\
-    \x05\x04\x01\x02\x01\x04\x12\x04\"\x04!\x16\n\x0c\n\x05\x04\x01\x02\x01\
-    \x05\x12\x03\"\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\"\x0b\x10\n\
-    \x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\"\x13\x14\n\n\n\x02\x04\x02\x12\
-    \x04%\0(\x01\n\n\n\x03\x04\x02\x01\x12\x03%\x08\x13\n\x0b\n\x04\x04\x02\
-    \x02\0\x12\x03&\x04\x14\n\r\n\x05\x04\x02\x02\0\x04\x12\x04&\x04%\x15\n\
-    \x0c\n\x05\x04\x02\x02\0\x05\x12\x03&\x04\t\n\x0c\n\x05\x04\x02\x02\0\
-    \x01\x12\x03&\n\x0f\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03&\x12\x13\n\x0b\
-    \n\x04\x04\x02\x02\x01\x12\x03'\x04\x15\n\r\n\x05\x04\x02\x02\x01\x04\
-    \x12\x04'\x04&\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03'\x04\n\n\x0c\
-    \n\x05\x04\x02\x02\x01\x01\x12\x03'\x0b\x10\n\x0c\n\x05\x04\x02\x02\x01\
-    \x03\x12\x03'\x13\x14\n\n\n\x02\x04\x03\x12\x04*\0.\x01\n\n\n\x03\x04\
-    \x03\x01\x12\x03*\x08\x14\n\x0b\n\x04\x04\x03\x02\0\x12\x03+\x04\x16\n\r\
-    \n\x05\x04\x03\x02\0\x04\x12\x04+\x04*\x16\n\x0c\n\x05\x04\x03\x02\0\x06\
-    \x12\x03+\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03+\x0b\x11\n\x0c\n\
-    \x05\x04\x03\x02\0\x03\x12\x03+\x14\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\
-    \x03,\x04\x13\n\r\n\x05\x04\x03\x02\x01\x04\x12\x04,\x04+\x16\n\x0c\n\
-    \x05\x04\x03\x02\x01\x05\x12\x03,\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\
-    \x12\x03,\x0b\x0e\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03,\x11\x12\n\x0b\
-    \n\x04\x04\x03\x02\x02\x12\x03-\x04\x15\n\r\n\x05\x04\x03\x02\x02\x04\
-    \x12\x04-\x04,\x13\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03-\x04\n\n\x0c\
-    \n\x05\x04\x03\x02\x02\x01\x12\x03-\x0b\x10\n\x0c\n\x05\x04\x03\x02\x02\
-    \x03\x12\x03-\x13\x14b\x06proto3\
+    \n\x0fkv_server.proto\x12\tkv_server\"V\n\x07Request\x12)\n\toperation\
+    \x18\x01\x20\x01(\x0e2\x14.kv_server.OperationB\0\x12\r\n\x03key\x18\x02\
+    \x20\x01(\tB\0\x12\x0f\n\x05value\x18\x03\x20\x01(\tB\0:\0\"B\n\x08Respo\
+    nse\x12#\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.StatusB\0\x12\
+    \x0f\n\x05value\x18\x02\x20\x01(\tB\0:\0\"[\n\nPutRequest\x12\r\n\x03key\
+    \x18\x01\x20\x01(\tB\0\x12\x0f\n\x05value\x18\x02\x20\x01(\tB\0\x12\x19\
+    \n\x0freturn_previous\x18\x03\x20\x01(\x08B\0\x12\x10\n\x06ttl_ms\x18\
+    \x04\x20\x01(\x04B\0:\0\"r\n\x0bPutResponse\x12#\n\x06status\x18\x01\x20\
+    \x01(\x0e2\x11.kv_server.StatusB\0\x12\x0f\n\x05error\x18\x02\x20\x01(\t\
+    B\0\x12\x11\n\x07existed\x18\x03\x20\x01(\x08B\0\x12\x18\n\x0eprevious_v\
+    alue\x18\x04\x20\x01(\tB\0:\0\"\x1d\n\nGetRequest\x12\r\n\x03key\x18\x01\
+    \x20\x01(\tB\0:\0\"g\n\x0bGetResponse\x12#\n\x06status\x18\x01\x20\x01(\
+    \x0e2\x11.kv_server.StatusB\0\x12\x0f\n\x05error\x18\x02\x20\x01(\tB\0\
+    \x12\x0f\n\x05found\x18\x03\x20\x01(\x08B\0\x12\x0f\n\x05value\x18\x04\
+    \x20\x01(\tB\0:\0\";\n\rDeleteRequest\x12\r\n\x03key\x18\x01\x20\x01(\tB\
+    \0\x12\x19\n\x0freturn_previous\x18\x02\x20\x01(\x08B\0:\0\"u\n\x0eDelet\
+    eResponse\x12#\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.StatusB\0\
+    \x12\x0f\n\x05error\x18\x02\x20\x01(\tB\0\x12\x11\n\x07existed\x18\x03\
+    \x20\x01(\x08B\0\x12\x18\n\x0eprevious_value\x18\x04\x20\x01(\tB\0:\0\"\
+    \x10\n\x0cMergeRequest:\0\"\xac\x01\n\rMergeResponse\x12#\n\x06status\
+    \x18\x01\x20\x01(\x0e2\x11.kv_server.StatusB\0\x12\x0f\n\x05error\x18\
+    \x02\x20\x01(\tB\0\x12\x16\n\x0cfiles_merged\x18\x03\x20\x01(\x04B\0\x12\
+    \x15\n\x0bfiles_total\x18\x04\x20\x01(\x04B\0\x12\x19\n\x0fbytes_rewritt\
+    en\x18\x05\x20\x01(\x04B\0\x12\x19\n\x0fbytes_reclaimed\x18\x06\x20\x01(\
+    \x04B\0:\0\"C\n\x0bScanRequest\x12\x0f\n\x05range\x18\x02\x20\x01(\x03B\
+    \0\x12\x0f\n\x05regex\x18\x03\x20\x01(\tB\0\x12\x10\n\x06cursor\x18\x04\
+    \x20\x01(\x0cB\0:\0\"0\n\x08KeyBound\x12\r\n\x03key\x18\x01\x20\x01(\tB\
+    \0\x12\x13\n\tinclusive\x18\x02\x20\x01(\x08B\0:\0\"\xa3\x01\n\x10RangeS\
+    canRequest\x12$\n\x05start\x18\x01\x20\x01(\x0b2\x13.kv_server.KeyBoundB\
+    \0\x12\"\n\x03end\x18\x02\x20\x01(\x0b2\x13.kv_server.KeyBoundB\0\x12!\n\
+    \x05order\x18\x03\x20\x01(\x0e2\x10.kv_server.OrderB\0\x12\x0f\n\x05limi\
+    t\x18\x04\x20\x01(\x03B\0\x12\x0f\n\x05regex\x18\x05\x20\x01(\tB\0:\0\"g\
+    \n\x0cScanResponse\x12#\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.\
+    StatusB\0\x12\r\n\x03key\x18\x02\x20\x01(\tB\0\x12\x0f\n\x05value\x18\
+    \x03\x20\x01(\tB\0\x12\x10\n\x06cursor\x18\x04\x20\x01(\x0cB\0:\0*3\n\tO\
+    peration\x12\x07\n\x03PUT\x10\0\x12\x07\n\x03GET\x10\x01\x12\x07\n\x03DE\
+    L\x10\x02\x12\t\n\x05MERGE\x10\x03\x1a\0*\x1c\n\x05Order\x12\x07\n\x03AS\
+    C\x10\0\x12\x08\n\x04DESC\x10\x01\x1a\0*K\n\x06Status\x12\x06\n\x02OK\
+    \x10\0\x12\x07\n\x03Err\x10\x01\x12\x0c\n\x08NotFound\x10\x02\x12\x10\n\
+    \x0cInvalidRegex\x10\x03\x12\x0e\n\nCorruption\x10\x05\x1a\02\xbb\x03\n\
+    \x08KVServer\x124\n\x05Serve\x12\x12.kv_server.Request\x1a\x13.kv_server\
+    .Response(\00\0\x12;\n\x04Scan\x12\x16.kv_server.ScanRequest\x1a\x17.kv_\
+    server.ScanResponse(\00\x01\x12E\n\tRangeScan\x12\x1b.kv_server.RangeSca\
+    nRequest\x1a\x17.kv_server.ScanResponse(\00\x01\x128\n\x03Put\x12\x15.kv\
+    _server.PutRequest\x1a\x16.kv_server.PutResponse(\00\0\x128\n\x03Get\x12\
+    \x15.kv_server.GetRequest\x1a\x16.kv_server.GetResponse(\00\0\x12A\n\x06\
+    Delete\x12\x18.kv_server.DeleteRequest\x1a\x19.kv_server.DeleteResponse(\
+    \00\0\x12>\n\x05Merge\x12\x17.kv_server.MergeRequest\x1a\x18.kv_server.M\
+    ergeResponse(\00\0B\0b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -32,6 +32,41 @@ const METHOD_KV_SERVER_SCAN: ::grpcio::Method<super::kv_server::ScanRequest, sup
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_KV_SERVER_RANGE_SCAN: ::grpcio::Method<super::kv_server::RangeScanRequest, super::kv_server::ScanResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/kv_server.KVServer/RangeScan",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_PUT: ::grpcio::Method<super::kv_server::PutRequest, super::kv_server::PutResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.KVServer/Put",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_GET: ::grpcio::Method<super::kv_server::GetRequest, super::kv_server::GetResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.KVServer/Get",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_DELETE: ::grpcio::Method<super::kv_server::DeleteRequest, super::kv_server::DeleteResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.KVServer/Delete",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_MERGE: ::grpcio::Method<super::kv_server::MergeRequest, super::kv_server::MergeResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.KVServer/Merge",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 #[derive(Clone)]
 pub struct KvServerClient {
     client: ::grpcio::Client,
@@ -67,6 +102,78 @@ impl KvServerClient {
     pub fn scan(&self, req: &super::kv_server::ScanRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::kv_server::ScanResponse>> {
         self.scan_opt(req, ::grpcio::CallOption::default())
     }
+
+    pub fn range_scan_opt(&self, req: &super::kv_server::RangeScanRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::kv_server::ScanResponse>> {
+        self.client.server_streaming(&METHOD_KV_SERVER_RANGE_SCAN, req, opt)
+    }
+
+    pub fn range_scan(&self, req: &super::kv_server::RangeScanRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::kv_server::ScanResponse>> {
+        self.range_scan_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn put_opt(&self, req: &super::kv_server::PutRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server::PutResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_PUT, req, opt)
+    }
+
+    pub fn put(&self, req: &super::kv_server::PutRequest) -> ::grpcio::Result<super::kv_server::PutResponse> {
+        self.put_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn put_async_opt(&self, req: &super::kv_server::PutRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server::PutResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_PUT, req, opt)
+    }
+
+    pub fn put_async(&self, req: &super::kv_server::PutRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server::PutResponse>> {
+        self.put_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_opt(&self, req: &super::kv_server::GetRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server::GetResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_GET, req, opt)
+    }
+
+    pub fn get(&self, req: &super::kv_server::GetRequest) -> ::grpcio::Result<super::kv_server::GetResponse> {
+        self.get_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_async_opt(&self, req: &super::kv_server::GetRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server::GetResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_GET, req, opt)
+    }
+
+    pub fn get_async(&self, req: &super::kv_server::GetRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server::GetResponse>> {
+        self.get_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn delete_opt(&self, req: &super::kv_server::DeleteRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server::DeleteResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_DELETE, req, opt)
+    }
+
+    pub fn delete(&self, req: &super::kv_server::DeleteRequest) -> ::grpcio::Result<super::kv_server::DeleteResponse> {
+        self.delete_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn delete_async_opt(&self, req: &super::kv_server::DeleteRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server::DeleteResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_DELETE, req, opt)
+    }
+
+    pub fn delete_async(&self, req: &super::kv_server::DeleteRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server::DeleteResponse>> {
+        self.delete_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn merge_opt(&self, req: &super::kv_server::MergeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server::MergeResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_MERGE, req, opt)
+    }
+
+    pub fn merge(&self, req: &super::kv_server::MergeRequest) -> ::grpcio::Result<super::kv_server::MergeResponse> {
+        self.merge_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn merge_async_opt(&self, req: &super::kv_server::MergeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server::MergeResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_MERGE, req, opt)
+    }
+
+    pub fn merge_async(&self, req: &super::kv_server::MergeRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server::MergeResponse>> {
+        self.merge_async_opt(req, ::grpcio::CallOption::default())
+    }
     pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item=(), Error=()> + Send + 'static {
         self.client.spawn(f)
     }
@@ -75,6 +182,11 @@ impl KvServerClient {
 pub trait KvServer {
     fn serve(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server::Request, sink: ::grpcio::UnarySink<super::kv_server::Response>);
     fn scan(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server::ScanRequest, sink: ::grpcio::ServerStreamingSink<super::kv_server::ScanResponse>);
+    fn range_scan(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server::RangeScanRequest, sink: ::grpcio::ServerStreamingSink<super::kv_server::ScanResponse>);
+    fn put(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server::PutRequest, sink: ::grpcio::UnarySink<super::kv_server::PutResponse>);
+    fn get(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server::GetRequest, sink: ::grpcio::UnarySink<super::kv_server::GetResponse>);
+    fn delete(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server::DeleteRequest, sink: ::grpcio::UnarySink<super::kv_server::DeleteResponse>);
+    fn merge(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server::MergeRequest, sink: ::grpcio::UnarySink<super::kv_server::MergeResponse>);
 }
 
 pub fn create_kv_server<S: KvServer + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
@@ -87,5 +199,25 @@ pub fn create_kv_server<S: KvServer + Send + Clone + 'static>(s: S) -> ::grpcio:
     builder = builder.add_server_streaming_handler(&METHOD_KV_SERVER_SCAN, move |ctx, req, resp| {
         instance.scan(ctx, req, resp)
     });
+    let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_KV_SERVER_RANGE_SCAN, move |ctx, req, resp| {
+        instance.range_scan(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_PUT, move |ctx, req, resp| {
+        instance.put(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_GET, move |ctx, req, resp| {
+        instance.get(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_DELETE, move |ctx, req, resp| {
+        instance.delete(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_MERGE, move |ctx, req, resp| {
+        instance.merge(ctx, req, resp)
+    });
     builder.build()
 }
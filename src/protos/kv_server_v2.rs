@@ -0,0 +1,6206 @@
+// This file is generated by rust-protobuf 2.7.0. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kv_server_v2.proto`
+
+use protobuf::Message as Message_imported_for_functions;
+use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_7_0;
+
+#[derive(PartialEq, Clone, Default)]
+pub struct Request {
+    // message fields
+    pub operation: super::kv_server::Operation,
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Request {
+    fn default() -> &'a Request {
+        <Request as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Request {
+    pub fn new() -> Request {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Operation operation = 1;
+
+
+    pub fn get_operation(&self) -> super::kv_server::Operation {
+        self.operation
+    }
+    pub fn clear_operation(&mut self) {
+        self.operation = super::kv_server::Operation::PUT;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_operation(&mut self, v: super::kv_server::Operation) {
+        self.operation = v;
+    }
+
+    // bytes key = 2;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 3;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for Request {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.operation, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                }
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.operation != super::kv_server::Operation::PUT {
+            my_size += ::protobuf::rt::enum_size(1, self.operation);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.operation != super::kv_server::Operation::PUT {
+            os.write_enum(1, self.operation.value())?;
+        }
+        if !self.key.is_empty() {
+            os.write_bytes(2, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Request {
+        Request::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Operation>>(
+                    "operation",
+                    |m: &Request| { &m.operation },
+                    |m: &mut Request| { &mut m.operation },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &Request| { &m.key },
+                    |m: &mut Request| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &Request| { &m.value },
+                    |m: &mut Request| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Request>(
+                    "Request",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Request {
+        static mut instance: ::protobuf::lazy::Lazy<Request> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Request,
+        };
+        unsafe {
+            instance.get(Request::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Request {
+    fn clear(&mut self) {
+        self.operation = super::kv_server::Operation::PUT;
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Request {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct Response {
+    // message fields
+    pub status: super::kv_server::Status,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Response {
+    fn default() -> &'a Response {
+        <Response as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Response {
+    pub fn new() -> Response {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> super::kv_server::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::kv_server::Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::kv_server::Status) {
+        self.status = v;
+    }
+
+    // bytes value = 2;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for Response {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != super::kv_server::Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != super::kv_server::Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Response {
+        Response::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Status>>(
+                    "status",
+                    |m: &Response| { &m.status },
+                    |m: &mut Response| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &Response| { &m.value },
+                    |m: &mut Response| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Response>(
+                    "Response",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Response {
+        static mut instance: ::protobuf::lazy::Lazy<Response> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Response,
+        };
+        unsafe {
+            instance.get(Response::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Response {
+    fn clear(&mut self) {
+        self.status = super::kv_server::Status::OK;
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Response {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Response {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct PutRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    pub return_previous: bool,
+    pub ttl_ms: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PutRequest {
+    fn default() -> &'a PutRequest {
+        <PutRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PutRequest {
+    pub fn new() -> PutRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 2;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    // bool return_previous = 3;
+
+
+    pub fn get_return_previous(&self) -> bool {
+        self.return_previous
+    }
+    pub fn clear_return_previous(&mut self) {
+        self.return_previous = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_return_previous(&mut self, v: bool) {
+        self.return_previous = v;
+    }
+
+    // uint64 ttl_ms = 4;
+
+
+    pub fn get_ttl_ms(&self) -> u64 {
+        self.ttl_ms
+    }
+    pub fn clear_ttl_ms(&mut self) {
+        self.ttl_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl_ms(&mut self, v: u64) {
+        self.ttl_ms = v;
+    }
+}
+
+impl ::protobuf::Message for PutRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.return_previous = tmp;
+                }
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.ttl_ms = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        if self.return_previous != false {
+            my_size += 2;
+        }
+        if self.ttl_ms != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.ttl_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        if self.return_previous != false {
+            os.write_bool(3, self.return_previous)?;
+        }
+        if self.ttl_ms != 0 {
+            os.write_uint64(4, self.ttl_ms)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PutRequest {
+        PutRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &PutRequest| { &m.key },
+                    |m: &mut PutRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &PutRequest| { &m.value },
+                    |m: &mut PutRequest| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "return_previous",
+                    |m: &PutRequest| { &m.return_previous },
+                    |m: &mut PutRequest| { &mut m.return_previous },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "ttl_ms",
+                    |m: &PutRequest| { &m.ttl_ms },
+                    |m: &mut PutRequest| { &mut m.ttl_ms },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PutRequest>(
+                    "PutRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PutRequest {
+        static mut instance: ::protobuf::lazy::Lazy<PutRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PutRequest,
+        };
+        unsafe {
+            instance.get(PutRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PutRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.return_previous = false;
+        self.ttl_ms = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PutRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PutRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct PutResponse {
+    // message fields
+    pub status: super::kv_server::Status,
+    pub error: ::std::string::String,
+    pub existed: bool,
+    pub previous_value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PutResponse {
+    fn default() -> &'a PutResponse {
+        <PutResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PutResponse {
+    pub fn new() -> PutResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> super::kv_server::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::kv_server::Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::kv_server::Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // bool existed = 3;
+
+
+    pub fn get_existed(&self) -> bool {
+        self.existed
+    }
+    pub fn clear_existed(&mut self) {
+        self.existed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_existed(&mut self, v: bool) {
+        self.existed = v;
+    }
+
+    // bytes previous_value = 4;
+
+
+    pub fn get_previous_value(&self) -> &[u8] {
+        &self.previous_value
+    }
+    pub fn clear_previous_value(&mut self) {
+        self.previous_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_previous_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.previous_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_previous_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.previous_value
+    }
+
+    // Take field
+    pub fn take_previous_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.previous_value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for PutResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.existed = tmp;
+                }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.previous_value)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != super::kv_server::Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.existed != false {
+            my_size += 2;
+        }
+        if !self.previous_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.previous_value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != super::kv_server::Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.existed != false {
+            os.write_bool(3, self.existed)?;
+        }
+        if !self.previous_value.is_empty() {
+            os.write_bytes(4, &self.previous_value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PutResponse {
+        PutResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Status>>(
+                    "status",
+                    |m: &PutResponse| { &m.status },
+                    |m: &mut PutResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &PutResponse| { &m.error },
+                    |m: &mut PutResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "existed",
+                    |m: &PutResponse| { &m.existed },
+                    |m: &mut PutResponse| { &mut m.existed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "previous_value",
+                    |m: &PutResponse| { &m.previous_value },
+                    |m: &mut PutResponse| { &mut m.previous_value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PutResponse>(
+                    "PutResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PutResponse {
+        static mut instance: ::protobuf::lazy::Lazy<PutResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PutResponse,
+        };
+        unsafe {
+            instance.get(PutResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PutResponse {
+    fn clear(&mut self) {
+        self.status = super::kv_server::Status::OK;
+        self.error.clear();
+        self.existed = false;
+        self.previous_value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PutResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PutResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct GetRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetRequest {
+    fn default() -> &'a GetRequest {
+        <GetRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetRequest {
+    pub fn new() -> GetRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for GetRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetRequest {
+        GetRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &GetRequest| { &m.key },
+                    |m: &mut GetRequest| { &mut m.key },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<GetRequest>(
+                    "GetRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GetRequest {
+        static mut instance: ::protobuf::lazy::Lazy<GetRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GetRequest,
+        };
+        unsafe {
+            instance.get(GetRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GetRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct GetResponse {
+    // message fields
+    pub status: super::kv_server::Status,
+    pub error: ::std::string::String,
+    pub found: bool,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetResponse {
+    fn default() -> &'a GetResponse {
+        <GetResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetResponse {
+    pub fn new() -> GetResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> super::kv_server::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::kv_server::Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::kv_server::Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // bool found = 3;
+
+
+    pub fn get_found(&self) -> bool {
+        self.found
+    }
+    pub fn clear_found(&mut self) {
+        self.found = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_found(&mut self, v: bool) {
+        self.found = v;
+    }
+
+    // bytes value = 4;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for GetResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.found = tmp;
+                }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != super::kv_server::Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.found != false {
+            my_size += 2;
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != super::kv_server::Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.found != false {
+            os.write_bool(3, self.found)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(4, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetResponse {
+        GetResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Status>>(
+                    "status",
+                    |m: &GetResponse| { &m.status },
+                    |m: &mut GetResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &GetResponse| { &m.error },
+                    |m: &mut GetResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "found",
+                    |m: &GetResponse| { &m.found },
+                    |m: &mut GetResponse| { &mut m.found },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &GetResponse| { &m.value },
+                    |m: &mut GetResponse| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<GetResponse>(
+                    "GetResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static GetResponse {
+        static mut instance: ::protobuf::lazy::Lazy<GetResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const GetResponse,
+        };
+        unsafe {
+            instance.get(GetResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for GetResponse {
+    fn clear(&mut self) {
+        self.status = super::kv_server::Status::OK;
+        self.error.clear();
+        self.found = false;
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct DeleteRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub return_previous: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteRequest {
+    fn default() -> &'a DeleteRequest {
+        <DeleteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteRequest {
+    pub fn new() -> DeleteRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bool return_previous = 2;
+
+
+    pub fn get_return_previous(&self) -> bool {
+        self.return_previous
+    }
+    pub fn clear_return_previous(&mut self) {
+        self.return_previous = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_return_previous(&mut self, v: bool) {
+        self.return_previous = v;
+    }
+}
+
+impl ::protobuf::Message for DeleteRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                }
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.return_previous = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if self.return_previous != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if self.return_previous != false {
+            os.write_bool(2, self.return_previous)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteRequest {
+        DeleteRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &DeleteRequest| { &m.key },
+                    |m: &mut DeleteRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "return_previous",
+                    |m: &DeleteRequest| { &m.return_previous },
+                    |m: &mut DeleteRequest| { &mut m.return_previous },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DeleteRequest>(
+                    "DeleteRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DeleteRequest {
+        static mut instance: ::protobuf::lazy::Lazy<DeleteRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DeleteRequest,
+        };
+        unsafe {
+            instance.get(DeleteRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DeleteRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.return_previous = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct DeleteResponse {
+    // message fields
+    pub status: super::kv_server::Status,
+    pub error: ::std::string::String,
+    pub existed: bool,
+    pub previous_value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteResponse {
+    fn default() -> &'a DeleteResponse {
+        <DeleteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteResponse {
+    pub fn new() -> DeleteResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> super::kv_server::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::kv_server::Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::kv_server::Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // bool existed = 3;
+
+
+    pub fn get_existed(&self) -> bool {
+        self.existed
+    }
+    pub fn clear_existed(&mut self) {
+        self.existed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_existed(&mut self, v: bool) {
+        self.existed = v;
+    }
+
+    // bytes previous_value = 4;
+
+
+    pub fn get_previous_value(&self) -> &[u8] {
+        &self.previous_value
+    }
+    pub fn clear_previous_value(&mut self) {
+        self.previous_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_previous_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.previous_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_previous_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.previous_value
+    }
+
+    // Take field
+    pub fn take_previous_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.previous_value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for DeleteResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.existed = tmp;
+                }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.previous_value)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != super::kv_server::Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.existed != false {
+            my_size += 2;
+        }
+        if !self.previous_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.previous_value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != super::kv_server::Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.existed != false {
+            os.write_bool(3, self.existed)?;
+        }
+        if !self.previous_value.is_empty() {
+            os.write_bytes(4, &self.previous_value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteResponse {
+        DeleteResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Status>>(
+                    "status",
+                    |m: &DeleteResponse| { &m.status },
+                    |m: &mut DeleteResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &DeleteResponse| { &m.error },
+                    |m: &mut DeleteResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "existed",
+                    |m: &DeleteResponse| { &m.existed },
+                    |m: &mut DeleteResponse| { &mut m.existed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "previous_value",
+                    |m: &DeleteResponse| { &m.previous_value },
+                    |m: &mut DeleteResponse| { &mut m.previous_value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DeleteResponse>(
+                    "DeleteResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DeleteResponse {
+        static mut instance: ::protobuf::lazy::Lazy<DeleteResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DeleteResponse,
+        };
+        unsafe {
+            instance.get(DeleteResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DeleteResponse {
+    fn clear(&mut self) {
+        self.status = super::kv_server::Status::OK;
+        self.error.clear();
+        self.existed = false;
+        self.previous_value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct MergeRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MergeRequest {
+    fn default() -> &'a MergeRequest {
+        <MergeRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MergeRequest {
+    pub fn new() -> MergeRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for MergeRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MergeRequest {
+        MergeRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<MergeRequest>(
+                    "MergeRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static MergeRequest {
+        static mut instance: ::protobuf::lazy::Lazy<MergeRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MergeRequest,
+        };
+        unsafe {
+            instance.get(MergeRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for MergeRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MergeRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MergeRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct MergeResponse {
+    // message fields
+    pub status: super::kv_server::Status,
+    pub error: ::std::string::String,
+    pub files_merged: u64,
+    pub files_total: u64,
+    pub bytes_rewritten: u64,
+    pub bytes_reclaimed: u64,
+    pub eta_ms: u64,
+    pub done: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MergeResponse {
+    fn default() -> &'a MergeResponse {
+        <MergeResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MergeResponse {
+    pub fn new() -> MergeResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> super::kv_server::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::kv_server::Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::kv_server::Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // uint64 files_merged = 3;
+
+
+    pub fn get_files_merged(&self) -> u64 {
+        self.files_merged
+    }
+    pub fn clear_files_merged(&mut self) {
+        self.files_merged = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_files_merged(&mut self, v: u64) {
+        self.files_merged = v;
+    }
+
+    // uint64 files_total = 4;
+
+
+    pub fn get_files_total(&self) -> u64 {
+        self.files_total
+    }
+    pub fn clear_files_total(&mut self) {
+        self.files_total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_files_total(&mut self, v: u64) {
+        self.files_total = v;
+    }
+
+    // uint64 bytes_rewritten = 5;
+
+
+    pub fn get_bytes_rewritten(&self) -> u64 {
+        self.bytes_rewritten
+    }
+    pub fn clear_bytes_rewritten(&mut self) {
+        self.bytes_rewritten = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bytes_rewritten(&mut self, v: u64) {
+        self.bytes_rewritten = v;
+    }
+
+    // uint64 bytes_reclaimed = 6;
+
+
+    pub fn get_bytes_reclaimed(&self) -> u64 {
+        self.bytes_reclaimed
+    }
+    pub fn clear_bytes_reclaimed(&mut self) {
+        self.bytes_reclaimed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bytes_reclaimed(&mut self, v: u64) {
+        self.bytes_reclaimed = v;
+    }
+
+    // uint64 eta_ms = 7;
+
+
+    pub fn get_eta_ms(&self) -> u64 {
+        self.eta_ms
+    }
+    pub fn clear_eta_ms(&mut self) {
+        self.eta_ms = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_eta_ms(&mut self, v: u64) {
+        self.eta_ms = v;
+    }
+
+    // bool done = 8;
+
+
+    pub fn get_done(&self) -> bool {
+        self.done
+    }
+    pub fn clear_done(&mut self) {
+        self.done = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_done(&mut self, v: bool) {
+        self.done = v;
+    }
+}
+
+impl ::protobuf::Message for MergeResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.files_merged = tmp;
+                }
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.files_total = tmp;
+                }
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.bytes_rewritten = tmp;
+                }
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.bytes_reclaimed = tmp;
+                }
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.eta_ms = tmp;
+                }
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.done = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != super::kv_server::Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.files_merged != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.files_merged, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.files_total != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.files_total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.bytes_rewritten != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.bytes_rewritten, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.bytes_reclaimed != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.bytes_reclaimed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.eta_ms != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.eta_ms, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.done != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != super::kv_server::Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.files_merged != 0 {
+            os.write_uint64(3, self.files_merged)?;
+        }
+        if self.files_total != 0 {
+            os.write_uint64(4, self.files_total)?;
+        }
+        if self.bytes_rewritten != 0 {
+            os.write_uint64(5, self.bytes_rewritten)?;
+        }
+        if self.bytes_reclaimed != 0 {
+            os.write_uint64(6, self.bytes_reclaimed)?;
+        }
+        if self.eta_ms != 0 {
+            os.write_uint64(7, self.eta_ms)?;
+        }
+        if self.done != false {
+            os.write_bool(8, self.done)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MergeResponse {
+        MergeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Status>>(
+                    "status",
+                    |m: &MergeResponse| { &m.status },
+                    |m: &mut MergeResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &MergeResponse| { &m.error },
+                    |m: &mut MergeResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "files_merged",
+                    |m: &MergeResponse| { &m.files_merged },
+                    |m: &mut MergeResponse| { &mut m.files_merged },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "files_total",
+                    |m: &MergeResponse| { &m.files_total },
+                    |m: &mut MergeResponse| { &mut m.files_total },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "bytes_rewritten",
+                    |m: &MergeResponse| { &m.bytes_rewritten },
+                    |m: &mut MergeResponse| { &mut m.bytes_rewritten },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "bytes_reclaimed",
+                    |m: &MergeResponse| { &m.bytes_reclaimed },
+                    |m: &mut MergeResponse| { &mut m.bytes_reclaimed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "eta_ms",
+                    |m: &MergeResponse| { &m.eta_ms },
+                    |m: &mut MergeResponse| { &mut m.eta_ms },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "done",
+                    |m: &MergeResponse| { &m.done },
+                    |m: &mut MergeResponse| { &mut m.done },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<MergeResponse>(
+                    "MergeResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static MergeResponse {
+        static mut instance: ::protobuf::lazy::Lazy<MergeResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MergeResponse,
+        };
+        unsafe {
+            instance.get(MergeResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for MergeResponse {
+    fn clear(&mut self) {
+        self.status = super::kv_server::Status::OK;
+        self.error.clear();
+        self.files_merged = 0;
+        self.files_total = 0;
+        self.bytes_rewritten = 0;
+        self.bytes_reclaimed = 0;
+        self.eta_ms = 0;
+        self.done = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MergeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MergeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct BatchWrite {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    pub delete: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchWrite {
+    fn default() -> &'a BatchWrite {
+        <BatchWrite as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchWrite {
+    pub fn new() -> BatchWrite {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 2;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    // bool delete = 3;
+
+
+    pub fn get_delete(&self) -> bool {
+        self.delete
+    }
+    pub fn clear_delete(&mut self) {
+        self.delete = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delete(&mut self, v: bool) {
+        self.delete = v;
+    }
+}
+
+impl ::protobuf::Message for BatchWrite {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.delete = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        if self.delete != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        if self.delete != false {
+            os.write_bool(3, self.delete)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchWrite {
+        BatchWrite::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &BatchWrite| { &m.key },
+                    |m: &mut BatchWrite| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &BatchWrite| { &m.value },
+                    |m: &mut BatchWrite| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "delete",
+                    |m: &BatchWrite| { &m.delete },
+                    |m: &mut BatchWrite| { &mut m.delete },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<BatchWrite>(
+                    "BatchWrite",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchWrite {
+        static mut instance: ::protobuf::lazy::Lazy<BatchWrite> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const BatchWrite,
+        };
+        unsafe {
+            instance.get(BatchWrite::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchWrite {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.delete = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchWrite {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchWrite {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct BatchRequest {
+    // message fields
+    pub writes: ::protobuf::RepeatedField<BatchWrite>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchRequest {
+    fn default() -> &'a BatchRequest {
+        <BatchRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchRequest {
+    pub fn new() -> BatchRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kv_server.v2.BatchWrite writes = 1;
+
+
+    pub fn get_writes(&self) -> &[BatchWrite] {
+        &self.writes
+    }
+    pub fn clear_writes(&mut self) {
+        self.writes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_writes(&mut self, v: ::protobuf::RepeatedField<BatchWrite>) {
+        self.writes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_writes(&mut self) -> &mut ::protobuf::RepeatedField<BatchWrite> {
+        &mut self.writes
+    }
+
+    // Take field
+    pub fn take_writes(&mut self) -> ::protobuf::RepeatedField<BatchWrite> {
+        ::std::mem::replace(&mut self.writes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.writes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.writes)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.writes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.writes {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchRequest {
+        BatchRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BatchWrite>>(
+                    "writes",
+                    |m: &BatchRequest| { &m.writes },
+                    |m: &mut BatchRequest| { &mut m.writes },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<BatchRequest>(
+                    "BatchRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchRequest {
+        static mut instance: ::protobuf::lazy::Lazy<BatchRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const BatchRequest,
+        };
+        unsafe {
+            instance.get(BatchRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchRequest {
+    fn clear(&mut self) {
+        self.writes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct BatchResponse {
+    // message fields
+    pub status: super::kv_server::Status,
+    pub error: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchResponse {
+    fn default() -> &'a BatchResponse {
+        <BatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchResponse {
+    pub fn new() -> BatchResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> super::kv_server::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::kv_server::Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::kv_server::Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for BatchResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != super::kv_server::Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != super::kv_server::Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchResponse {
+        BatchResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Status>>(
+                    "status",
+                    |m: &BatchResponse| { &m.status },
+                    |m: &mut BatchResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &BatchResponse| { &m.error },
+                    |m: &mut BatchResponse| { &mut m.error },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<BatchResponse>(
+                    "BatchResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchResponse {
+        static mut instance: ::protobuf::lazy::Lazy<BatchResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const BatchResponse,
+        };
+        unsafe {
+            instance.get(BatchResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchResponse {
+    fn clear(&mut self) {
+        self.status = super::kv_server::Status::OK;
+        self.error.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct CompareAndSwapRequest {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub expect_absent: bool,
+    pub expected_value: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    pub delete: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CompareAndSwapRequest {
+    fn default() -> &'a CompareAndSwapRequest {
+        <CompareAndSwapRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CompareAndSwapRequest {
+    pub fn new() -> CompareAndSwapRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bool expect_absent = 2;
+
+
+    pub fn get_expect_absent(&self) -> bool {
+        self.expect_absent
+    }
+    pub fn clear_expect_absent(&mut self) {
+        self.expect_absent = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expect_absent(&mut self, v: bool) {
+        self.expect_absent = v;
+    }
+
+    // bytes expected_value = 3;
+
+
+    pub fn get_expected_value(&self) -> &[u8] {
+        &self.expected_value
+    }
+    pub fn clear_expected_value(&mut self) {
+        self.expected_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expected_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.expected_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_expected_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.expected_value
+    }
+
+    // Take field
+    pub fn take_expected_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.expected_value, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 4;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    // bool delete = 5;
+
+
+    pub fn get_delete(&self) -> bool {
+        self.delete
+    }
+    pub fn clear_delete(&mut self) {
+        self.delete = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delete(&mut self, v: bool) {
+        self.delete = v;
+    }
+}
+
+impl ::protobuf::Message for CompareAndSwapRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                }
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.expect_absent = tmp;
+                }
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.expected_value)?;
+                }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                }
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.delete = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if self.expect_absent != false {
+            my_size += 2;
+        }
+        if !self.expected_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.expected_value);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.value);
+        }
+        if self.delete != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if self.expect_absent != false {
+            os.write_bool(2, self.expect_absent)?;
+        }
+        if !self.expected_value.is_empty() {
+            os.write_bytes(3, &self.expected_value)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(4, &self.value)?;
+        }
+        if self.delete != false {
+            os.write_bool(5, self.delete)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CompareAndSwapRequest {
+        CompareAndSwapRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &CompareAndSwapRequest| { &m.key },
+                    |m: &mut CompareAndSwapRequest| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "expect_absent",
+                    |m: &CompareAndSwapRequest| { &m.expect_absent },
+                    |m: &mut CompareAndSwapRequest| { &mut m.expect_absent },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "expected_value",
+                    |m: &CompareAndSwapRequest| { &m.expected_value },
+                    |m: &mut CompareAndSwapRequest| { &mut m.expected_value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &CompareAndSwapRequest| { &m.value },
+                    |m: &mut CompareAndSwapRequest| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "delete",
+                    |m: &CompareAndSwapRequest| { &m.delete },
+                    |m: &mut CompareAndSwapRequest| { &mut m.delete },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CompareAndSwapRequest>(
+                    "CompareAndSwapRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CompareAndSwapRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CompareAndSwapRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CompareAndSwapRequest,
+        };
+        unsafe {
+            instance.get(CompareAndSwapRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CompareAndSwapRequest {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.expect_absent = false;
+        self.expected_value.clear();
+        self.value.clear();
+        self.delete = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CompareAndSwapRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompareAndSwapRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct CompareAndSwapResponse {
+    // message fields
+    pub status: super::kv_server::Status,
+    pub error: ::std::string::String,
+    pub swapped: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CompareAndSwapResponse {
+    fn default() -> &'a CompareAndSwapResponse {
+        <CompareAndSwapResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CompareAndSwapResponse {
+    pub fn new() -> CompareAndSwapResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> super::kv_server::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::kv_server::Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::kv_server::Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // bool swapped = 3;
+
+
+    pub fn get_swapped(&self) -> bool {
+        self.swapped
+    }
+    pub fn clear_swapped(&mut self) {
+        self.swapped = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_swapped(&mut self, v: bool) {
+        self.swapped = v;
+    }
+}
+
+impl ::protobuf::Message for CompareAndSwapResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.swapped = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != super::kv_server::Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.swapped != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != super::kv_server::Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.swapped != false {
+            os.write_bool(3, self.swapped)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CompareAndSwapResponse {
+        CompareAndSwapResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Status>>(
+                    "status",
+                    |m: &CompareAndSwapResponse| { &m.status },
+                    |m: &mut CompareAndSwapResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &CompareAndSwapResponse| { &m.error },
+                    |m: &mut CompareAndSwapResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "swapped",
+                    |m: &CompareAndSwapResponse| { &m.swapped },
+                    |m: &mut CompareAndSwapResponse| { &mut m.swapped },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CompareAndSwapResponse>(
+                    "CompareAndSwapResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CompareAndSwapResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CompareAndSwapResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CompareAndSwapResponse,
+        };
+        unsafe {
+            instance.get(CompareAndSwapResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CompareAndSwapResponse {
+    fn clear(&mut self) {
+        self.status = super::kv_server::Status::OK;
+        self.error.clear();
+        self.swapped = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CompareAndSwapResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompareAndSwapResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct TransactionRead {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub absent: bool,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TransactionRead {
+    fn default() -> &'a TransactionRead {
+        <TransactionRead as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TransactionRead {
+    pub fn new() -> TransactionRead {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bool absent = 2;
+
+
+    pub fn get_absent(&self) -> bool {
+        self.absent
+    }
+    pub fn clear_absent(&mut self) {
+        self.absent = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_absent(&mut self, v: bool) {
+        self.absent = v;
+    }
+
+    // bytes value = 3;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for TransactionRead {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                }
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.absent = tmp;
+                }
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if self.absent != false {
+            my_size += 2;
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if self.absent != false {
+            os.write_bool(2, self.absent)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TransactionRead {
+        TransactionRead::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &TransactionRead| { &m.key },
+                    |m: &mut TransactionRead| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "absent",
+                    |m: &TransactionRead| { &m.absent },
+                    |m: &mut TransactionRead| { &mut m.absent },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &TransactionRead| { &m.value },
+                    |m: &mut TransactionRead| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TransactionRead>(
+                    "TransactionRead",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TransactionRead {
+        static mut instance: ::protobuf::lazy::Lazy<TransactionRead> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TransactionRead,
+        };
+        unsafe {
+            instance.get(TransactionRead::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TransactionRead {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.absent = false;
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TransactionRead {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TransactionRead {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct CommitRequest {
+    // message fields
+    pub reads: ::protobuf::RepeatedField<TransactionRead>,
+    pub writes: ::protobuf::RepeatedField<BatchWrite>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CommitRequest {
+    fn default() -> &'a CommitRequest {
+        <CommitRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CommitRequest {
+    pub fn new() -> CommitRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kv_server.v2.TransactionRead reads = 1;
+
+
+    pub fn get_reads(&self) -> &[TransactionRead] {
+        &self.reads
+    }
+    pub fn clear_reads(&mut self) {
+        self.reads.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reads(&mut self, v: ::protobuf::RepeatedField<TransactionRead>) {
+        self.reads = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_reads(&mut self) -> &mut ::protobuf::RepeatedField<TransactionRead> {
+        &mut self.reads
+    }
+
+    // Take field
+    pub fn take_reads(&mut self) -> ::protobuf::RepeatedField<TransactionRead> {
+        ::std::mem::replace(&mut self.reads, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kv_server.v2.BatchWrite writes = 2;
+
+
+    pub fn get_writes(&self) -> &[BatchWrite] {
+        &self.writes
+    }
+    pub fn clear_writes(&mut self) {
+        self.writes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_writes(&mut self, v: ::protobuf::RepeatedField<BatchWrite>) {
+        self.writes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_writes(&mut self) -> &mut ::protobuf::RepeatedField<BatchWrite> {
+        &mut self.writes
+    }
+
+    // Take field
+    pub fn take_writes(&mut self) -> ::protobuf::RepeatedField<BatchWrite> {
+        ::std::mem::replace(&mut self.writes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CommitRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.reads {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.writes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.reads)?;
+                }
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.writes)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.reads {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.writes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.reads {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.writes {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CommitRequest {
+        CommitRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionRead>>(
+                    "reads",
+                    |m: &CommitRequest| { &m.reads },
+                    |m: &mut CommitRequest| { &mut m.reads },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BatchWrite>>(
+                    "writes",
+                    |m: &CommitRequest| { &m.writes },
+                    |m: &mut CommitRequest| { &mut m.writes },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CommitRequest>(
+                    "CommitRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CommitRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CommitRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CommitRequest,
+        };
+        unsafe {
+            instance.get(CommitRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CommitRequest {
+    fn clear(&mut self) {
+        self.reads.clear();
+        self.writes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CommitRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommitRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct CommitResponse {
+    // message fields
+    pub status: super::kv_server::Status,
+    pub error: ::std::string::String,
+    pub committed: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CommitResponse {
+    fn default() -> &'a CommitResponse {
+        <CommitResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CommitResponse {
+    pub fn new() -> CommitResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> super::kv_server::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::kv_server::Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::kv_server::Status) {
+        self.status = v;
+    }
+
+    // string error = 2;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // bool committed = 3;
+
+
+    pub fn get_committed(&self) -> bool {
+        self.committed
+    }
+    pub fn clear_committed(&mut self) {
+        self.committed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_committed(&mut self, v: bool) {
+        self.committed = v;
+    }
+}
+
+impl ::protobuf::Message for CommitResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                }
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.committed = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != super::kv_server::Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.committed != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != super::kv_server::Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.committed != false {
+            os.write_bool(3, self.committed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CommitResponse {
+        CommitResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Status>>(
+                    "status",
+                    |m: &CommitResponse| { &m.status },
+                    |m: &mut CommitResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &CommitResponse| { &m.error },
+                    |m: &mut CommitResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "committed",
+                    |m: &CommitResponse| { &m.committed },
+                    |m: &mut CommitResponse| { &mut m.committed },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CommitResponse>(
+                    "CommitResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CommitResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CommitResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CommitResponse,
+        };
+        unsafe {
+            instance.get(CommitResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CommitResponse {
+    fn clear(&mut self) {
+        self.status = super::kv_server::Status::OK;
+        self.error.clear();
+        self.committed = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CommitResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommitResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct StatsRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StatsRequest {
+    fn default() -> &'a StatsRequest {
+        <StatsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StatsRequest {
+    pub fn new() -> StatsRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for StatsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsRequest {
+        StatsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<StatsRequest>(
+                    "StatsRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StatsRequest {
+        static mut instance: ::protobuf::lazy::Lazy<StatsRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StatsRequest,
+        };
+        unsafe {
+            instance.get(StatsRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StatsRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StatsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct StatsResponse {
+    // message fields
+    pub status: super::kv_server::Status,
+    pub engine: ::std::string::String,
+    pub durability: ::std::string::String,
+    pub writes: u64,
+    pub syncs: u64,
+    pub keys: u64,
+    pub key_dirs_bytes: u64,
+    pub open_files: u64,
+    pub file_cache_hits: u64,
+    pub file_cache_misses: u64,
+    pub file_cache_evictions: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StatsResponse {
+    fn default() -> &'a StatsResponse {
+        <StatsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StatsResponse {
+    pub fn new() -> StatsResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> super::kv_server::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::kv_server::Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::kv_server::Status) {
+        self.status = v;
+    }
+
+    // string engine = 2;
+
+
+    pub fn get_engine(&self) -> &str {
+        &self.engine
+    }
+    pub fn clear_engine(&mut self) {
+        self.engine.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_engine(&mut self, v: ::std::string::String) {
+        self.engine = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_engine(&mut self) -> &mut ::std::string::String {
+        &mut self.engine
+    }
+
+    // Take field
+    pub fn take_engine(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.engine, ::std::string::String::new())
+    }
+
+    // string durability = 3;
+
+
+    pub fn get_durability(&self) -> &str {
+        &self.durability
+    }
+    pub fn clear_durability(&mut self) {
+        self.durability.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_durability(&mut self, v: ::std::string::String) {
+        self.durability = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_durability(&mut self) -> &mut ::std::string::String {
+        &mut self.durability
+    }
+
+    // Take field
+    pub fn take_durability(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.durability, ::std::string::String::new())
+    }
+
+    // uint64 writes = 4;
+
+
+    pub fn get_writes(&self) -> u64 {
+        self.writes
+    }
+    pub fn clear_writes(&mut self) {
+        self.writes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_writes(&mut self, v: u64) {
+        self.writes = v;
+    }
+
+    // uint64 syncs = 5;
+
+
+    pub fn get_syncs(&self) -> u64 {
+        self.syncs
+    }
+    pub fn clear_syncs(&mut self) {
+        self.syncs = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_syncs(&mut self, v: u64) {
+        self.syncs = v;
+    }
+
+    // uint64 keys = 6;
+
+
+    pub fn get_keys(&self) -> u64 {
+        self.keys
+    }
+    pub fn clear_keys(&mut self) {
+        self.keys = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: u64) {
+        self.keys = v;
+    }
+
+    // uint64 key_dirs_bytes = 7;
+
+
+    pub fn get_key_dirs_bytes(&self) -> u64 {
+        self.key_dirs_bytes
+    }
+    pub fn clear_key_dirs_bytes(&mut self) {
+        self.key_dirs_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_dirs_bytes(&mut self, v: u64) {
+        self.key_dirs_bytes = v;
+    }
+
+    // uint64 open_files = 8;
+
+
+    pub fn get_open_files(&self) -> u64 {
+        self.open_files
+    }
+    pub fn clear_open_files(&mut self) {
+        self.open_files = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_open_files(&mut self, v: u64) {
+        self.open_files = v;
+    }
+
+    // uint64 file_cache_hits = 9;
+
+
+    pub fn get_file_cache_hits(&self) -> u64 {
+        self.file_cache_hits
+    }
+    pub fn clear_file_cache_hits(&mut self) {
+        self.file_cache_hits = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_cache_hits(&mut self, v: u64) {
+        self.file_cache_hits = v;
+    }
+
+    // uint64 file_cache_misses = 10;
+
+
+    pub fn get_file_cache_misses(&self) -> u64 {
+        self.file_cache_misses
+    }
+    pub fn clear_file_cache_misses(&mut self) {
+        self.file_cache_misses = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_cache_misses(&mut self, v: u64) {
+        self.file_cache_misses = v;
+    }
+
+    // uint64 file_cache_evictions = 11;
+
+
+    pub fn get_file_cache_evictions(&self) -> u64 {
+        self.file_cache_evictions
+    }
+    pub fn clear_file_cache_evictions(&mut self) {
+        self.file_cache_evictions = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_cache_evictions(&mut self, v: u64) {
+        self.file_cache_evictions = v;
+    }
+}
+
+impl ::protobuf::Message for StatsResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.engine)?;
+                }
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.durability)?;
+                }
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.writes = tmp;
+                }
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.syncs = tmp;
+                }
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.keys = tmp;
+                }
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.key_dirs_bytes = tmp;
+                }
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.open_files = tmp;
+                }
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.file_cache_hits = tmp;
+                }
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.file_cache_misses = tmp;
+                }
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.file_cache_evictions = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != super::kv_server::Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.engine.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.engine);
+        }
+        if !self.durability.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.durability);
+        }
+        if self.writes != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.writes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.syncs != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.syncs, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.keys != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.keys, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.key_dirs_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.key_dirs_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.open_files != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.open_files, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.file_cache_hits != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.file_cache_hits, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.file_cache_misses != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.file_cache_misses, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.file_cache_evictions != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.file_cache_evictions, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != super::kv_server::Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.engine.is_empty() {
+            os.write_string(2, &self.engine)?;
+        }
+        if !self.durability.is_empty() {
+            os.write_string(3, &self.durability)?;
+        }
+        if self.writes != 0 {
+            os.write_uint64(4, self.writes)?;
+        }
+        if self.syncs != 0 {
+            os.write_uint64(5, self.syncs)?;
+        }
+        if self.keys != 0 {
+            os.write_uint64(6, self.keys)?;
+        }
+        if self.key_dirs_bytes != 0 {
+            os.write_uint64(7, self.key_dirs_bytes)?;
+        }
+        if self.open_files != 0 {
+            os.write_uint64(8, self.open_files)?;
+        }
+        if self.file_cache_hits != 0 {
+            os.write_uint64(9, self.file_cache_hits)?;
+        }
+        if self.file_cache_misses != 0 {
+            os.write_uint64(10, self.file_cache_misses)?;
+        }
+        if self.file_cache_evictions != 0 {
+            os.write_uint64(11, self.file_cache_evictions)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StatsResponse {
+        StatsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Status>>(
+                    "status",
+                    |m: &StatsResponse| { &m.status },
+                    |m: &mut StatsResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "engine",
+                    |m: &StatsResponse| { &m.engine },
+                    |m: &mut StatsResponse| { &mut m.engine },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "durability",
+                    |m: &StatsResponse| { &m.durability },
+                    |m: &mut StatsResponse| { &mut m.durability },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "writes",
+                    |m: &StatsResponse| { &m.writes },
+                    |m: &mut StatsResponse| { &mut m.writes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "syncs",
+                    |m: &StatsResponse| { &m.syncs },
+                    |m: &mut StatsResponse| { &mut m.syncs },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "keys",
+                    |m: &StatsResponse| { &m.keys },
+                    |m: &mut StatsResponse| { &mut m.keys },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "key_dirs_bytes",
+                    |m: &StatsResponse| { &m.key_dirs_bytes },
+                    |m: &mut StatsResponse| { &mut m.key_dirs_bytes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "open_files",
+                    |m: &StatsResponse| { &m.open_files },
+                    |m: &mut StatsResponse| { &mut m.open_files },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "file_cache_hits",
+                    |m: &StatsResponse| { &m.file_cache_hits },
+                    |m: &mut StatsResponse| { &mut m.file_cache_hits },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "file_cache_misses",
+                    |m: &StatsResponse| { &m.file_cache_misses },
+                    |m: &mut StatsResponse| { &mut m.file_cache_misses },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "file_cache_evictions",
+                    |m: &StatsResponse| { &m.file_cache_evictions },
+                    |m: &mut StatsResponse| { &mut m.file_cache_evictions },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StatsResponse>(
+                    "StatsResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StatsResponse {
+        static mut instance: ::protobuf::lazy::Lazy<StatsResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StatsResponse,
+        };
+        unsafe {
+            instance.get(StatsResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StatsResponse {
+    fn clear(&mut self) {
+        self.status = super::kv_server::Status::OK;
+        self.engine.clear();
+        self.durability.clear();
+        self.writes = 0;
+        self.syncs = 0;
+        self.keys = 0;
+        self.key_dirs_bytes = 0;
+        self.open_files = 0;
+        self.file_cache_hits = 0;
+        self.file_cache_misses = 0;
+        self.file_cache_evictions = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StatsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StatsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct ScanRequest {
+    // message fields
+    pub range: i64,
+    pub regex: ::std::string::String,
+    pub cursor: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ScanRequest {
+    fn default() -> &'a ScanRequest {
+        <ScanRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScanRequest {
+    pub fn new() -> ScanRequest {
+        ::std::default::Default::default()
+    }
+
+    // int64 range = 2;
+
+
+    pub fn get_range(&self) -> i64 {
+        self.range
+    }
+    pub fn clear_range(&mut self) {
+        self.range = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_range(&mut self, v: i64) {
+        self.range = v;
+    }
+
+    // string regex = 3;
+
+
+    pub fn get_regex(&self) -> &str {
+        &self.regex
+    }
+    pub fn clear_regex(&mut self) {
+        self.regex.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_regex(&mut self, v: ::std::string::String) {
+        self.regex = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_regex(&mut self) -> &mut ::std::string::String {
+        &mut self.regex
+    }
+
+    // Take field
+    pub fn take_regex(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.regex, ::std::string::String::new())
+    }
+
+    // bytes cursor = 4;
+
+
+    pub fn get_cursor(&self) -> &[u8] {
+        &self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: ::std::vec::Vec<u8>) {
+        self.cursor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cursor(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.cursor
+    }
+
+    // Take field
+    pub fn take_cursor(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.cursor, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for ScanRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.range = tmp;
+                }
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.regex)?;
+                }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.cursor)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.range != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.range, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.regex.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.regex);
+        }
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.cursor);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.range != 0 {
+            os.write_int64(2, self.range)?;
+        }
+        if !self.regex.is_empty() {
+            os.write_string(3, &self.regex)?;
+        }
+        if !self.cursor.is_empty() {
+            os.write_bytes(4, &self.cursor)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScanRequest {
+        ScanRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "range",
+                    |m: &ScanRequest| { &m.range },
+                    |m: &mut ScanRequest| { &mut m.range },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "regex",
+                    |m: &ScanRequest| { &m.regex },
+                    |m: &mut ScanRequest| { &mut m.regex },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "cursor",
+                    |m: &ScanRequest| { &m.cursor },
+                    |m: &mut ScanRequest| { &mut m.cursor },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ScanRequest>(
+                    "ScanRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ScanRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ScanRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ScanRequest,
+        };
+        unsafe {
+            instance.get(ScanRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ScanRequest {
+    fn clear(&mut self) {
+        self.range = 0;
+        self.regex.clear();
+        self.cursor.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScanRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScanRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct KeyBound {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub inclusive: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KeyBound {
+    fn default() -> &'a KeyBound {
+        <KeyBound as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyBound {
+    pub fn new() -> KeyBound {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bool inclusive = 2;
+
+
+    pub fn get_inclusive(&self) -> bool {
+        self.inclusive
+    }
+    pub fn clear_inclusive(&mut self) {
+        self.inclusive = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_inclusive(&mut self, v: bool) {
+        self.inclusive = v;
+    }
+}
+
+impl ::protobuf::Message for KeyBound {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                }
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.inclusive = tmp;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if self.inclusive != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if self.inclusive != false {
+            os.write_bool(2, self.inclusive)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KeyBound {
+        KeyBound::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &KeyBound| { &m.key },
+                    |m: &mut KeyBound| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "inclusive",
+                    |m: &KeyBound| { &m.inclusive },
+                    |m: &mut KeyBound| { &mut m.inclusive },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<KeyBound>(
+                    "KeyBound",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KeyBound {
+        static mut instance: ::protobuf::lazy::Lazy<KeyBound> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const KeyBound,
+        };
+        unsafe {
+            instance.get(KeyBound::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KeyBound {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.inclusive = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KeyBound {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyBound {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct RangeScanRequest {
+    // message fields
+    pub start: ::protobuf::SingularPtrField<KeyBound>,
+    pub end: ::protobuf::SingularPtrField<KeyBound>,
+    pub order: super::kv_server::Order,
+    pub limit: i64,
+    pub regex: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RangeScanRequest {
+    fn default() -> &'a RangeScanRequest {
+        <RangeScanRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RangeScanRequest {
+    pub fn new() -> RangeScanRequest {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.v2.KeyBound start = 1;
+
+
+    pub fn get_start(&self) -> &KeyBound {
+        self.start.as_ref().unwrap_or_else(|| KeyBound::default_instance())
+    }
+    pub fn clear_start(&mut self) {
+        self.start.clear();
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: KeyBound) {
+        self.start = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_start(&mut self) -> &mut KeyBound {
+        if self.start.is_none() {
+            self.start.set_default();
+        }
+        self.start.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_start(&mut self) -> KeyBound {
+        self.start.take().unwrap_or_else(|| KeyBound::new())
+    }
+
+    // .kv_server.v2.KeyBound end = 2;
+
+
+    pub fn get_end(&self) -> &KeyBound {
+        self.end.as_ref().unwrap_or_else(|| KeyBound::default_instance())
+    }
+    pub fn clear_end(&mut self) {
+        self.end.clear();
+    }
+
+    pub fn has_end(&self) -> bool {
+        self.end.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: KeyBound) {
+        self.end = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_end(&mut self) -> &mut KeyBound {
+        if self.end.is_none() {
+            self.end.set_default();
+        }
+        self.end.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_end(&mut self) -> KeyBound {
+        self.end.take().unwrap_or_else(|| KeyBound::new())
+    }
+
+    // .kv_server.Order order = 3;
+
+
+    pub fn get_order(&self) -> super::kv_server::Order {
+        self.order
+    }
+    pub fn clear_order(&mut self) {
+        self.order = super::kv_server::Order::ASC;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_order(&mut self, v: super::kv_server::Order) {
+        self.order = v;
+    }
+
+    // int64 limit = 4;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+
+    // string regex = 5;
+
+
+    pub fn get_regex(&self) -> &str {
+        &self.regex
+    }
+    pub fn clear_regex(&mut self) {
+        self.regex.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_regex(&mut self, v: ::std::string::String) {
+        self.regex = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_regex(&mut self) -> &mut ::std::string::String {
+        &mut self.regex
+    }
+
+    // Take field
+    pub fn take_regex(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.regex, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RangeScanRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.start {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.end {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.start)?;
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.end)?;
+                }
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.order, 3, &mut self.unknown_fields)?
+                }
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                }
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.regex)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.start.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.end.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.order != super::kv_server::Order::ASC {
+            my_size += ::protobuf::rt::enum_size(3, self.order);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.regex.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.regex);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.start.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.end.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.order != super::kv_server::Order::ASC {
+            os.write_enum(3, self.order.value())?;
+        }
+        if self.limit != 0 {
+            os.write_int64(4, self.limit)?;
+        }
+        if !self.regex.is_empty() {
+            os.write_string(5, &self.regex)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RangeScanRequest {
+        RangeScanRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KeyBound>>(
+                    "start",
+                    |m: &RangeScanRequest| { &m.start },
+                    |m: &mut RangeScanRequest| { &mut m.start },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<KeyBound>>(
+                    "end",
+                    |m: &RangeScanRequest| { &m.end },
+                    |m: &mut RangeScanRequest| { &mut m.end },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Order>>(
+                    "order",
+                    |m: &RangeScanRequest| { &m.order },
+                    |m: &mut RangeScanRequest| { &mut m.order },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "limit",
+                    |m: &RangeScanRequest| { &m.limit },
+                    |m: &mut RangeScanRequest| { &mut m.limit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "regex",
+                    |m: &RangeScanRequest| { &m.regex },
+                    |m: &mut RangeScanRequest| { &mut m.regex },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RangeScanRequest>(
+                    "RangeScanRequest",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RangeScanRequest {
+        static mut instance: ::protobuf::lazy::Lazy<RangeScanRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RangeScanRequest,
+        };
+        unsafe {
+            instance.get(RangeScanRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RangeScanRequest {
+    fn clear(&mut self) {
+        self.start.clear();
+        self.end.clear();
+        self.order = super::kv_server::Order::ASC;
+        self.limit = 0;
+        self.regex.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RangeScanRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RangeScanRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq, Clone, Default)]
+pub struct ScanResponse {
+    // message fields
+    pub status: super::kv_server::Status,
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    pub cursor: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ScanResponse {
+    fn default() -> &'a ScanResponse {
+        <ScanResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScanResponse {
+    pub fn new() -> ScanResponse {
+        ::std::default::Default::default()
+    }
+
+    // .kv_server.Status status = 1;
+
+
+    pub fn get_status(&self) -> super::kv_server::Status {
+        self.status
+    }
+    pub fn clear_status(&mut self) {
+        self.status = super::kv_server::Status::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::kv_server::Status) {
+        self.status = v;
+    }
+
+    // bytes key = 2;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 3;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    // bytes cursor = 4;
+
+
+    pub fn get_cursor(&self) -> &[u8] {
+        &self.cursor
+    }
+    pub fn clear_cursor(&mut self) {
+        self.cursor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cursor(&mut self, v: ::std::vec::Vec<u8>) {
+        self.cursor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cursor(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.cursor
+    }
+
+    // Take field
+    pub fn take_cursor(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.cursor, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for ScanResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 1, &mut self.unknown_fields)?
+                }
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                }
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                }
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.cursor)?;
+                }
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                }
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.status != super::kv_server::Status::OK {
+            my_size += ::protobuf::rt::enum_size(1, self.status);
+        }
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        }
+        if !self.cursor.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.cursor);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.status != super::kv_server::Status::OK {
+            os.write_enum(1, self.status.value())?;
+        }
+        if !self.key.is_empty() {
+            os.write_bytes(2, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
+        }
+        if !self.cursor.is_empty() {
+            os.write_bytes(4, &self.cursor)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScanResponse {
+        ScanResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::kv_server::Status>>(
+                    "status",
+                    |m: &ScanResponse| { &m.status },
+                    |m: &mut ScanResponse| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &ScanResponse| { &m.key },
+                    |m: &mut ScanResponse| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &ScanResponse| { &m.value },
+                    |m: &mut ScanResponse| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "cursor",
+                    |m: &ScanResponse| { &m.cursor },
+                    |m: &mut ScanResponse| { &mut m.cursor },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ScanResponse>(
+                    "ScanResponse",
+                    fields,
+                    file_descriptor_proto(),
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ScanResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ScanResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ScanResponse,
+        };
+        unsafe {
+            instance.get(ScanResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ScanResponse {
+    fn clear(&mut self) {
+        self.status = super::kv_server::Status::OK;
+        self.key.clear();
+        self.value.clear();
+        self.cursor.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScanResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScanResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x12kv_server_v2.proto\x12\x0ckv_server.v2\"V\n\x07Request\x12)\n\tope\
+    ration\x18\x01\x20\x01(\x0e2\x14.kv_server.OperationB\0\x12\r\n\x03key\
+    \x18\x02\x20\x01(\x0cB\0\x12\x0f\n\x05value\x18\x03\x20\x01(\x0cB\0:\0\"\
+    B\n\x08Response\x12#\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.Sta\
+    tusB\0\x12\x0f\n\x05value\x18\x02\x20\x01(\x0cB\0:\0\"[\n\nPutRequest\
+    \x12\r\n\x03key\x18\x01\x20\x01(\x0cB\0\x12\x0f\n\x05value\x18\x02\x20\
+    \x01(\x0cB\0\x12\x19\n\x0freturn_previous\x18\x03\x20\x01(\x08B\0\x12\
+    \x10\n\x06ttl_ms\x18\x04\x20\x01(\x04B\0:\0\"r\n\x0bPutResponse\x12#\n\
+    \x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.StatusB\0\x12\x0f\n\x05er\
+    ror\x18\x02\x20\x01(\tB\0\x12\x11\n\x07existed\x18\x03\x20\x01(\x08B\0\
+    \x12\x18\n\x0eprevious_value\x18\x04\x20\x01(\x0cB\0:\0\"\x1d\n\nGetRequ\
+    est\x12\r\n\x03key\x18\x01\x20\x01(\x0cB\0:\0\"g\n\x0bGetResponse\x12#\n\
+    \x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.StatusB\0\x12\x0f\n\x05er\
+    ror\x18\x02\x20\x01(\tB\0\x12\x0f\n\x05found\x18\x03\x20\x01(\x08B\0\x12\
+    \x0f\n\x05value\x18\x04\x20\x01(\x0cB\0:\0\";\n\rDeleteRequest\x12\r\n\
+    \x03key\x18\x01\x20\x01(\x0cB\0\x12\x19\n\x0freturn_previous\x18\x02\x20\
+    \x01(\x08B\0:\0\"u\n\x0eDeleteResponse\x12#\n\x06status\x18\x01\x20\x01(\
+    \x0e2\x11.kv_server.StatusB\0\x12\x0f\n\x05error\x18\x02\x20\x01(\tB\0\
+    \x12\x11\n\x07existed\x18\x03\x20\x01(\x08B\0\x12\x18\n\x0eprevious_valu\
+    e\x18\x04\x20\x01(\x0cB\0:\0\"\x10\n\x0cMergeRequest:\0\"\xce\x01\n\rMer\
+    geResponse\x12#\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.StatusB\
+    \0\x12\x0f\n\x05error\x18\x02\x20\x01(\tB\0\x12\x16\n\x0cfiles_merged\
+    \x18\x03\x20\x01(\x04B\0\x12\x15\n\x0bfiles_total\x18\x04\x20\x01(\x04B\
+    \0\x12\x19\n\x0fbytes_rewritten\x18\x05\x20\x01(\x04B\0\x12\x19\n\x0fbyt\
+    es_reclaimed\x18\x06\x20\x01(\x04B\0\x12\x10\n\x06eta_ms\x18\x07\x20\x01\
+    (\x04B\0\x12\x0e\n\x04done\x18\x08\x20\x01(\x08B\0:\0\"@\n\nBatchWrite\
+    \x12\r\n\x03key\x18\x01\x20\x01(\x0cB\0\x12\x0f\n\x05value\x18\x02\x20\
+    \x01(\x0cB\0\x12\x10\n\x06delete\x18\x03\x20\x01(\x08B\0:\0\"<\n\x0cBatc\
+    hRequest\x12*\n\x06writes\x18\x01\x20\x03(\x0b2\x18.kv_server.v2.BatchWr\
+    iteB\0:\0\"G\n\rBatchResponse\x12#\n\x06status\x18\x01\x20\x01(\x0e2\x11\
+    .kv_server.StatusB\0\x12\x0f\n\x05error\x18\x02\x20\x01(\tB\0:\0\"~\n\
+    \x15CompareAndSwapRequest\x12\r\n\x03key\x18\x01\x20\x01(\x0cB\0\x12\x17\
+    \n\rexpect_absent\x18\x02\x20\x01(\x08B\0\x12\x18\n\x0eexpected_value\
+    \x18\x03\x20\x01(\x0cB\0\x12\x0f\n\x05value\x18\x04\x20\x01(\x0cB\0\x12\
+    \x10\n\x06delete\x18\x05\x20\x01(\x08B\0:\0\"c\n\x16CompareAndSwapRespon\
+    se\x12#\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.StatusB\0\x12\
+    \x0f\n\x05error\x18\x02\x20\x01(\tB\0\x12\x11\n\x07swapped\x18\x03\x20\
+    \x01(\x08B\0:\0\"E\n\x0fTransactionRead\x12\r\n\x03key\x18\x01\x20\x01(\
+    \x0cB\0\x12\x10\n\x06absent\x18\x02\x20\x01(\x08B\0\x12\x0f\n\x05value\
+    \x18\x03\x20\x01(\x0cB\0:\0\"m\n\rCommitRequest\x12.\n\x05reads\x18\x01\
+    \x20\x03(\x0b2\x1d.kv_server.v2.TransactionReadB\0\x12*\n\x06writes\x18\
+    \x02\x20\x03(\x0b2\x18.kv_server.v2.BatchWriteB\0:\0\"]\n\x0eCommitRespo\
+    nse\x12#\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.StatusB\0\x12\
+    \x0f\n\x05error\x18\x02\x20\x01(\tB\0\x12\x13\n\tcommitted\x18\x03\x20\
+    \x01(\x08B\0:\0\"\x10\n\x0cStatsRequest:\0\"\x99\x02\n\rStatsResponse\
+    \x12#\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.StatusB\0\x12\x10\
+    \n\x06engine\x18\x02\x20\x01(\tB\0\x12\x14\n\ndurability\x18\x03\x20\x01\
+    (\tB\0\x12\x10\n\x06writes\x18\x04\x20\x01(\x04B\0\x12\x0f\n\x05syncs\
+    \x18\x05\x20\x01(\x04B\0\x12\x0e\n\x04keys\x18\x06\x20\x01(\x04B\0\x12\
+    \x18\n\x0ekey_dirs_bytes\x18\x07\x20\x01(\x04B\0\x12\x14\n\nopen_files\
+    \x18\x08\x20\x01(\x04B\0\x12\x19\n\x0ffile_cache_hits\x18\t\x20\x01(\x04\
+    B\0\x12\x1b\n\x11file_cache_misses\x18\n\x20\x01(\x04B\0\x12\x1e\n\x14fi\
+    le_cache_evictions\x18\x0b\x20\x01(\x04B\0:\0\"C\n\x0bScanRequest\x12\
+    \x0f\n\x05range\x18\x02\x20\x01(\x03B\0\x12\x0f\n\x05regex\x18\x03\x20\
+    \x01(\tB\0\x12\x10\n\x06cursor\x18\x04\x20\x01(\x0cB\0:\0\"0\n\x08KeyBou\
+    nd\x12\r\n\x03key\x18\x01\x20\x01(\x0cB\0\x12\x13\n\tinclusive\x18\x02\
+    \x20\x01(\x08B\0:\0\"\xa9\x01\n\x10RangeScanRequest\x12'\n\x05start\x18\
+    \x01\x20\x01(\x0b2\x16.kv_server.v2.KeyBoundB\0\x12%\n\x03end\x18\x02\
+    \x20\x01(\x0b2\x16.kv_server.v2.KeyBoundB\0\x12!\n\x05order\x18\x03\x20\
+    \x01(\x0e2\x10.kv_server.OrderB\0\x12\x0f\n\x05limit\x18\x04\x20\x01(\
+    \x03B\0\x12\x0f\n\x05regex\x18\x05\x20\x01(\tB\0:\0\"g\n\x0cScanResponse\
+    \x12#\n\x06status\x18\x01\x20\x01(\x0e2\x11.kv_server.StatusB\0\x12\r\n\
+    \x03key\x18\x02\x20\x01(\x0cB\0\x12\x0f\n\x05value\x18\x03\x20\x01(\x0cB\
+    \0\x12\x10\n\x06cursor\x18\x04\x20\x01(\x0cB\0:\02\x9b\x06\n\x08KVServer\
+    \x12:\n\x05Serve\x12\x15.kv_server.v2.Request\x1a\x16.kv_server.v2.Respo\
+    nse(\00\0\x12>\n\x03Put\x12\x18.kv_server.v2.PutRequest\x1a\x19.kv_serve\
+    r.v2.PutResponse(\00\0\x12>\n\x03Get\x12\x18.kv_server.v2.GetRequest\x1a\
+    \x19.kv_server.v2.GetResponse(\00\0\x12G\n\x06Delete\x12\x1b.kv_server.v\
+    2.DeleteRequest\x1a\x1c.kv_server.v2.DeleteResponse(\00\0\x12D\n\x05Merg\
+    e\x12\x1a.kv_server.v2.MergeRequest\x1a\x1b.kv_server.v2.MergeResponse(\
+    \00\x01\x12D\n\x05Batch\x12\x1a.kv_server.v2.BatchRequest\x1a\x1b.kv_ser\
+    ver.v2.BatchResponse(\00\0\x12_\n\x0eCompareAndSwap\x12#.kv_server.v2.Co\
+    mpareAndSwapRequest\x1a$.kv_server.v2.CompareAndSwapResponse(\00\0\x12G\
+    \n\x06Commit\x12\x1b.kv_server.v2.CommitRequest\x1a\x1c.kv_server.v2.Com\
+    mitResponse(\00\0\x12D\n\x05Stats\x12\x1a.kv_server.v2.StatsRequest\x1a\
+    \x1b.kv_server.v2.StatsResponse(\00\0\x12A\n\x04Scan\x12\x19.kv_server.v\
+    2.ScanRequest\x1a\x1a.kv_server.v2.ScanResponse(\00\x01\x12K\n\tRangeSca\
+    n\x12\x1e.kv_server.v2.RangeScanRequest\x1a\x1a.kv_server.v2.ScanRespons\
+    e(\00\x01B\0b\x06proto3\
+";
+
+static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
+    lock: ::protobuf::lazy::ONCE_INIT,
+    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
+};
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    unsafe {
+        file_descriptor_proto_lazy.get(|| {
+            parse_descriptor_proto()
+        })
+    }
+}
@@ -0,0 +1,327 @@
+// This file is generated. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+const METHOD_KV_SERVER_SERVE: ::grpcio::Method<super::kv_server_v2::Request, super::kv_server_v2::Response> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.v2.KVServer/Serve",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_PUT: ::grpcio::Method<super::kv_server_v2::PutRequest, super::kv_server_v2::PutResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.v2.KVServer/Put",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_GET: ::grpcio::Method<super::kv_server_v2::GetRequest, super::kv_server_v2::GetResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.v2.KVServer/Get",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_DELETE: ::grpcio::Method<super::kv_server_v2::DeleteRequest, super::kv_server_v2::DeleteResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.v2.KVServer/Delete",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_MERGE: ::grpcio::Method<super::kv_server_v2::MergeRequest, super::kv_server_v2::MergeResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/kv_server.v2.KVServer/Merge",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_BATCH: ::grpcio::Method<super::kv_server_v2::BatchRequest, super::kv_server_v2::BatchResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.v2.KVServer/Batch",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_COMPARE_AND_SWAP: ::grpcio::Method<super::kv_server_v2::CompareAndSwapRequest, super::kv_server_v2::CompareAndSwapResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.v2.KVServer/CompareAndSwap",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_COMMIT: ::grpcio::Method<super::kv_server_v2::CommitRequest, super::kv_server_v2::CommitResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.v2.KVServer/Commit",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_STATS: ::grpcio::Method<super::kv_server_v2::StatsRequest, super::kv_server_v2::StatsResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/kv_server.v2.KVServer/Stats",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_SCAN: ::grpcio::Method<super::kv_server_v2::ScanRequest, super::kv_server_v2::ScanResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/kv_server.v2.KVServer/Scan",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_KV_SERVER_RANGE_SCAN: ::grpcio::Method<super::kv_server_v2::RangeScanRequest, super::kv_server_v2::ScanResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/kv_server.v2.KVServer/RangeScan",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+#[derive(Clone)]
+pub struct KvServerClient {
+    client: ::grpcio::Client,
+}
+
+impl KvServerClient {
+    pub fn new(channel: ::grpcio::Channel) -> Self {
+        KvServerClient {
+            client: ::grpcio::Client::new(channel),
+        }
+    }
+
+    pub fn serve_opt(&self, req: &super::kv_server_v2::Request, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server_v2::Response> {
+        self.client.unary_call(&METHOD_KV_SERVER_SERVE, req, opt)
+    }
+
+    pub fn serve(&self, req: &super::kv_server_v2::Request) -> ::grpcio::Result<super::kv_server_v2::Response> {
+        self.serve_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn serve_async_opt(&self, req: &super::kv_server_v2::Request, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::Response>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_SERVE, req, opt)
+    }
+
+    pub fn serve_async(&self, req: &super::kv_server_v2::Request) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::Response>> {
+        self.serve_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn put_opt(&self, req: &super::kv_server_v2::PutRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server_v2::PutResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_PUT, req, opt)
+    }
+
+    pub fn put(&self, req: &super::kv_server_v2::PutRequest) -> ::grpcio::Result<super::kv_server_v2::PutResponse> {
+        self.put_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn put_async_opt(&self, req: &super::kv_server_v2::PutRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::PutResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_PUT, req, opt)
+    }
+
+    pub fn put_async(&self, req: &super::kv_server_v2::PutRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::PutResponse>> {
+        self.put_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_opt(&self, req: &super::kv_server_v2::GetRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server_v2::GetResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_GET, req, opt)
+    }
+
+    pub fn get(&self, req: &super::kv_server_v2::GetRequest) -> ::grpcio::Result<super::kv_server_v2::GetResponse> {
+        self.get_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_async_opt(&self, req: &super::kv_server_v2::GetRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::GetResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_GET, req, opt)
+    }
+
+    pub fn get_async(&self, req: &super::kv_server_v2::GetRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::GetResponse>> {
+        self.get_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn delete_opt(&self, req: &super::kv_server_v2::DeleteRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server_v2::DeleteResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_DELETE, req, opt)
+    }
+
+    pub fn delete(&self, req: &super::kv_server_v2::DeleteRequest) -> ::grpcio::Result<super::kv_server_v2::DeleteResponse> {
+        self.delete_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn delete_async_opt(&self, req: &super::kv_server_v2::DeleteRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::DeleteResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_DELETE, req, opt)
+    }
+
+    pub fn delete_async(&self, req: &super::kv_server_v2::DeleteRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::DeleteResponse>> {
+        self.delete_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn merge_opt(&self, req: &super::kv_server_v2::MergeRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::kv_server_v2::MergeResponse>> {
+        self.client.server_streaming(&METHOD_KV_SERVER_MERGE, req, opt)
+    }
+
+    pub fn merge(&self, req: &super::kv_server_v2::MergeRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::kv_server_v2::MergeResponse>> {
+        self.merge_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn batch_opt(&self, req: &super::kv_server_v2::BatchRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server_v2::BatchResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_BATCH, req, opt)
+    }
+
+    pub fn batch(&self, req: &super::kv_server_v2::BatchRequest) -> ::grpcio::Result<super::kv_server_v2::BatchResponse> {
+        self.batch_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn batch_async_opt(&self, req: &super::kv_server_v2::BatchRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::BatchResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_BATCH, req, opt)
+    }
+
+    pub fn batch_async(&self, req: &super::kv_server_v2::BatchRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::BatchResponse>> {
+        self.batch_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn compare_and_swap_opt(&self, req: &super::kv_server_v2::CompareAndSwapRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server_v2::CompareAndSwapResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_COMPARE_AND_SWAP, req, opt)
+    }
+
+    pub fn compare_and_swap(&self, req: &super::kv_server_v2::CompareAndSwapRequest) -> ::grpcio::Result<super::kv_server_v2::CompareAndSwapResponse> {
+        self.compare_and_swap_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn compare_and_swap_async_opt(&self, req: &super::kv_server_v2::CompareAndSwapRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::CompareAndSwapResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_COMPARE_AND_SWAP, req, opt)
+    }
+
+    pub fn compare_and_swap_async(&self, req: &super::kv_server_v2::CompareAndSwapRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::CompareAndSwapResponse>> {
+        self.compare_and_swap_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn commit_opt(&self, req: &super::kv_server_v2::CommitRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server_v2::CommitResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_COMMIT, req, opt)
+    }
+
+    pub fn commit(&self, req: &super::kv_server_v2::CommitRequest) -> ::grpcio::Result<super::kv_server_v2::CommitResponse> {
+        self.commit_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn commit_async_opt(&self, req: &super::kv_server_v2::CommitRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::CommitResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_COMMIT, req, opt)
+    }
+
+    pub fn commit_async(&self, req: &super::kv_server_v2::CommitRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::CommitResponse>> {
+        self.commit_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn stats_opt(&self, req: &super::kv_server_v2::StatsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::kv_server_v2::StatsResponse> {
+        self.client.unary_call(&METHOD_KV_SERVER_STATS, req, opt)
+    }
+
+    pub fn stats(&self, req: &super::kv_server_v2::StatsRequest) -> ::grpcio::Result<super::kv_server_v2::StatsResponse> {
+        self.stats_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn stats_async_opt(&self, req: &super::kv_server_v2::StatsRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::StatsResponse>> {
+        self.client.unary_call_async(&METHOD_KV_SERVER_STATS, req, opt)
+    }
+
+    pub fn stats_async(&self, req: &super::kv_server_v2::StatsRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::kv_server_v2::StatsResponse>> {
+        self.stats_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn scan_opt(&self, req: &super::kv_server_v2::ScanRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::kv_server_v2::ScanResponse>> {
+        self.client.server_streaming(&METHOD_KV_SERVER_SCAN, req, opt)
+    }
+
+    pub fn scan(&self, req: &super::kv_server_v2::ScanRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::kv_server_v2::ScanResponse>> {
+        self.scan_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn range_scan_opt(&self, req: &super::kv_server_v2::RangeScanRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::kv_server_v2::ScanResponse>> {
+        self.client.server_streaming(&METHOD_KV_SERVER_RANGE_SCAN, req, opt)
+    }
+
+    pub fn range_scan(&self, req: &super::kv_server_v2::RangeScanRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::kv_server_v2::ScanResponse>> {
+        self.range_scan_opt(req, ::grpcio::CallOption::default())
+    }
+    pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item=(), Error=()> + Send + 'static {
+        self.client.spawn(f)
+    }
+}
+
+pub trait KvServer {
+    fn serve(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::Request, sink: ::grpcio::UnarySink<super::kv_server_v2::Response>);
+    fn put(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::PutRequest, sink: ::grpcio::UnarySink<super::kv_server_v2::PutResponse>);
+    fn get(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::GetRequest, sink: ::grpcio::UnarySink<super::kv_server_v2::GetResponse>);
+    fn delete(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::DeleteRequest, sink: ::grpcio::UnarySink<super::kv_server_v2::DeleteResponse>);
+    fn merge(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::MergeRequest, sink: ::grpcio::ServerStreamingSink<super::kv_server_v2::MergeResponse>);
+    fn batch(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::BatchRequest, sink: ::grpcio::UnarySink<super::kv_server_v2::BatchResponse>);
+    fn compare_and_swap(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::CompareAndSwapRequest, sink: ::grpcio::UnarySink<super::kv_server_v2::CompareAndSwapResponse>);
+    fn commit(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::CommitRequest, sink: ::grpcio::UnarySink<super::kv_server_v2::CommitResponse>);
+    fn stats(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::StatsRequest, sink: ::grpcio::UnarySink<super::kv_server_v2::StatsResponse>);
+    fn scan(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::ScanRequest, sink: ::grpcio::ServerStreamingSink<super::kv_server_v2::ScanResponse>);
+    fn range_scan(&mut self, ctx: ::grpcio::RpcContext, req: super::kv_server_v2::RangeScanRequest, sink: ::grpcio::ServerStreamingSink<super::kv_server_v2::ScanResponse>);
+}
+
+pub fn create_kv_server<S: KvServer + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
+    let mut builder = ::grpcio::ServiceBuilder::new();
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_SERVE, move |ctx, req, resp| {
+        instance.serve(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_PUT, move |ctx, req, resp| {
+        instance.put(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_GET, move |ctx, req, resp| {
+        instance.get(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_DELETE, move |ctx, req, resp| {
+        instance.delete(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_KV_SERVER_MERGE, move |ctx, req, resp| {
+        instance.merge(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_BATCH, move |ctx, req, resp| {
+        instance.batch(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_COMPARE_AND_SWAP, move |ctx, req, resp| {
+        instance.compare_and_swap(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_COMMIT, move |ctx, req, resp| {
+        instance.commit(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_KV_SERVER_STATS, move |ctx, req, resp| {
+        instance.stats(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_KV_SERVER_SCAN, move |ctx, req, resp| {
+        instance.scan(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_KV_SERVER_RANGE_SCAN, move |ctx, req, resp| {
+        instance.range_scan(ctx, req, resp)
+    });
+    builder.build()
+}
@@ -1,2 +1,4 @@
 pub mod kv_server;
 pub mod kv_server_grpc;
+pub mod kv_server_v2;
+pub mod kv_server_v2_grpc;
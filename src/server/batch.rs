@@ -0,0 +1,28 @@
+/// A group of puts and deletes, applied all-or-nothing by `Engine::write_batch`.
+#[derive(Clone, Debug, Default)]
+pub struct WriteBatch {
+    // `None` is a delete
+    pub ops: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+}
+
+impl WriteBatch {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn put(&mut self, k: Vec<u8>, v: Vec<u8>) {
+        self.ops.push((k, Some(v)));
+    }
+
+    pub fn del(&mut self, k: Vec<u8>) {
+        self.ops.push((k, None));
+    }
+
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+}
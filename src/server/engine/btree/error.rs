@@ -0,0 +1,27 @@
+#[derive(Debug, Fail)]
+pub enum BTreeEngineError {
+    #[fail(display = "Page: `{}`, corrupted", page_id)]
+    Corruption { page_id: u64 },
+    #[fail(display = "File: `{}`, no valid meta page", path)]
+    InvalidMeta { path: String },
+    #[fail(
+        display = "Page size mismatch, expected: `{}`, found: `{}`",
+        expected, found
+    )]
+    PageSizeMismatch { expected: usize, found: usize },
+    #[fail(
+        display = "Page size: `{}`, must be a power of two of at least `{}`",
+        page_size, min
+    )]
+    InvalidPageSize { page_size: usize, min: usize },
+    #[fail(display = "Key size: `{}`, exceeds limit: `{}`", size, limit)]
+    KeyTooLarge { size: usize, limit: usize },
+    #[fail(display = "Key/Value size: `{}`, exceeds limit: `{}`", size, limit)]
+    KeyValueTooLarge { size: usize, limit: usize },
+    #[fail(display = "Unsupported scanner")]
+    UnsupportedScanner,
+    #[fail(display = "Cursor: `{:?}`, invalid", cursor)]
+    InvalidCursor { cursor: Vec<u8> },
+    #[fail(display = "TTL unsupported")]
+    UnsupportedTtl,
+}
@@ -1 +1,292 @@
+mod error;
+mod options;
+mod page;
+mod pager;
+mod scanner;
+mod tree;
 
+pub use error::BTreeEngineError;
+pub use options::Options;
+pub use scanner::BTreeScanner;
+pub use tree::{BTree, BulkLoader};
+
+// --- std ---
+use std::{
+    sync::{Arc, Mutex, RwLock},
+    time::Duration,
+};
+// --- external ---
+use failure::Error;
+use regex::bytes::Regex;
+// --- custom ---
+use crate::{MergeProgress, Order, Scanner, Stats, Transaction, WriteBatch};
+
+const DB_FILE: &'static str = "btree.db";
+const MERGE_FILE: &'static str = "btree.db.merge";
+
+pub struct BTreeEngineBuilder {
+    options: Options,
+    storage_dir: String,
+}
+
+impl BTreeEngineBuilder {
+    pub fn new() -> Self {
+        Self {
+            options: Default::default(),
+            storage_dir: "kv-server-btree-engine-data".to_string(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn storage_dir(mut self, path: &str) -> Self {
+        self.storage_dir = path.to_string();
+        self
+    }
+
+    pub fn build(self) -> Result<BTreeEngine, Error> {
+        BTreeEngine::init(self)
+    }
+}
+
+#[derive(Clone)]
+pub struct BTreeEngine {
+    options: Options,
+
+    storage_dir: String,
+    tree: Arc<RwLock<BTree>>,
+    // the writes made while a merge rebuilds the tree, replayed onto the rebuilt one
+    merge_log: Arc<Mutex<Option<Vec<(Vec<u8>, Option<Vec<u8>>)>>>>,
+    merging: Arc<Mutex<()>>,
+}
+
+impl BTreeEngine {
+    fn init(builder: BTreeEngineBuilder) -> Result<BTreeEngine, Error> {
+        // --- std ---
+        use std::fs::{create_dir_all, remove_file};
+
+        create_dir_all(&builder.storage_dir)?;
+        // an unfinished merge never replaced the db file, drop it
+        let _ = remove_file(format!("{}/{}", builder.storage_dir, MERGE_FILE));
+
+        let tree = BTree::open(
+            &format!("{}/{}", builder.storage_dir, DB_FILE),
+            builder.options.page_size,
+            builder.options.cache_pages,
+        )?;
+
+        Ok(BTreeEngine {
+            options: builder.options,
+            storage_dir: builder.storage_dir,
+            tree: Arc::new(RwLock::new(tree)),
+            merge_log: Arc::new(Mutex::new(None)),
+            merging: Arc::new(Mutex::new(())),
+        })
+    }
+
+    // every write goes through here, under the tree write lock
+    fn write(&self, tree: &mut BTree, ops: Vec<(Vec<u8>, Option<Vec<u8>>)>) -> Result<(), Error> {
+        match self.merge_log.lock().unwrap().as_mut() {
+            Some(merge_log) => {
+                tree.write_batch(ops.clone())?;
+                merge_log.extend(ops);
+
+                Ok(())
+            }
+            None => tree.write_batch(ops),
+        }
+    }
+}
+
+impl super::Engine for BTreeEngine {
+    fn put(&mut self, k: Vec<u8>, v: Vec<u8>) -> Result<(), Error> {
+        self.write(&mut self.tree.write().unwrap(), vec![(k, Some(v))])
+    }
+
+    fn put_with_ttl(&mut self, _: Vec<u8>, _: Vec<u8>, _: Duration) -> Result<(), Error> {
+        Err(BTreeEngineError::UnsupportedTtl.into())
+    }
+
+    fn get(&mut self, k: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        self.tree.read().unwrap().get(k)
+    }
+
+    fn del(&mut self, k: &[u8]) -> Result<(), Error> {
+        self.write(&mut self.tree.write().unwrap(), vec![(k.to_vec(), None)])
+    }
+
+    fn replace(
+        &mut self,
+        k: Vec<u8>,
+        v: Option<Vec<u8>>,
+        ttl: Option<Duration>,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        if ttl.is_some() {
+            return Err(BTreeEngineError::UnsupportedTtl.into());
+        }
+
+        let mut tree = self.tree.write().unwrap();
+        let previous = tree.get(&k)?;
+        self.write(&mut tree, vec![(k, v)])?;
+
+        Ok(previous)
+    }
+
+    fn write_batch(&mut self, batch: WriteBatch) -> Result<(), Error> {
+        self.write(&mut self.tree.write().unwrap(), batch.ops)
+    }
+
+    fn commit(&mut self, transaction: Transaction) -> Result<bool, Error> {
+        let mut tree = self.tree.write().unwrap();
+        for (k, v) in transaction.reads.iter() {
+            if tree.get(k)? != *v {
+                return Ok(false);
+            }
+        }
+        if !transaction.writes.is_empty() {
+            self.write(&mut tree, transaction.writes.ops)?;
+        }
+
+        Ok(true)
+    }
+
+    fn scanner(&self, cursor: Vec<u8>, range: i64, regex: Option<Regex>) -> Scanner {
+        Scanner::BTreeScanner(BTreeScanner {
+            cursor,
+            range,
+            regex,
+        })
+    }
+
+    fn scan(&mut self, mut scanner: Scanner) -> Result<(Scanner, Vec<(Vec<u8>, Vec<u8>)>), Error> {
+        let kvs = match scanner {
+            Scanner::BTreeScanner(ref mut scanner) => scanner.scan(&self.tree.read().unwrap())?,
+            Scanner::RangeScanner(ref mut scanner) => {
+                let mut kvs = vec![];
+                let (start, end) = (
+                    scanner.start_key().map(|k| k.to_vec()),
+                    scanner.end_key().map(|k| k.to_vec()),
+                );
+                self.tree.read().unwrap().walk_range(
+                    start.as_ref().map(|k| k.as_slice()),
+                    end.as_ref().map(|k| k.as_slice()),
+                    scanner.order == Order::Desc,
+                    &mut |k, v| scanner.feed(k, v, &mut kvs),
+                )?;
+
+                kvs
+            }
+            _ => return Err(BTreeEngineError::UnsupportedScanner.into()),
+        };
+
+        Ok((scanner, kvs))
+    }
+
+    fn merge_with_progress(
+        &mut self,
+        progress: &mut dyn FnMut(&MergeProgress) -> bool,
+    ) -> Result<bool, Error> {
+        // --- std ---
+        use std::fs::{metadata, remove_file, rename, File};
+
+        let _merging = self.merging.lock().unwrap();
+        let db_path = format!("{}/{}", self.storage_dir, DB_FILE);
+        let merge_path = format!("{}/{}", self.storage_dir, MERGE_FILE);
+        let _ = remove_file(&merge_path);
+
+        // rebuilt from a snapshot, reads and writes go on meanwhile, the writes are logged
+        let snapshot = {
+            let tree = self.tree.read().unwrap();
+            *self.merge_log.lock().unwrap() = Some(vec![]);
+
+            tree.snapshot(&db_path)
+        };
+        let rebuilt = snapshot.and_then(|snapshot| {
+            let mut loader = BulkLoader::new(
+                &merge_path,
+                self.options.page_size,
+                self.options.cache_pages,
+            )?;
+            let mut result = Ok(());
+            snapshot.walk(&mut |k, v| {
+                result = loader.push(k.to_vec(), v.to_vec());
+                result.is_ok()
+            })?;
+            result?;
+
+            loader.finish()
+        });
+        // a failed merge leaves the db as it was, and no rebuilt file behind
+        let abort = |e: Error| {
+            *self.merge_log.lock().unwrap() = None;
+            let _ = remove_file(&merge_path);
+            Err(e)
+        };
+        let mut rebuilt = match rebuilt {
+            Ok(rebuilt) => rebuilt,
+            Err(e) => return abort(e),
+        };
+
+        // the rebuilt file is a single one, the last chance to cancel is before it replaces the db
+        let sizes = metadata(&merge_path)
+            .and_then(|rebuilt| Ok((rebuilt.len(), metadata(&db_path)?.len())));
+        let (bytes_rewritten, db_bytes) = match sizes {
+            Ok(sizes) => sizes,
+            Err(e) => return abort(e.into()),
+        };
+        if !progress(&MergeProgress {
+            files_merged: 1,
+            files_total: 1,
+            bytes_rewritten,
+            bytes_reclaimed: db_bytes.saturating_sub(bytes_rewritten),
+            eta: None,
+        }) {
+            *self.merge_log.lock().unwrap() = None;
+            remove_file(&merge_path)?;
+            return Ok(false);
+        }
+
+        // only the swap holds the write lock, with the writes missed by the snapshot
+        let mut w = self.tree.write().unwrap();
+        let merge_log = self.merge_log.lock().unwrap().take().unwrap_or_default();
+        // reopened before it replaces the db, the current tree stays if it can't be
+        let mut result = Ok(());
+        if !merge_log.is_empty() {
+            result = rebuilt.write_batch(merge_log);
+        }
+        drop(rebuilt);
+        let reopened = result.and_then(|_| {
+            BTree::open(
+                &merge_path,
+                self.options.page_size,
+                self.options.cache_pages,
+            )
+        });
+        let tree = match reopened {
+            Ok(tree) => tree,
+            Err(e) => return abort(e),
+        };
+        if let Err(e) = rename(&merge_path, &db_path) {
+            return abort(e.into());
+        }
+        // its handle follows the rename
+        *w = tree;
+        File::open(&self.storage_dir)?.sync_all()?;
+
+        Ok(true)
+    }
+
+    fn stats(&self) -> Stats {
+        Stats {
+            engine: "btree".to_string(),
+            // every commit is fsynced
+            durability: "sync".to_string(),
+            ..Default::default()
+        }
+    }
+}
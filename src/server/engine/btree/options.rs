@@ -0,0 +1,14 @@
+#[derive(Clone)]
+pub struct Options {
+    pub page_size: usize,
+    pub cache_pages: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            page_size: 4096,
+            cache_pages: 4096,
+        }
+    }
+}
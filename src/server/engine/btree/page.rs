@@ -0,0 +1,234 @@
+// --- external ---
+use failure::Error;
+// --- custom ---
+use super::BTreeEngineError;
+
+// crc : kind : count : payload
+// u32 :   u8 :   u32 :       ?
+pub const PAGE_HEADER_SIZE: usize = 9;
+// key size : value size :  key :  value
+//      u32 :        u32 :    ? :      ?
+// an overflowed value is the id of its first overflow page, flagged in the value size
+pub const LEAF_ENTRY_OVERHEAD: usize = 8;
+// key size :  key : child
+//      u32 :    ? :   u64
+pub const INTERNAL_ENTRY_OVERHEAD: usize = 12;
+// crc : kind : count : next : bytes
+// u32 :   u8 :   u32 :  u64 :     ?
+// `count` bytes of a value, the rest of it is in `next`, 0 for the last page
+pub const OVERFLOW_HEADER_SIZE: usize = 17;
+// the largest value size, the top bit of the value size flags an overflowed value
+pub const VALUE_SIZE_LIMIT: usize = OVERFLOW_FLAG as usize - 1;
+
+const LEAF: u8 = 0;
+const INTERNAL: u8 = 1;
+const OVERFLOW: u8 = 2;
+const OVERFLOW_FLAG: u32 = 1 << 31;
+
+/// The value of a leaf entry, in overflow pages if it doesn't fit in the leaf.
+#[derive(Clone, Debug)]
+pub enum LeafValue {
+    Inline(Vec<u8>),
+    // the first page of the chain, and the size of the whole value
+    Overflow { page_id: u64, size: u32 },
+}
+
+impl LeafValue {
+    // the bytes it takes in the leaf
+    pub fn encoded_size(&self) -> usize {
+        match self {
+            LeafValue::Inline(v) => v.len(),
+            LeafValue::Overflow { .. } => 8,
+        }
+    }
+}
+
+/// A B+tree node, stored in exactly one page.
+///
+/// Internal nodes hold `keys.len() + 1` children, `children[i]` covers keys in
+/// `[keys[i - 1], keys[i])`.
+#[derive(Clone, Debug)]
+pub enum Node {
+    Leaf(Vec<(Vec<u8>, LeafValue)>),
+    Internal {
+        keys: Vec<Vec<u8>>,
+        children: Vec<u64>,
+    },
+}
+
+impl Node {
+    pub fn encoded_size(&self) -> usize {
+        match self {
+            Node::Leaf(entries) => entries.iter().fold(PAGE_HEADER_SIZE, |size, (k, v)| {
+                size + LEAF_ENTRY_OVERHEAD + k.len() + v.encoded_size()
+            }),
+            Node::Internal { keys, .. } => keys.iter().fold(PAGE_HEADER_SIZE + 8, |size, k| {
+                size + INTERNAL_ENTRY_OVERHEAD + k.len()
+            }),
+        }
+    }
+
+    pub fn encode(&self, page_size: usize) -> Vec<u8> {
+        let mut buffer = vec![0; 4];
+
+        match self {
+            Node::Leaf(entries) => {
+                buffer.push(LEAF);
+                buffer.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+                for (k, v) in entries {
+                    buffer.extend_from_slice(&(k.len() as u32).to_le_bytes());
+                    match v {
+                        LeafValue::Inline(v) => {
+                            buffer.extend_from_slice(&(v.len() as u32).to_le_bytes());
+                            buffer.extend_from_slice(k);
+                            buffer.extend_from_slice(v);
+                        }
+                        LeafValue::Overflow { page_id, size } => {
+                            buffer.extend_from_slice(&(size | OVERFLOW_FLAG).to_le_bytes());
+                            buffer.extend_from_slice(k);
+                            buffer.extend_from_slice(&page_id.to_le_bytes());
+                        }
+                    }
+                }
+            }
+            Node::Internal { keys, children } => {
+                buffer.push(INTERNAL);
+                buffer.extend_from_slice(&(keys.len() as u32).to_le_bytes());
+                buffer.extend_from_slice(&children[0].to_le_bytes());
+                for (k, child) in keys.iter().zip(children[1..].iter()) {
+                    buffer.extend_from_slice(&(k.len() as u32).to_le_bytes());
+                    buffer.extend_from_slice(k);
+                    buffer.extend_from_slice(&child.to_le_bytes());
+                }
+            }
+        }
+
+        seal(buffer, page_size)
+    }
+
+    pub fn decode(page_id: u64, bytes: &[u8]) -> Result<Self, Error> {
+        let corruption = || BTreeEngineError::Corruption { page_id };
+
+        if !is_sealed(bytes, PAGE_HEADER_SIZE) {
+            return Err(corruption().into());
+        }
+
+        let count = read_u32(bytes, 5) as usize;
+        let mut offset = PAGE_HEADER_SIZE;
+        let mut take = |size: usize| -> Result<&[u8], BTreeEngineError> {
+            if offset + size > bytes.len() {
+                return Err(corruption());
+            }
+
+            offset += size;
+            Ok(&bytes[offset - size..offset])
+        };
+
+        match bytes[4] {
+            LEAF => {
+                let mut entries = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let key_size = read_u32(take(4)?, 0) as usize;
+                    let value_size = read_u32(take(4)?, 0);
+                    let k = take(key_size)?.to_vec();
+                    let v = if value_size & OVERFLOW_FLAG == 0 {
+                        LeafValue::Inline(take(value_size as usize)?.to_vec())
+                    } else {
+                        LeafValue::Overflow {
+                            page_id: read_u64(take(8)?, 0),
+                            size: value_size & !OVERFLOW_FLAG,
+                        }
+                    };
+                    entries.push((k, v));
+                }
+
+                Ok(Node::Leaf(entries))
+            }
+            INTERNAL => {
+                let mut keys = Vec::with_capacity(count);
+                let mut children = Vec::with_capacity(count + 1);
+                children.push(read_u64(take(8)?, 0));
+                for _ in 0..count {
+                    let key_size = read_u32(take(4)?, 0) as usize;
+                    keys.push(take(key_size)?.to_vec());
+                    children.push(read_u64(take(8)?, 0));
+                }
+
+                Ok(Node::Internal { keys, children })
+            }
+            _ => Err(corruption().into()),
+        }
+    }
+}
+
+/// A page of a value too large for its leaf, chained to the next one.
+pub struct Overflow<'a> {
+    pub next: u64,
+    pub bytes: &'a [u8],
+}
+
+impl<'a> Overflow<'a> {
+    /// The part of a value that fits in a page of `page_size`.
+    pub fn capacity(page_size: usize) -> usize {
+        page_size - OVERFLOW_HEADER_SIZE
+    }
+
+    pub fn encode(&self, page_size: usize) -> Vec<u8> {
+        let mut buffer = vec![0; 4];
+        buffer.push(OVERFLOW);
+        buffer.extend_from_slice(&(self.bytes.len() as u32).to_le_bytes());
+        buffer.extend_from_slice(&self.next.to_le_bytes());
+        buffer.extend_from_slice(self.bytes);
+
+        seal(buffer, page_size)
+    }
+
+    pub fn decode(page_id: u64, bytes: &'a [u8]) -> Result<Self, Error> {
+        let corruption = || BTreeEngineError::Corruption { page_id };
+
+        if !is_sealed(bytes, OVERFLOW_HEADER_SIZE) || bytes[4] != OVERFLOW {
+            return Err(corruption().into());
+        }
+
+        let count = read_u32(bytes, 5) as usize;
+        Ok(Self {
+            next: read_u64(bytes, 9),
+            bytes: bytes
+                .get(OVERFLOW_HEADER_SIZE..OVERFLOW_HEADER_SIZE + count)
+                .ok_or_else(corruption)?,
+        })
+    }
+}
+
+// pad a page to `page_size`, with the crc of the rest of it in front
+fn seal(mut buffer: Vec<u8>, page_size: usize) -> Vec<u8> {
+    // --- external ---
+    use crc::crc32::checksum_ieee;
+
+    buffer.resize(page_size, 0);
+    let crc = checksum_ieee(&buffer[4..]);
+    buffer[..4].copy_from_slice(&crc.to_le_bytes());
+
+    buffer
+}
+
+fn is_sealed(bytes: &[u8], header_size: usize) -> bool {
+    // --- external ---
+    use crc::crc32::checksum_ieee;
+
+    bytes.len() >= header_size && read_u32(bytes, 0) == checksum_ieee(&bytes[4..])
+}
+
+pub fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    let mut buffer = [0; 4];
+    buffer.copy_from_slice(&bytes[offset..offset + 4]);
+
+    u32::from_le_bytes(buffer)
+}
+
+pub fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    let mut buffer = [0; 8];
+    buffer.copy_from_slice(&bytes[offset..offset + 8]);
+
+    u64::from_le_bytes(buffer)
+}
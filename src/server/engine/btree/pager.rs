@@ -0,0 +1,293 @@
+// --- std ---
+use std::{
+    fs::File,
+    os::unix::fs::FileExt,
+    sync::{Arc, RwLock},
+};
+// --- external ---
+use failure::Error;
+use hashbrown::HashMap;
+// --- custom ---
+use super::{
+    page::{read_u32, read_u64, Node, Overflow},
+    BTreeEngineError,
+};
+
+const MAGIC: u32 = 0x4b56_4254;
+// a page holds at least a few entries of a reasonable size, and the meta
+pub const MIN_PAGE_SIZE: usize = 512;
+// magic : crc : page size : txid : root : page count
+//   u32 : u32 :       u32 :  u64 :  u64 :        u64
+const META_SIZE: usize = 36;
+const META_PAGES: u64 = 2;
+
+/// The committed state of the tree, double buffered in page `0` and `1`.
+#[derive(Clone, Copy, Debug)]
+pub struct Meta {
+    pub txid: u64,
+    pub root: u64,
+    pub page_count: u64,
+}
+
+impl Meta {
+    fn encode(&self, page_size: usize) -> Vec<u8> {
+        // --- external ---
+        use crc::crc32::checksum_ieee;
+
+        let mut buffer = vec![0; 8];
+        buffer[..4].copy_from_slice(&MAGIC.to_le_bytes());
+        buffer.extend_from_slice(&(page_size as u32).to_le_bytes());
+        buffer.extend_from_slice(&self.txid.to_le_bytes());
+        buffer.extend_from_slice(&self.root.to_le_bytes());
+        buffer.extend_from_slice(&self.page_count.to_le_bytes());
+
+        let crc = checksum_ieee(&buffer[8..]);
+        buffer[4..8].copy_from_slice(&crc.to_le_bytes());
+
+        buffer
+    }
+
+    fn decode(bytes: &[u8]) -> Option<(Self, usize)> {
+        // --- external ---
+        use crc::crc32::checksum_ieee;
+
+        if read_u32(bytes, 0) != MAGIC || read_u32(bytes, 4) != checksum_ieee(&bytes[8..META_SIZE])
+        {
+            return None;
+        }
+
+        Some((
+            Self {
+                txid: read_u64(bytes, 12),
+                root: read_u64(bytes, 20),
+                page_count: read_u64(bytes, 28),
+            },
+            read_u32(bytes, 8) as usize,
+        ))
+    }
+}
+
+/// Append-only page file.
+///
+/// Pages are never overwritten once written, so a crash can only lose the
+/// pages appended after the last committed `Meta`.
+pub struct Pager {
+    pub page_size: usize,
+    pub page_count: u64,
+
+    file: File,
+    cache: RwLock<HashMap<u64, Arc<Node>>>,
+    pub cache_pages: usize,
+    // pages no committed tree or snapshot reads anymore, written to before appending
+    free: Vec<u64>,
+}
+
+impl Pager {
+    pub fn open(path: &str, page_size: usize, cache_pages: usize) -> Result<(Self, Meta), Error> {
+        // --- std ---
+        use std::fs::OpenOptions;
+
+        if page_size < MIN_PAGE_SIZE || !page_size.is_power_of_two() {
+            return Err(BTreeEngineError::InvalidPageSize {
+                page_size,
+                min: MIN_PAGE_SIZE,
+            }
+            .into());
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .write(true)
+            .open(path)?;
+        let mut pager = Pager {
+            page_size,
+            page_count: META_PAGES,
+            file,
+            cache: RwLock::new(HashMap::new()),
+            cache_pages,
+            free: vec![],
+        };
+
+        if pager.file.metadata()?.len() == 0 {
+            let root = pager.write(Node::Leaf(vec![]))?;
+            let meta = Meta {
+                txid: 1,
+                root,
+                page_count: pager.page_count,
+            };
+            pager.commit(&meta)?;
+
+            return Ok((pager, meta));
+        }
+
+        let mut latest: Option<Meta> = None;
+        for slot in 0..META_PAGES {
+            let mut bytes = [0; META_SIZE];
+            if pager
+                .file
+                .read_exact_at(&mut bytes, slot * page_size as u64)
+                .is_err()
+            {
+                continue;
+            }
+
+            if let Some((meta, found)) = Meta::decode(&bytes) {
+                if found != page_size {
+                    return Err(BTreeEngineError::PageSizeMismatch {
+                        expected: page_size,
+                        found,
+                    }
+                    .into());
+                }
+
+                if latest.map_or(true, |latest| meta.txid > latest.txid) {
+                    latest = Some(meta);
+                }
+            }
+        }
+
+        if let Some(meta) = latest {
+            // drop the pages of an uncommitted write
+            pager.file.set_len(meta.page_count * page_size as u64)?;
+            pager.page_count = meta.page_count;
+
+            Ok((pager, meta))
+        } else {
+            Err(BTreeEngineError::InvalidMeta {
+                path: path.to_string(),
+            }
+            .into())
+        }
+    }
+
+    /// A read-only handle on the first `page_count` pages of the file at `path`.
+    pub fn reader(
+        path: &str,
+        page_size: usize,
+        cache_pages: usize,
+        page_count: u64,
+    ) -> Result<Self, Error> {
+        Ok(Pager {
+            page_size,
+            page_count,
+            file: File::open(path)?,
+            cache: RwLock::new(HashMap::new()),
+            cache_pages,
+            free: vec![],
+        })
+    }
+
+    pub fn read(&self, page_id: u64) -> Result<Arc<Node>, Error> {
+        if let Some(node) = self.cache.read().unwrap().get(&page_id) {
+            return Ok(node.clone());
+        }
+
+        let node = Arc::new(Node::decode(page_id, &self.read_page(page_id)?)?);
+        self.cache_node(page_id, node.clone());
+
+        Ok(node)
+    }
+
+    pub fn write(&mut self, node: Node) -> Result<u64, Error> {
+        let page_id = self.write_page(&node.encode(self.page_size))?;
+        self.cache_node(page_id, Arc::new(node));
+
+        Ok(page_id)
+    }
+
+    /// Read back the `size` bytes of a value written by `write_overflow`, they're not cached.
+    pub fn read_overflow(&self, mut page_id: u64, size: usize) -> Result<Vec<u8>, Error> {
+        let mut v = Vec::with_capacity(size);
+        while v.len() < size {
+            let bytes = self.read_page(page_id)?;
+            let overflow = Overflow::decode(page_id, &bytes)?;
+            if overflow.bytes.is_empty() || v.len() + overflow.bytes.len() > size {
+                return Err(BTreeEngineError::Corruption { page_id }.into());
+            }
+
+            v.extend_from_slice(overflow.bytes);
+            page_id = overflow.next;
+        }
+
+        Ok(v)
+    }
+
+    /// The pages of a value written by `write_overflow`.
+    pub fn overflow_pages(&self, mut page_id: u64, size: usize) -> Result<Vec<u64>, Error> {
+        let capacity = Overflow::capacity(self.page_size);
+        let count = (size + capacity - 1) / capacity;
+        let mut pages = Vec::with_capacity(count);
+        while pages.len() < count {
+            pages.push(page_id);
+            page_id = Overflow::decode(page_id, &self.read_page(page_id)?)?.next;
+        }
+
+        Ok(pages)
+    }
+
+    /// Hand out `pages` again, once nothing reads them anymore.
+    pub fn reuse(&mut self, pages: &[u64]) {
+        self.free.extend_from_slice(pages);
+    }
+
+    /// Write `v` across as many overflow pages as it takes, returning the first one.
+    pub fn write_overflow(&mut self, v: &[u8]) -> Result<u64, Error> {
+        // from the last page, every page points to the one written before it
+        let mut next = 0;
+        for bytes in v.chunks(Overflow::capacity(self.page_size)).rev() {
+            next = self.write_page(&Overflow { next, bytes }.encode(self.page_size))?;
+        }
+
+        Ok(next)
+    }
+
+    pub fn commit(&mut self, meta: &Meta) -> Result<(), Error> {
+        self.file.sync_data()?;
+        self.file.write_all_at(
+            &meta.encode(self.page_size),
+            (meta.txid % META_PAGES) * self.page_size as u64,
+        )?;
+        self.file.sync_data()?;
+
+        Ok(())
+    }
+
+    fn read_page(&self, page_id: u64) -> Result<Vec<u8>, Error> {
+        if page_id < META_PAGES || page_id >= self.page_count {
+            return Err(BTreeEngineError::Corruption { page_id }.into());
+        }
+
+        let mut bytes = vec![0; self.page_size];
+        self.file
+            .read_exact_at(&mut bytes, page_id * self.page_size as u64)?;
+
+        Ok(bytes)
+    }
+
+    fn write_page(&mut self, bytes: &[u8]) -> Result<u64, Error> {
+        let page_id = match self.free.pop() {
+            Some(page_id) => {
+                // it may have held a node
+                self.cache.write().unwrap().remove(&page_id);
+                page_id
+            }
+            None => {
+                self.page_count += 1;
+                self.page_count - 1
+            }
+        };
+        self.file
+            .write_all_at(bytes, page_id * self.page_size as u64)?;
+
+        Ok(page_id)
+    }
+
+    fn cache_node(&self, page_id: u64, node: Arc<Node>) {
+        let mut w = self.cache.write().unwrap();
+        if w.len() >= self.cache_pages {
+            w.clear();
+        }
+        w.insert(page_id, node);
+    }
+}
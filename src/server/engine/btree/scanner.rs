@@ -0,0 +1,60 @@
+// --- external ---
+use failure::Error;
+use regex::bytes::Regex;
+// --- custom ---
+use super::{tree::BTree, BTreeEngineError};
+use crate::server::scanner::{cursor_key, key_cursor};
+
+/// `cursor` is the last key visited, see `key_cursor`, empty means start over,
+/// and it's set back to empty once the scan is complete.
+pub struct BTreeScanner {
+    pub cursor: Vec<u8>,
+    pub range: i64,
+    pub regex: Option<Regex>,
+}
+
+impl BTreeScanner {
+    pub fn scan(&mut self, tree: &BTree) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Error> {
+        let mut matched_kvs = vec![];
+        let start = cursor_key(&self.cursor)
+            .map_err(|_| BTreeEngineError::InvalidCursor {
+                cursor: self.cursor.clone(),
+            })?
+            .map(|k| k.to_vec());
+        let mut last_key = None;
+
+        let complete = tree.walk_range(
+            start.as_ref().map(|k| k.as_slice()),
+            None,
+            false,
+            &mut |k, v| {
+                if start.as_ref().map_or(false, |start| k <= start.as_slice()) {
+                    return true;
+                }
+                if self.range == 0 {
+                    return false;
+                }
+                self.range -= 1;
+                last_key = Some(k.to_owned());
+
+                if let Some(ref regex) = self.regex {
+                    if regex.is_match(k) {
+                        matched_kvs.push((k.to_owned(), v.to_owned()));
+                    }
+                } else {
+                    matched_kvs.push((k.to_owned(), v.to_owned()));
+                }
+
+                true
+            },
+        )?;
+
+        if complete {
+            self.cursor.clear();
+        } else if let Some(k) = last_key {
+            self.cursor = key_cursor(&k);
+        }
+
+        Ok(matched_kvs)
+    }
+}
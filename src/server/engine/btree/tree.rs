@@ -0,0 +1,610 @@
+// --- std ---
+use std::{
+    borrow::Cow,
+    sync::{Arc, Mutex, Weak},
+};
+// --- external ---
+use failure::Error;
+// --- custom ---
+use super::{
+    page::{
+        LeafValue, Node, INTERNAL_ENTRY_OVERHEAD, LEAF_ENTRY_OVERHEAD, PAGE_HEADER_SIZE,
+        VALUE_SIZE_LIMIT,
+    },
+    pager::{Meta, Pager},
+    BTreeEngineError,
+};
+
+enum Inserted {
+    One(u64),
+    Split(u64, Vec<u8>, u64),
+}
+
+enum Removed {
+    NotFound,
+    Empty,
+    One(u64),
+}
+
+/// Copy-on-write B+tree on top of a `Pager`.
+///
+/// Every write rewrites the path from the leaf to the root into new pages
+/// and then commits a new `Meta`, the previous version stays intact on disk
+/// until no snapshot of it is left, then its pages are reused by the next
+/// writes. The pages freed before a restart are only reclaimed by `merge`.
+pub struct BTree {
+    pager: Pager,
+    meta: Meta,
+
+    // pages replaced by the uncommitted write
+    freed: Vec<u64>,
+    // pages replaced by every commit (its txid) a snapshot may still read
+    pending: Vec<(u64, Vec<u64>)>,
+    snapshots: Mutex<Vec<Weak<u64>>>,
+    // the txid of the view of a snapshot, only held to keep its pages, see `snapshot`
+    #[allow(dead_code)]
+    generation: Option<Arc<u64>>,
+}
+
+impl BTree {
+    pub fn open(path: &str, page_size: usize, cache_pages: usize) -> Result<Self, Error> {
+        let (pager, meta) = Pager::open(path, page_size, cache_pages)?;
+
+        Ok(Self::new(pager, meta))
+    }
+
+    fn new(pager: Pager, meta: Meta) -> Self {
+        Self {
+            pager,
+            meta,
+            freed: vec![],
+            pending: vec![],
+            snapshots: Mutex::new(vec![]),
+            generation: None,
+        }
+    }
+
+    /// Build a compact tree at `path` from sorted `entries`.
+    pub fn bulk_load<I>(
+        path: &str,
+        page_size: usize,
+        cache_pages: usize,
+        entries: I,
+    ) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (Vec<u8>, Vec<u8>)>,
+    {
+        let mut loader = BulkLoader::new(path, page_size, cache_pages)?;
+        for (k, v) in entries {
+            loader.push(k, v)?;
+        }
+
+        loader.finish()
+    }
+
+    /// A read-only view of the committed tree, through a handle of its own on `path`.
+    ///
+    /// The pages of the view aren't reused until it's dropped, it stays valid while the
+    /// tree is written to.
+    pub fn snapshot(&self, path: &str) -> Result<Self, Error> {
+        let generation = Arc::new(self.meta.txid);
+        self.snapshots
+            .lock()
+            .unwrap()
+            .push(Arc::downgrade(&generation));
+
+        Ok(Self {
+            generation: Some(generation),
+            ..Self::new(
+                Pager::reader(
+                    path,
+                    self.pager.page_size,
+                    self.pager.cache_pages,
+                    self.meta.page_count,
+                )?,
+                self.meta,
+            )
+        })
+    }
+
+    /// The largest key + value size a page can always fit, a larger value goes to
+    /// overflow pages, see `leaf_value`.
+    pub fn entry_size_limit(page_size: usize) -> usize {
+        (page_size - PAGE_HEADER_SIZE - 8) / 4 - INTERNAL_ENTRY_OVERHEAD
+    }
+
+    pub fn get(&self, k: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        let mut page_id = self.meta.root;
+        loop {
+            let node = self.pager.read(page_id)?;
+            match node.as_ref() {
+                Node::Leaf(entries) => {
+                    return match entries.binary_search_by(|(key, _)| key.as_slice().cmp(k)) {
+                        Ok(i) => Ok(Some(self.value(&entries[i].1)?.into_owned())),
+                        Err(_) => Ok(None),
+                    };
+                }
+                Node::Internal { keys, children } => page_id = children[Self::child_index(keys, k)],
+            }
+        }
+    }
+
+    pub fn put(&mut self, k: Vec<u8>, v: Vec<u8>) -> Result<(), Error> {
+        self.write_batch(vec![(k, Some(v))])
+    }
+
+    pub fn del(&mut self, k: &[u8]) -> Result<(), Error> {
+        self.write_batch(vec![(k.to_vec(), None)])
+    }
+
+    /// Apply every put (`Some`) and delete (`None`) under a single commit.
+    pub fn write_batch(&mut self, ops: Vec<(Vec<u8>, Option<Vec<u8>>)>) -> Result<(), Error> {
+        let result = self.apply(ops);
+        // the committed tree still points to the pages a failed write replaced
+        if result.is_err() {
+            self.freed.clear();
+        }
+
+        result
+    }
+
+    fn apply(&mut self, ops: Vec<(Vec<u8>, Option<Vec<u8>>)>) -> Result<(), Error> {
+        let mut root = self.meta.root;
+        for (k, v) in ops {
+            match v {
+                Some(v) => root = self.put_at(root, k, v)?,
+                None => {
+                    if let Some(new_root) = self.del_at(root, &k)? {
+                        root = new_root;
+                    }
+                }
+            }
+        }
+
+        // only deletes of missing keys, nothing to commit
+        if root == self.meta.root {
+            return Ok(());
+        }
+
+        self.commit(root)
+    }
+
+    // returns the new root, uncommitted
+    fn put_at(&mut self, root: u64, k: Vec<u8>, v: Vec<u8>) -> Result<u64, Error> {
+        let v = leaf_value(&mut self.pager, &k, v)?;
+
+        Ok(match self.insert(root, k, v)? {
+            Inserted::One(page_id) => page_id,
+            Inserted::Split(left, k, right) => self.pager.write(Node::Internal {
+                keys: vec![k],
+                children: vec![left, right],
+            })?,
+        })
+    }
+
+    // returns the new root, uncommitted, `None` if `k` doesn't exist
+    fn del_at(&mut self, root: u64, k: &[u8]) -> Result<Option<u64>, Error> {
+        Ok(match self.remove(root, k)? {
+            Removed::NotFound => None,
+            Removed::Empty => Some(self.pager.write(Node::Leaf(vec![]))?),
+            Removed::One(page_id) => Some(page_id),
+        })
+    }
+
+    /// Visit every entry in key order until `f` returns `false`.
+    pub fn walk<F>(&self, f: &mut F) -> Result<bool, Error>
+    where
+        F: FnMut(&[u8], &[u8]) -> bool,
+    {
+        self.walk_page(self.meta.root, f)
+    }
+
+    /// Visit the entries between `start` and `end` in key order, or in reverse
+    /// order, until `f` returns `false`, skipping the subtrees out of range.
+    pub fn walk_range<F>(
+        &self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        reverse: bool,
+        f: &mut F,
+    ) -> Result<bool, Error>
+    where
+        F: FnMut(&[u8], &[u8]) -> bool,
+    {
+        self.walk_range_page(self.meta.root, start, end, reverse, f)
+    }
+
+    fn walk_range_page<F>(
+        &self,
+        page_id: u64,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        reverse: bool,
+        f: &mut F,
+    ) -> Result<bool, Error>
+    where
+        F: FnMut(&[u8], &[u8]) -> bool,
+    {
+        let node = self.pager.read(page_id)?;
+        match node.as_ref() {
+            Node::Leaf(entries) => {
+                let mut visit = |(k, v): &(Vec<u8>, LeafValue)| -> Result<bool, Error> {
+                    Ok(f(k, &self.value(v)?))
+                };
+                if reverse {
+                    for entry in entries.iter().rev() {
+                        if !visit(entry)? {
+                            return Ok(false);
+                        }
+                    }
+                } else {
+                    for entry in entries.iter() {
+                        if !visit(entry)? {
+                            return Ok(false);
+                        }
+                    }
+                }
+            }
+            Node::Internal { keys, children } => {
+                let first = start.map_or(0, |k| Self::child_index(keys, k));
+                let last = end.map_or(children.len() - 1, |k| Self::child_index(keys, k));
+                let mut range = (first..=last).collect::<Vec<_>>();
+                if reverse {
+                    range.reverse();
+                }
+
+                for i in range {
+                    if !self.walk_range_page(children[i], start, end, reverse, f)? {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn walk_page<F>(&self, page_id: u64, f: &mut F) -> Result<bool, Error>
+    where
+        F: FnMut(&[u8], &[u8]) -> bool,
+    {
+        let node = self.pager.read(page_id)?;
+        match node.as_ref() {
+            Node::Leaf(entries) => {
+                for (k, v) in entries {
+                    if !f(k, &self.value(v)?) {
+                        return Ok(false);
+                    }
+                }
+            }
+            Node::Internal { children, .. } => {
+                for &child in children {
+                    if !self.walk_page(child, f)? {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn commit(&mut self, root: u64) -> Result<(), Error> {
+        let meta = Meta {
+            txid: self.meta.txid + 1,
+            root,
+            page_count: self.pager.page_count,
+        };
+        self.pager.commit(&meta)?;
+        self.meta = meta;
+
+        // the pages replaced by a commit are only read by the snapshots taken before it
+        let oldest = {
+            let mut snapshots = self.snapshots.lock().unwrap();
+            snapshots.retain(|generation| generation.upgrade().is_some());
+            snapshots
+                .iter()
+                .filter_map(|generation| generation.upgrade().map(|txid| *txid))
+                .min()
+                .unwrap_or(meta.txid)
+        };
+        self.pending
+            .push((meta.txid, std::mem::take(&mut self.freed)));
+        let pager = &mut self.pager;
+        self.pending.retain(|(txid, pages)| {
+            if *txid <= oldest {
+                pager.reuse(pages);
+                false
+            } else {
+                true
+            }
+        });
+
+        Ok(())
+    }
+
+    // a page of the committed tree replaced by the write
+    fn free(&mut self, page_id: u64) {
+        self.freed.push(page_id);
+    }
+
+    // the overflow pages of a value the write drops
+    fn free_value(&mut self, v: &LeafValue) -> Result<(), Error> {
+        if let LeafValue::Overflow { page_id, size } = v {
+            let pages = self.pager.overflow_pages(*page_id, *size as _)?;
+            self.freed.extend(pages);
+        }
+
+        Ok(())
+    }
+
+    // the bytes of a value, read from its overflow pages if it has any
+    fn value<'a>(&self, v: &'a LeafValue) -> Result<Cow<'a, [u8]>, Error> {
+        match v {
+            LeafValue::Inline(v) => Ok(Cow::Borrowed(v)),
+            LeafValue::Overflow { page_id, size } => {
+                Ok(Cow::Owned(self.pager.read_overflow(*page_id, *size as _)?))
+            }
+        }
+    }
+
+    fn first_key(entries: &[(Vec<u8>, LeafValue)]) -> Vec<u8> {
+        entries.first().map(|(k, _)| k.clone()).unwrap_or_default()
+    }
+
+    fn child_index(keys: &[Vec<u8>], k: &[u8]) -> usize {
+        match keys.binary_search_by(|key| key.as_slice().cmp(k)) {
+            Ok(i) => i + 1,
+            Err(i) => i,
+        }
+    }
+
+    fn insert(&mut self, page_id: u64, k: Vec<u8>, v: LeafValue) -> Result<Inserted, Error> {
+        let node = self.pager.read(page_id)?;
+        let node = match node.as_ref() {
+            Node::Leaf(entries) => {
+                let mut entries = entries.clone();
+                match entries.binary_search_by(|(key, _)| key.cmp(&k)) {
+                    Ok(i) => {
+                        let previous = std::mem::replace(&mut entries[i].1, v);
+                        self.free_value(&previous)?;
+                    }
+                    Err(i) => entries.insert(i, (k, v)),
+                }
+
+                Node::Leaf(entries)
+            }
+            Node::Internal { keys, children } => {
+                let i = Self::child_index(keys, &k);
+                let mut keys = keys.clone();
+                let mut children = children.clone();
+                match self.insert(children[i], k, v)? {
+                    Inserted::One(child) => children[i] = child,
+                    Inserted::Split(left, k, right) => {
+                        children[i] = left;
+                        children.insert(i + 1, right);
+                        keys.insert(i, k);
+                    }
+                }
+
+                Node::Internal { keys, children }
+            }
+        };
+        self.free(page_id);
+
+        let total = node.encoded_size();
+        if total <= self.pager.page_size {
+            return Ok(Inserted::One(self.pager.write(node)?));
+        }
+
+        match node {
+            Node::Leaf(mut entries) => {
+                let mut left_size = PAGE_HEADER_SIZE;
+                let mut mid = 0;
+                while mid < entries.len() - 1 && left_size < total / 2 {
+                    left_size +=
+                        LEAF_ENTRY_OVERHEAD + entries[mid].0.len() + entries[mid].1.encoded_size();
+                    mid += 1;
+                }
+                let right = entries.split_off(mid.max(1));
+                let separator = right[0].0.clone();
+
+                Ok(Inserted::Split(
+                    self.pager.write(Node::Leaf(entries))?,
+                    separator,
+                    self.pager.write(Node::Leaf(right))?,
+                ))
+            }
+            Node::Internal {
+                mut keys,
+                mut children,
+            } => {
+                let mid = keys.len() / 2;
+                let right_keys = keys.split_off(mid + 1);
+                let separator = keys.pop().unwrap();
+                let right_children = children.split_off(mid + 1);
+
+                Ok(Inserted::Split(
+                    self.pager.write(Node::Internal { keys, children })?,
+                    separator,
+                    self.pager.write(Node::Internal {
+                        keys: right_keys,
+                        children: right_children,
+                    })?,
+                ))
+            }
+        }
+    }
+
+    // nodes are not rebalanced on removal, emptied nodes are unlinked and
+    // `merge` rebuilds a compact tree
+    fn remove(&mut self, page_id: u64, k: &[u8]) -> Result<Removed, Error> {
+        let node = self.pager.read(page_id)?;
+        match node.as_ref() {
+            Node::Leaf(entries) => {
+                match entries.binary_search_by(|(key, _)| key.as_slice().cmp(k)) {
+                    Ok(i) => {
+                        self.free_value(&entries[i].1)?;
+                        self.free(page_id);
+                        if entries.len() == 1 {
+                            return Ok(Removed::Empty);
+                        }
+
+                        let mut entries = entries.clone();
+                        entries.remove(i);
+
+                        Ok(Removed::One(self.pager.write(Node::Leaf(entries))?))
+                    }
+                    Err(_) => Ok(Removed::NotFound),
+                }
+            }
+            Node::Internal { keys, children } => {
+                let i = Self::child_index(keys, k);
+                let mut keys = keys.clone();
+                let mut children = children.clone();
+                match self.remove(children[i], k)? {
+                    Removed::NotFound => return Ok(Removed::NotFound),
+                    Removed::One(child) => children[i] = child,
+                    Removed::Empty => {
+                        children.remove(i);
+                        keys.remove(if i == 0 { 0 } else { i - 1 });
+                    }
+                }
+                self.free(page_id);
+
+                match children.len() {
+                    0 => Ok(Removed::Empty),
+                    1 => Ok(Removed::One(children[0])),
+                    _ => Ok(Removed::One(
+                        self.pager.write(Node::Internal { keys, children })?,
+                    )),
+                }
+            }
+        }
+    }
+}
+
+/// Builds a compact tree from sorted entries pushed one by one, only the leaf being
+/// filled and the page ids of the levels above are held in memory.
+pub struct BulkLoader {
+    pager: Pager,
+    meta: Meta,
+
+    leaf: Vec<(Vec<u8>, LeafValue)>,
+    leaf_size: usize,
+    // first key and page id of every leaf written so far
+    level: Vec<(Vec<u8>, u64)>,
+}
+
+impl BulkLoader {
+    pub fn new(path: &str, page_size: usize, cache_pages: usize) -> Result<Self, Error> {
+        let (pager, meta) = Pager::open(path, page_size, cache_pages)?;
+
+        Ok(Self {
+            pager,
+            meta,
+            leaf: vec![],
+            leaf_size: PAGE_HEADER_SIZE,
+            level: vec![],
+        })
+    }
+
+    pub fn push(&mut self, k: Vec<u8>, v: Vec<u8>) -> Result<(), Error> {
+        let v = leaf_value(&mut self.pager, &k, v)?;
+        let entry_size = LEAF_ENTRY_OVERHEAD + k.len() + v.encoded_size();
+        if self.leaf_size + entry_size > self.pager.page_size && !self.leaf.is_empty() {
+            self.flush_leaf()?;
+        }
+
+        self.leaf_size += entry_size;
+        self.leaf.push((k, v));
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<BTree, Error> {
+        if !self.leaf.is_empty() || self.level.is_empty() {
+            self.flush_leaf()?;
+        }
+
+        let (mut pager, mut meta) = (self.pager, self.meta);
+        let page_size = pager.page_size;
+        let mut level = self.level;
+        while level.len() > 1 {
+            let mut upper_level = vec![];
+            let mut keys = vec![];
+            let mut children = vec![];
+            let mut first_key = vec![];
+            let mut size = PAGE_HEADER_SIZE + 8;
+            for (k, page_id) in level {
+                let entry_size = INTERNAL_ENTRY_OVERHEAD + k.len();
+                if children.is_empty() {
+                    first_key = k;
+                } else if size + entry_size > page_size && children.len() > 1 {
+                    upper_level.push((first_key, pager.write(Node::Internal { keys, children })?));
+                    keys = vec![];
+                    children = vec![];
+                    first_key = k;
+                    size = PAGE_HEADER_SIZE + 8;
+                } else {
+                    size += entry_size;
+                    keys.push(k);
+                }
+                children.push(page_id);
+            }
+            upper_level.push((first_key, pager.write(Node::Internal { keys, children })?));
+
+            level = upper_level;
+        }
+
+        meta.txid += 1;
+        meta.root = level[0].1;
+        meta.page_count = pager.page_count;
+        pager.commit(&meta)?;
+
+        Ok(BTree::new(pager, meta))
+    }
+
+    fn flush_leaf(&mut self) -> Result<(), Error> {
+        // --- std ---
+        use std::mem::replace;
+
+        let leaf = replace(&mut self.leaf, vec![]);
+        let first_key = BTree::first_key(&leaf);
+        self.level
+            .push((first_key, self.pager.write(Node::Leaf(leaf))?));
+        self.leaf_size = PAGE_HEADER_SIZE;
+
+        Ok(())
+    }
+}
+
+// the value of an entry of `k`, in overflow pages unless the whole entry fits in a page along
+// with a few others
+fn leaf_value(pager: &mut Pager, k: &[u8], v: Vec<u8>) -> Result<LeafValue, Error> {
+    let limit = BTree::entry_size_limit(pager.page_size);
+    if k.len() + v.len() <= limit {
+        return Ok(LeafValue::Inline(v));
+    }
+
+    // an overflowed value takes the id of its first page in the leaf
+    if k.len() + 8 > limit {
+        return Err(BTreeEngineError::KeyTooLarge {
+            size: k.len(),
+            limit: limit - 8,
+        }
+        .into());
+    }
+    if v.len() > VALUE_SIZE_LIMIT {
+        return Err(BTreeEngineError::KeyValueTooLarge {
+            size: k.len() + v.len(),
+            limit: VALUE_SIZE_LIMIT,
+        }
+        .into());
+    }
+
+    Ok(LeafValue::Overflow {
+        page_id: pager.write_overflow(&v)?,
+        size: v.len() as _,
+    })
+}
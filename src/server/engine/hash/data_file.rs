@@ -3,26 +3,67 @@ use std::{
     fs::File,
     io::{self, Read, Write},
     mem::transmute,
-    sync::{Arc, RwLock},
+    ops::Range,
+    os::unix::fs::FileExt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
 };
 // --- external ---
 use chrono::Utc;
 use failure::Error;
 use hashbrown::HashMap;
+use memmap::Mmap;
 // --- custom ---
-use super::{Entry, KeyDirs};
+use super::{Entry, HashEngineError, ShardedKeyDirs};
 
-// crc : timestamp : key size : value size :  key :  value
-// u32 :       u64 :      u32 :        u32 : ?(8) : ?(256)
-const DATA_HEADER_SIZE: usize = 20;
-// timestamp : key size : value size : value position :  key
-//       u64 :      u32 :        u32 :            u64 : ?(8)
-const HINT_HEADER_SIZE: usize = 24;
+// magic : version
+//  [u8] :     u32
+const FILE_HEADER_SIZE: usize = 8;
+const MAGIC: &'static [u8; 4] = b"KVDH";
+// files written before the file header was introduced
+pub const LEGACY_VERSION: u32 = 0;
+// version 2 added `FLAG_EXPIRES`, a version 1 file is read the same way but never appended to
+pub const VERSION: u32 = 2;
+// crc : timestamp : key size : value size : flags : expires at :  key :  value
+// u32 :       u64 :      u32 :        u32 :    u8 :    ?(u64)  : ?(8) : ?(256)
+// crc covers everything after itself, `expires at` is only there with `FLAG_EXPIRES`
+pub const DATA_HEADER_SIZE: usize = 21;
+pub const EXPIRES_AT_SIZE: usize = 8;
+// legacy records have no value size, their crc only covers timestamp and key size
+// 0 : timestamp : key size : crc :  key :  value
+// [u8] :    u64 :      u32 : u32 : ?(8) : ?(256)
+pub const LEGACY_DATA_HEADER_SIZE: usize = 20;
+// timestamp : key size : value size : value position : flags : expires at :  key
+//       u64 :      u32 :        u32 :            u64 :    u8 :    ?(u64)  : ?(8)
+const HINT_HEADER_SIZE: usize = 25;
+// hints of legacy files have no flags, a delete is an empty value at position 0
+const LEGACY_HINT_HEADER_SIZE: usize = 24;
+// record flags
+pub const FLAG_TOMBSTONE: u8 = 1;
+// part of a batch, which only counts once its last record (with `FLAG_COMMIT`) is written
+pub const FLAG_BATCH: u8 = 2;
+pub const FLAG_COMMIT: u8 = 4;
+// followed by the time (unix nanos) the record expires at
+pub const FLAG_EXPIRES: u8 = 8;
+
+// size of the optional part of a header
+fn expires_at_size(flags: u8) -> usize {
+    if flags & FLAG_EXPIRES != 0 {
+        EXPIRES_AT_SIZE
+    } else {
+        0
+    }
+}
 
 struct DataHeader {
     timestamp: u64,
     key_size: u32,
     value_size: u32,
+    flags: u8,
+    // 0 unless `FLAG_EXPIRES` is set
+    expires_at: u64,
 }
 
 impl DataHeader {
@@ -31,24 +72,69 @@ impl DataHeader {
         use crc::crc32::checksum_ieee;
 
         let mut buffer = vec![0; 4];
-
-        {
-            let timestamp: [u8; 8] = unsafe { transmute(self.timestamp) };
-            buffer.extend_from_slice(&timestamp);
-        }
-        {
-            let key_size: [u8; 4] = unsafe { transmute(self.key_size) };
-            buffer.extend_from_slice(&key_size);
-        }
-        {
-            let crc: [u8; 4] = unsafe { transmute(checksum_ieee(&buffer[4..])) };
-            buffer.extend_from_slice(&crc);
+        buffer.extend_from_slice(&self.timestamp.to_le_bytes());
+        buffer.extend_from_slice(&self.key_size.to_le_bytes());
+        buffer.extend_from_slice(&self.value_size.to_le_bytes());
+        buffer.push(self.flags);
+        if self.flags & FLAG_EXPIRES != 0 {
+            buffer.extend_from_slice(&self.expires_at.to_le_bytes());
         }
         buffer.extend_from_slice(&k);
         buffer.extend_from_slice(&v);
 
+        let crc = checksum_ieee(&buffer[4..]);
+        buffer[..4].copy_from_slice(&crc.to_le_bytes());
+
         buffer
     }
+
+    // returns the stored crc along with the header, `expires_at` is read separately
+    fn decode(bytes: &[u8]) -> (u32, Self) {
+        let mut crc = [0; 4];
+        crc.copy_from_slice(&bytes[..4]);
+        let mut timestamp = [0; 8];
+        timestamp.copy_from_slice(&bytes[4..12]);
+        let mut key_size = [0; 4];
+        key_size.copy_from_slice(&bytes[12..16]);
+        let mut value_size = [0; 4];
+        value_size.copy_from_slice(&bytes[16..20]);
+
+        (
+            u32::from_le_bytes(crc),
+            Self {
+                timestamp: u64::from_le_bytes(timestamp),
+                key_size: u32::from_le_bytes(key_size),
+                value_size: u32::from_le_bytes(value_size),
+                flags: bytes[20],
+                expires_at: 0,
+            },
+        )
+    }
+}
+
+/// A record of a data file, as described by its hint.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hint {
+    pub key: Vec<u8>,
+    pub entry: Entry,
+    pub tombstone: bool,
+}
+
+/// `Hint` of every record of a data file, in write order.
+pub type Hints = Vec<Hint>;
+
+pub struct DataRecord {
+    pub timestamp: u64,
+    pub flags: u8,
+    pub expires_at: u64,
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+impl DataRecord {
+    pub fn size(&self) -> u64 {
+        (DATA_HEADER_SIZE + expires_at_size(self.flags) + self.key.len() + self.value.len()) as _
+    }
 }
 
 struct HintHeader {
@@ -56,6 +142,9 @@ struct HintHeader {
     key_size: u32,
     value_size: u32,
     value_position: u64,
+    flags: u8,
+    // 0 unless `FLAG_EXPIRES` is set
+    expires_at: u64,
 }
 
 impl HintHeader {
@@ -78,6 +167,10 @@ impl HintHeader {
             let value_position: [u8; 8] = unsafe { transmute(self.value_position) };
             buffer.extend_from_slice(&value_position);
         }
+        buffer.push(self.flags);
+        if self.flags & FLAG_EXPIRES != 0 {
+            buffer.extend_from_slice(&self.expires_at.to_le_bytes());
+        }
         buffer.extend_from_slice(&k);
 
         buffer
@@ -105,24 +198,99 @@ impl From<&[u8]> for HintHeader {
                 },
                 value_position: {
                     let mut value_position = [0; 8];
-                    value_position.copy_from_slice(&bytes[16..]);
+                    value_position.copy_from_slice(&bytes[16..24]);
                     transmute(value_position)
                 },
+                flags: if bytes.len() > LEGACY_HINT_HEADER_SIZE {
+                    bytes[24]
+                } else {
+                    0
+                },
+                expires_at: 0,
             }
         }
     }
 }
 
-#[derive(Clone)]
 pub struct DHFile {
     pub write_offset: u64,
 
     pub file_id: u64,
-    pub data_file: Arc<RwLock<File>>,
-    pub hint_file: Arc<RwLock<File>>,
+    // appended to under the active file lock, read from anywhere with positional reads,
+    // `None` until the first write of a file made active by a rotation, see `new`
+    pub data_file: Option<Arc<File>>,
+    // every record appended so far, they only go to the hint file once it's sealed
+    pub hints: Hints,
 }
 
 impl DHFile {
+    /// The next active file, only created on the disk by `open` before its first write,
+    /// a merge without any write after it leaves no empty file behind.
+    pub fn new(file_id: u64) -> DHFile {
+        DHFile {
+            write_offset: FILE_HEADER_SIZE as _,
+            file_id,
+            data_file: None,
+            hints: vec![],
+        }
+    }
+
+    /// Open (or create) the data file of `file_id` for appending.
+    ///
+    /// `hints` must describe the records already in it, see `seal`.
+    pub fn open(storage_dir: &str, file_id: u64) -> Result<DHFile, Error> {
+        let mut data_file = DHFile::set_active_file(storage_dir, file_id, "data")?;
+
+        if data_file.metadata()?.len() == 0 {
+            let mut file_header = MAGIC.to_vec();
+            file_header.extend_from_slice(&VERSION.to_le_bytes());
+            data_file.write_all(&file_header)?;
+            data_file.sync_data()?;
+        }
+
+        Ok(DHFile {
+            write_offset: data_file.metadata()?.len(),
+            file_id,
+            data_file: Some(Arc::new(data_file)),
+            hints: vec![],
+        })
+    }
+
+    /// A file always takes at least one record, whatever the size limit.
+    pub fn is_full(&self, file_size_limit: u64) -> bool {
+        self.write_offset > FILE_HEADER_SIZE as u64 && self.write_offset >= file_size_limit
+    }
+
+    /// Format version of a data file, files without a file header are `LEGACY_VERSION`.
+    pub fn read_version(file: &File, file_id: u64) -> Result<u32, Error> {
+        let mut file_header = [0; FILE_HEADER_SIZE];
+        if file.metadata()?.len() < FILE_HEADER_SIZE as u64 {
+            return Ok(LEGACY_VERSION);
+        }
+        file.read_exact_at(&mut file_header, 0)?;
+        if &file_header[..4] != MAGIC {
+            return Ok(LEGACY_VERSION);
+        }
+
+        let mut version = [0; 4];
+        version.copy_from_slice(&file_header[4..]);
+        let version = u32::from_le_bytes(version);
+        if version > VERSION {
+            return Err(HashEngineError::UnsupportedVersion { file_id, version }.into());
+        }
+
+        Ok(version)
+    }
+
+    /// Where the first record of a data file starts.
+    pub fn data_offset(version: u32) -> u64 {
+        if version == LEGACY_VERSION {
+            0
+        } else {
+            FILE_HEADER_SIZE as _
+        }
+    }
+
     pub fn set_active_file(
         storage_dir: &str,
         file_id: u64,
@@ -138,9 +306,193 @@ impl DHFile {
             .open(format!("{}/{}.{}", storage_dir, file_id, extension))?)
     }
 
-    pub fn load_hint(path: &str, file_id: u64, key_dirs: &mut KeyDirs) -> Result<(), Error> {
-        let mut file = File::open(path)?;
-        let mut bytes = [0; HINT_HEADER_SIZE];
+    /// Read the record at `offset` of a data file and verify its checksum.
+    ///
+    /// Positional reads, the cursor of `file` doesn't move.
+    pub fn read_record(file: &File, file_id: u64, offset: u64) -> Result<DataRecord, Error> {
+        let corruption = || HashEngineError::Corruption { file_id, offset };
+        let len = file.metadata()?.len();
+        if offset + DATA_HEADER_SIZE as u64 > len {
+            return Err(corruption().into());
+        }
+
+        let mut header = [0; DATA_HEADER_SIZE];
+        file.read_exact_at(&mut header, offset)?;
+        let (_, data_header) = DataHeader::decode(&header);
+        let record_size = (DATA_HEADER_SIZE + expires_at_size(data_header.flags)) as u64
+            + data_header.key_size as u64
+            + data_header.value_size as u64;
+        // don't trust the sizes of a damaged header
+        if offset + record_size > len {
+            return Err(corruption().into());
+        }
+
+        let mut bytes = vec![0; record_size as _];
+        bytes[..DATA_HEADER_SIZE].copy_from_slice(&header);
+        file.read_exact_at(
+            &mut bytes[DATA_HEADER_SIZE..],
+            offset + DATA_HEADER_SIZE as u64,
+        )?;
+        let (data_header, key, value) = DHFile::check_record(&bytes, file_id, offset)?;
+        let value = bytes.split_off(value.start);
+        bytes.truncate(key.end);
+
+        Ok(DataRecord {
+            timestamp: data_header.timestamp,
+            flags: data_header.flags,
+            expires_at: data_header.expires_at,
+            key: bytes.split_off(key.start),
+            value,
+        })
+    }
+
+    // verify the record at the start of `bytes`, at `offset` of its file, returning its
+    // header and where its key and value are, `bytes` may go on past its end
+    fn check_record(
+        bytes: &[u8],
+        file_id: u64,
+        offset: u64,
+    ) -> Result<(DataHeader, Range<usize>, Range<usize>), Error> {
+        // --- external ---
+        use crc::crc32::checksum_ieee;
+
+        let corruption = || HashEngineError::Corruption { file_id, offset };
+        if bytes.len() < DATA_HEADER_SIZE {
+            return Err(corruption().into());
+        }
+
+        let (crc, mut data_header) = DataHeader::decode(&bytes[..DATA_HEADER_SIZE]);
+        let key_start = DATA_HEADER_SIZE + expires_at_size(data_header.flags);
+        let value_start = key_start + data_header.key_size as usize;
+        let end = value_start + data_header.value_size as usize;
+        if end > bytes.len() || checksum_ieee(&bytes[4..end]) != crc {
+            return Err(corruption().into());
+        }
+        if key_start != DATA_HEADER_SIZE {
+            let mut expires_at = [0; EXPIRES_AT_SIZE];
+            expires_at.copy_from_slice(&bytes[DATA_HEADER_SIZE..key_start]);
+            data_header.expires_at = u64::from_le_bytes(expires_at);
+        }
+
+        Ok((data_header, key_start..value_start, value_start..end))
+    }
+
+    /// Verify the record of `k` at the start of `bytes`, at `offset` of its file, and
+    /// return where its value is.
+    ///
+    /// A legacy record has no value size, it has to come from the hints.
+    pub fn check_value(
+        bytes: &[u8],
+        file_id: u64,
+        offset: u64,
+        k: &[u8],
+        version: u32,
+        value_size: u32,
+    ) -> Result<Range<usize>, Error> {
+        // --- external ---
+        use crc::crc32::checksum_ieee;
+
+        let corruption = || HashEngineError::Corruption { file_id, offset };
+        if version == LEGACY_VERSION {
+            let value_start = LEGACY_DATA_HEADER_SIZE + k.len();
+            let end = value_start + value_size as usize;
+            if end > bytes.len() {
+                return Err(corruption().into());
+            }
+
+            let mut crc = [0; 4];
+            crc.copy_from_slice(&bytes[16..20]);
+            if checksum_ieee(&bytes[4..16]) != u32::from_le_bytes(crc)
+                || &bytes[LEGACY_DATA_HEADER_SIZE..value_start] != k
+            {
+                return Err(corruption().into());
+            }
+
+            return Ok(value_start..end);
+        }
+
+        let (_, key, value) = DHFile::check_record(bytes, file_id, offset)?;
+        if &bytes[key] != k || value.len() != value_size as usize {
+            return Err(corruption().into());
+        }
+
+        Ok(value)
+    }
+
+    /// Verify every record of a data file, returns the length of the valid part.
+    ///
+    /// A record can't be framed without a valid header, so everything from the first
+    /// damaged record on is treated as a torn tail, which is either truncated or
+    /// reported as `HashEngineError::Corruption`.
+    pub fn recover(path: &str, file_id: u64, truncate_torn_tail: bool) -> Result<u64, Error> {
+        // --- std ---
+        use std::fs::OpenOptions;
+
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        let len = file.metadata()?.len();
+        let mut offset = DHFile::data_offset(DHFile::read_version(&file, file_id)?);
+        let mut batch_start = None;
+
+        while offset < len {
+            match DHFile::read_record(&file, file_id, offset) {
+                Ok(record) => {
+                    if record.flags & FLAG_BATCH != 0 && batch_start.is_none() {
+                        batch_start = Some(offset);
+                    }
+                    if record.flags & FLAG_COMMIT != 0 {
+                        batch_start = None;
+                    }
+
+                    offset += record.size();
+                }
+                Err(e) => {
+                    if truncate_torn_tail {
+                        if let Some(HashEngineError::Corruption { .. }) = e.downcast_ref() {
+                            file.set_len(offset)?;
+                            file.sync_all()?;
+
+                            break;
+                        }
+                    }
+
+                    return Err(e);
+                }
+            }
+        }
+
+        // roll back a batch that never got its commit record
+        if let Some(batch_start) = batch_start {
+            file.set_len(batch_start)?;
+            file.sync_all()?;
+            offset = batch_start;
+        }
+
+        Ok(offset)
+    }
+
+    /// Parse a hint file, `Ok(None)` if it's missing or doesn't describe every record of
+    /// the data file (`data_len` bytes) in order, e.g. it's torn or was never written.
+    pub fn read_hints(
+        path: &str,
+        file_id: u64,
+        version: u32,
+        data_len: u64,
+    ) -> Result<Option<Hints>, Error> {
+        let mut file = match File::open(path) {
+            Ok(file) => file,
+            Err(e) => match e.kind() {
+                io::ErrorKind::NotFound => return Ok(None),
+                _ => return Err(e.into()),
+            },
+        };
+        let mut hints = vec![];
+        let mut offset = DHFile::data_offset(version);
+        let (data_header_size, hint_header_size) = if version == LEGACY_VERSION {
+            (LEGACY_DATA_HEADER_SIZE as u64, LEGACY_HINT_HEADER_SIZE)
+        } else {
+            (DATA_HEADER_SIZE as u64, HINT_HEADER_SIZE)
+        };
+        let mut bytes = vec![0; hint_header_size];
 
         loop {
             match file.read_exact(&mut bytes) {
@@ -149,31 +501,54 @@ impl DHFile {
                         timestamp,
                         key_size,
                         value_size,
-                        value_position,
+                        mut value_position,
+                        flags,
+                        ..
                     } = HintHeader::from(bytes.as_ref());
+                    let mut expires_at = [0; EXPIRES_AT_SIZE];
+                    let data_header_size = if flags & FLAG_EXPIRES != 0 {
+                        if file.read_exact(&mut expires_at).is_err() {
+                            return Ok(None);
+                        }
+                        data_header_size + EXPIRES_AT_SIZE as u64
+                    } else {
+                        data_header_size
+                    };
 
-                    //                    println!("{}, {}, {}, {}",
-                    //                             timestamp,
-                    //                             key_size,
-                    //                             value_size,
-                    //                             value_position);
+                    let tombstone = if version == LEGACY_VERSION {
+                        value_size == 0
+                    } else {
+                        flags & FLAG_TOMBSTONE != 0
+                    };
+                    // legacy hints of deletes have no position
+                    if version == LEGACY_VERSION && tombstone && value_position == 0 {
+                        value_position = offset + data_header_size + key_size as u64;
+                    }
+                    // every hint must point right behind the previous record
+                    if value_position != offset + data_header_size + key_size as u64
+                        || value_position + value_size as u64 > data_len
+                    {
+                        return Ok(None);
+                    }
+                    offset = value_position + value_size as u64;
 
                     let mut bytes = vec![0; key_size as _];
-                    file.read_exact(&mut bytes)?;
-
-                    if value_position == 0 {
-                        key_dirs.remove(&bytes);
-                    } else {
-                        key_dirs.insert(
-                            bytes,
-                            Entry {
-                                file_id,
-                                timestamp,
-                                value_size,
-                                value_position,
-                            },
-                        );
+                    if file.read_exact(&mut bytes).is_err() {
+                        return Ok(None);
                     }
+
+                    hints.push(Hint {
+                        key: bytes,
+                        entry: Entry {
+                            file_id,
+                            version,
+                            timestamp,
+                            value_size,
+                            value_position,
+                            expires_at: u64::from_le_bytes(expires_at),
+                        },
+                        tombstone,
+                    });
                 }
                 Err(e) => match e.kind() {
                     io::ErrorKind::UnexpectedEof => break,
@@ -182,77 +557,391 @@ impl DHFile {
             }
         }
 
-        Ok(())
+        if offset == data_len {
+            Ok(Some(hints))
+        } else {
+            Ok(None)
+        }
     }
 
-    pub fn write(&mut self, k: &[u8], v: &[u8]) -> Result<Entry, Error> {
-        let data_header = DataHeader {
-            timestamp: Utc::now().timestamp_nanos() as _,
-            key_size: k.len() as _,
-            value_size: v.len() as _,
+    /// Regenerate the hints of a data file from its records.
+    pub fn rebuild_hints(path: &str, file_id: u64) -> Result<Hints, Error> {
+        let file = File::open(path)?;
+        let len = file.metadata()?.len();
+        let mut hints = vec![];
+        let version = match DHFile::read_version(&file, file_id)? {
+            LEGACY_VERSION => return Err(HashEngineError::MissingHints { file_id }.into()),
+            version => version,
         };
-        {
-            let buffer = data_header.encode(k, v);
-            let mut w = self.data_file.write().unwrap();
-            w.write(&buffer)?;
-            w.sync_data()?;
+        let mut offset = DHFile::data_offset(version);
+
+        while offset < len {
+            let record = DHFile::read_record(&file, file_id, offset)?;
+            offset += record.size();
+            hints.push(Hint {
+                entry: Entry {
+                    file_id,
+                    version,
+                    timestamp: record.timestamp,
+                    value_size: record.value.len() as _,
+                    value_position: offset - record.value.len() as u64,
+                    expires_at: record.expires_at,
+                },
+                key: record.key,
+                tombstone: record.flags & FLAG_TOMBSTONE != 0,
+            });
         }
 
-        let value_position =
-            self.write_offset + DATA_HEADER_SIZE as u64 + data_header.key_size as u64;
-        let hint_header = HintHeader {
-            timestamp: data_header.timestamp,
-            key_size: data_header.key_size,
-            value_size: data_header.value_size,
-            value_position: if v.is_empty() { 0 } else { value_position },
-        };
+        Ok(hints)
+    }
+
+    /// Replace a hint file, through a temporary file so a crash never leaves it half written.
+    pub fn write_hints(path: &str, hints: &Hints) -> Result<(), Error> {
+        // --- std ---
+        use std::fs::rename;
+
+        let tmp_path = format!("{}.tmp", path);
         {
-            let buffer = hint_header.encode(k);
-            let mut w = self.hint_file.write().unwrap();
-            w.write(&buffer)?;
-            w.sync_data()?;
+            let mut file = File::create(&tmp_path)?;
+            for hint in hints {
+                let hint_header = HintHeader {
+                    timestamp: hint.entry.timestamp,
+                    key_size: hint.key.len() as _,
+                    value_size: hint.entry.value_size,
+                    value_position: hint.entry.value_position,
+                    flags: match (hint.tombstone, hint.entry.expires_at) {
+                        (true, _) => FLAG_TOMBSTONE,
+                        (false, 0) => 0,
+                        (false, _) => FLAG_EXPIRES,
+                    },
+                    expires_at: hint.entry.expires_at,
+                };
+                file.write_all(&hint_header.encode(&hint.key))?;
+            }
+            file.sync_all()?;
         }
+        rename(tmp_path, path)?;
 
-        self.write_offset = value_position + data_header.value_size as u64;
+        Ok(())
+    }
 
-        Ok(Entry {
-            file_id: self.file_id,
-            timestamp: hint_header.timestamp,
-            value_size: hint_header.value_size,
-            value_position,
-        })
+    pub fn apply_hints(hints: Hints, key_dirs: &mut ShardedKeyDirs) {
+        for hint in hints {
+            if hint.tombstone {
+                key_dirs.shard_mut(&hint.key).remove(&hint.key);
+            } else {
+                key_dirs.shard_mut(&hint.key).insert(hint.key, hint.entry);
+            }
+        }
+    }
+
+    /// Append a record, `None` writes a tombstone, `expires_at` is 0 for a record that
+    /// never expires.
+    ///
+    /// Nothing is synced, see `sync`.
+    pub fn write(&mut self, k: &[u8], v: Option<&[u8]>, expires_at: u64) -> Result<Entry, Error> {
+        let (data, entry) = self.encode_record(
+            self.write_offset,
+            Utc::now().timestamp_nanos() as _,
+            k,
+            v,
+            if expires_at == 0 { 0 } else { FLAG_EXPIRES },
+            expires_at,
+        );
+        self.file().write_all(&data)?;
+
+        self.write_offset += data.len() as u64;
+        self.hints.push(Hint {
+            key: k.to_vec(),
+            entry: entry.clone(),
+            tombstone: v.is_none(),
+        });
+
+        Ok(entry)
+    }
+
+    /// Append every put (`Some`) and delete (`None`) with a single write.
+    ///
+    /// Nothing is synced, see `sync`.
+    pub fn write_batch(&mut self, ops: &[(Vec<u8>, Option<Vec<u8>>)]) -> Result<Vec<Entry>, Error> {
+        let timestamp = Utc::now().timestamp_nanos() as _;
+        let mut offset = self.write_offset;
+        let (mut data, mut entries) = (vec![], vec![]);
+
+        for (i, (k, v)) in ops.iter().enumerate() {
+            let flags = if i + 1 == ops.len() {
+                FLAG_BATCH | FLAG_COMMIT
+            } else {
+                FLAG_BATCH
+            };
+            let (record, entry) = self.encode_record(
+                offset,
+                timestamp,
+                k,
+                v.as_ref().map(|v| v.as_slice()),
+                flags,
+                0,
+            );
+            offset += record.len() as u64;
+            data.extend_from_slice(&record);
+            entries.push(entry);
+        }
+
+        self.file().write_all(&data)?;
+
+        self.write_offset = offset;
+        for ((k, v), entry) in ops.iter().zip(entries.iter()) {
+            self.hints.push(Hint {
+                key: k.clone(),
+                entry: entry.clone(),
+                tombstone: v.is_none(),
+            });
+        }
+
+        Ok(entries)
     }
+
+    /// Fsync the data written so far.
+    ///
+    /// Hints only reach the disk once the file is sealed, see `seal`.
+    pub fn sync(&self) -> Result<(), Error> {
+        match self.data_file {
+            Some(ref data_file) => Ok(data_file.sync_data()?),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the hint file of a file that won't be appended to anymore.
+    ///
+    /// The active file has none, it's recovered by scanning its records on startup. A file
+    /// never written to isn't on the disk, it has none either.
+    pub fn seal(&self, storage_dir: &str) -> Result<(), Error> {
+        if self.data_file.is_none() {
+            return Ok(());
+        }
+
+        DHFile::write_hints(
+            &format!("{}/{}.hint", storage_dir, self.file_id),
+            &self.hints,
+        )
+    }
+
+    fn file(&self) -> &File {
+        self.data_file
+            .as_ref()
+            .expect("the active file is opened before it's written to")
+    }
+
+    // encode a record to be written at `offset`, along with its entry
+    fn encode_record(
+        &self,
+        offset: u64,
+        timestamp: u64,
+        k: &[u8],
+        v: Option<&[u8]>,
+        flags: u8,
+        expires_at: u64,
+    ) -> (Vec<u8>, Entry) {
+        let (v, flags) = match v {
+            Some(v) => (v, flags),
+            None => (&[][..], flags | FLAG_TOMBSTONE),
+        };
+        let data_header = DataHeader {
+            timestamp,
+            key_size: k.len() as _,
+            value_size: v.len() as _,
+            flags,
+            expires_at,
+        };
+        let value_position = offset
+            + (DATA_HEADER_SIZE + expires_at_size(flags)) as u64
+            + data_header.key_size as u64;
+
+        (
+            data_header.encode(k, v),
+            Entry {
+                file_id: self.file_id,
+                version: VERSION,
+                timestamp,
+                value_size: data_header.value_size,
+                value_position,
+                expires_at,
+            },
+        )
+    }
+}
+
+/// A data file to read from, see `DataFiles`.
+#[derive(Clone)]
+pub enum DataFile {
+    File(Arc<File>),
+    // a sealed file, unmapped once the last reader of it is done
+    Mapped(Arc<Mmap>),
 }
 
+/// Handles on the data files to read from, shared by every reader.
+///
+/// Reads are positional, a handle has no cursor anyone relies on. With `mmap`, sealed
+/// files are mapped in memory instead, their values are read without a copy.
+///
+/// At most `limit` of them stay open, the least recently read sealed file is closed first,
+/// the active file's handle is never closed. A reader still holding a closed handle goes on
+/// with it, it's only dropped after that read.
 #[derive(Clone)]
-pub struct DataFiles(Arc<RwLock<HashMap<u64, File>>>);
+pub struct DataFiles(Arc<OpenFiles>);
+
+struct OpenFiles {
+    files: RwLock<HashMap<u64, OpenFile>>,
+    limit: usize,
+    mmap: bool,
+
+    // bumped on every read, the order of `OpenFile::last_used`
+    clock: AtomicU64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+struct OpenFile {
+    file: DataFile,
+    // the active file, it isn't evicted until it's sealed
+    pinned: bool,
+    last_used: AtomicU64,
+}
 
 impl DataFiles {
-    pub fn new() -> Self {
-        Self(Arc::new(RwLock::new(HashMap::new())))
+    pub fn new(limit: usize, mmap: bool) -> Self {
+        Self(Arc::new(OpenFiles {
+            files: RwLock::new(HashMap::new()),
+            limit,
+            mmap,
+            clock: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+        }))
     }
 
-    pub fn insert(&self, file_id: u64, file: File) {
-        self.0.write().unwrap().insert(file_id, file);
+    /// Close the files, e.g. once a merge deleted them, for every clone.
+    ///
+    /// Nothing reopens them afterwards, the key dirs don't point to them anymore.
+    pub fn remove(&self, file_ids: &[u64]) {
+        let mut files = self.0.files.write().unwrap();
+        for file_id in file_ids {
+            files.remove(file_id);
+        }
     }
 
-    pub fn try_get(&self, storage_dir: &str, file_id: u64) -> Result<Option<File>, Error> {
-        let r = self.0.read().unwrap();
-        if let Some(file) = r.get(&file_id) {
-            Ok(Some(file.try_clone()?))
-        } else {
-            match File::open(format!("{}/{}.data", storage_dir, file_id)) {
-                Ok(file) => {
-                    drop(r);
+    /// The handle the active file is appended through, pinned until it's sealed.
+    pub fn insert(&self, file_id: u64, file: Arc<File>) {
+        let mut files = self.0.files.write().unwrap();
+        files.insert(
+            file_id,
+            OpenFile {
+                file: DataFile::File(file),
+                pinned: true,
+                last_used: AtomicU64::new(self.0.clock.fetch_add(1, Ordering::Relaxed)),
+            },
+        );
+        self.evict(&mut files);
+    }
+
+    /// The file won't be appended to anymore, it may be closed, and it's mapped on its
+    /// next read with `mmap`.
+    pub fn seal(&self, file_id: u64) {
+        let mut files = self.0.files.write().unwrap();
+        if self.0.mmap {
+            files.remove(&file_id);
+        } else if let Some(open_file) = files.get_mut(&file_id) {
+            open_file.pinned = false;
+            self.evict(&mut files);
+        }
+    }
+
+    /// Any file but the active one is sealed, it's mapped if `mmap`.
+    pub fn try_get(&self, storage_dir: &str, file_id: u64) -> Result<Option<DataFile>, Error> {
+        if let Some(open_file) = self.0.files.read().unwrap().get(&file_id) {
+            open_file.last_used.store(
+                self.0.clock.fetch_add(1, Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+            self.0.hits.fetch_add(1, Ordering::Relaxed);
+
+            return Ok(Some(open_file.file.clone()));
+        }
+        self.0.misses.fetch_add(1, Ordering::Relaxed);
+
+        match File::open(format!("{}/{}.data", storage_dir, file_id)) {
+            Ok(file) => {
+                // an empty file can't be mapped, and has nothing to read anyway
+                let file = if self.0.mmap && file.metadata()?.len() != 0 {
+                    // sealed files are never written to nor truncated, only removed,
+                    // and a mapping outlives the removal
+                    DataFile::Mapped(Arc::new(unsafe { Mmap::map(&file)? }))
+                } else {
+                    DataFile::File(Arc::new(file))
+                };
+
+                let mut files = self.0.files.write().unwrap();
+                // another reader may have opened it meanwhile, keep a single handle
+                let file = files
+                    .entry(file_id)
+                    .or_insert_with(|| OpenFile {
+                        file,
+                        pinned: false,
+                        last_used: AtomicU64::new(0),
+                    })
+                    .file
+                    .clone();
+                files[&file_id].last_used.store(
+                    self.0.clock.fetch_add(1, Ordering::Relaxed),
+                    Ordering::Relaxed,
+                );
+                self.evict(&mut files);
+
+                Ok(Some(file))
+            }
+            Err(e) => match e.kind() {
+                io::ErrorKind::NotFound => Ok(None),
+                _ => Err(e.into()),
+            },
+        }
+    }
+
+    /// Files open right now, the active one and the mapped ones included.
+    pub fn open_files(&self) -> u64 {
+        self.0.files.read().unwrap().len() as _
+    }
+
+    /// Reads served by an open file since startup.
+    pub fn hits(&self) -> u64 {
+        self.0.hits.load(Ordering::Relaxed)
+    }
+
+    /// Reads that had to open their file since startup.
+    pub fn misses(&self) -> u64 {
+        self.0.misses.load(Ordering::Relaxed)
+    }
 
-                    self.0.write().unwrap().insert(file_id, file.try_clone()?);
+    /// Files closed to stay within the limit since startup.
+    pub fn evictions(&self) -> u64 {
+        self.0.evictions.load(Ordering::Relaxed)
+    }
 
-                    Ok(Some(file))
+    // close the least recently read sealed files beyond the limit
+    fn evict(&self, files: &mut HashMap<u64, OpenFile>) {
+        while files.len() > self.0.limit {
+            let lru = files
+                .iter()
+                .filter(|(_, open_file)| !open_file.pinned)
+                .min_by_key(|(_, open_file)| open_file.last_used.load(Ordering::Relaxed))
+                .map(|(file_id, _)| *file_id);
+            match lru {
+                Some(file_id) => {
+                    files.remove(&file_id);
+                    self.0.evictions.fetch_add(1, Ordering::Relaxed);
                 }
-                Err(e) => match e.kind() {
-                    io::ErrorKind::NotFound => Ok(None),
-                    _ => Err(e.into()),
-                },
+                None => break,
             }
         }
     }
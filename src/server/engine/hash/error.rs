@@ -8,6 +8,21 @@ pub enum HashEngineError {
     FileNotFound { path: String },
     #[fail(display = "Merge locked")]
     MergeLocked,
+    #[fail(display = "No file id left between: `{}` and `{}`", lower, upper)]
+    FileIdsExhausted { lower: u64, upper: u64 },
+    #[fail(display = "Unsupported scanner")]
+    UnsupportedScanner,
+    #[fail(display = "Cursor: `{:?}`, invalid", cursor)]
+    InvalidCursor { cursor: Vec<u8> },
+    #[fail(display = "File: `{}`, corrupted at offset: {}", file_id, offset)]
+    Corruption { file_id: u64, offset: u64 },
+    #[fail(display = "File: `{}`, unsupported version: {}", file_id, version)]
+    UnsupportedVersion { file_id: u64, version: u32 },
+    #[fail(
+        display = "File: `{}`, legacy data file can't be read without hints",
+        file_id
+    )]
+    MissingHints { file_id: u64 },
     //    #[fail(display = "Nothing to scan")]
     //    EmptyScanMap,
 }
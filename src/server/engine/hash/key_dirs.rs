@@ -1,28 +1,694 @@
 // --- std ---
-use std::{fs::File, io::Read};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::Hasher,
+    mem::size_of,
+    os::unix::fs::FileExt,
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
 // --- external ---
 use failure::Error;
 use hashbrown::HashMap;
+// --- custom ---
+use super::{
+    data_file::{DATA_HEADER_SIZE, EXPIRES_AT_SIZE, LEGACY_DATA_HEADER_SIZE, LEGACY_VERSION},
+    DHFile, DataFile, HashEngineError, Value,
+};
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Entry {
     pub file_id: u64,
+    // format version of the data file
+    pub version: u32,
     pub timestamp: u64,
     pub value_size: u32,
     pub value_position: u64,
+    // unix nanos, 0 if it never expires
+    pub expires_at: u64,
 }
 
 impl Entry {
-    pub fn seek_value(&self, file: &mut File) -> Result<Vec<u8>, Error> {
-        // --- std ---
-        use std::io::{Seek, SeekFrom};
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at != 0 && self.expires_at <= now
+    }
+
+    /// Size of the whole record of a key of `key_len` bytes.
+    pub fn record_size(&self, key_len: usize) -> u64 {
+        (self.data_header_size() + key_len) as u64 + self.value_size as u64
+    }
+
+    /// Read back the key of `key_len` bytes of the record, right before the value in any version.
+    pub fn seek_key(&self, file: &DataFile, key_len: usize) -> Result<Vec<u8>, Error> {
+        let corruption = || HashEngineError::Corruption {
+            file_id: self.file_id,
+            offset: self.value_position,
+        };
+        let offset = self
+            .value_position
+            .checked_sub(key_len as _)
+            .ok_or_else(corruption)?;
+        match file {
+            DataFile::File(file) => {
+                let mut key = vec![0; key_len];
+                file.read_exact_at(&mut key, offset)?;
+
+                Ok(key)
+            }
+            DataFile::Mapped(map) => map
+                .get(offset as usize..self.value_position as usize)
+                .map(|key| key.to_vec())
+                .ok_or_else(|| corruption().into()),
+        }
+    }
+
+    /// Read the whole record of `k` and verify it before handing out the value.
+    ///
+    /// Positional reads, `file` may be shared. The value of a mapped file is a slice of it.
+    pub fn seek_value(&self, file: &DataFile, k: &[u8]) -> Result<Value, Error> {
+        let corruption = |offset| HashEngineError::Corruption {
+            file_id: self.file_id,
+            offset,
+        };
+        let offset = self
+            .value_position
+            .checked_sub((self.data_header_size() + k.len()) as _)
+            .ok_or_else(|| corruption(self.value_position))?;
+        let record_size = self.record_size(k.len());
+        match file {
+            DataFile::File(file) => {
+                if offset + record_size > file.metadata()?.len() {
+                    return Err(corruption(offset).into());
+                }
+
+                let mut record = vec![0; record_size as _];
+                file.read_exact_at(&mut record, offset)?;
+                let range = DHFile::check_value(
+                    &record,
+                    self.file_id,
+                    offset,
+                    k,
+                    self.version,
+                    self.value_size,
+                )?;
+                record.truncate(range.end);
+
+                Ok(Value::Owned(record.split_off(range.start)))
+            }
+            DataFile::Mapped(map) => {
+                let record = map
+                    .get(offset as usize..(offset + record_size) as usize)
+                    .ok_or_else(|| corruption(offset))?;
+                let range = DHFile::check_value(
+                    record,
+                    self.file_id,
+                    offset,
+                    k,
+                    self.version,
+                    self.value_size,
+                )?;
+                let start = offset as usize + range.start;
+
+                Ok(Value::Mapped(map.clone(), start..start + range.len()))
+            }
+        }
+    }
+
+    fn data_header_size(&self) -> usize {
+        if self.version == LEGACY_VERSION {
+            LEGACY_DATA_HEADER_SIZE
+        } else if self.expires_at != 0 {
+            DATA_HEADER_SIZE + EXPIRES_AT_SIZE
+        } else {
+            DATA_HEADER_SIZE
+        }
+    }
+}
+
+/// How `KeyDirs` holds the keys, from the fastest to the smallest, see `Options::key_dirs`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyDirsLayout {
+    /// A hash map of owned keys to entries.
+    Standard,
+    /// The keys back to back in an arena, along with packed entries.
+    Compact,
+    /// Two hashes of every key instead of the key, whatever its length. Scans read the keys
+    /// back from the data files, and a read fails with `Corruption` if it lands on another key.
+    Hashed,
+}
+
+/// A key of `KeyDirs`, a `Hashed` one is only on the disk, see `Entry::seek_key`.
+#[derive(Clone, Copy, Debug)]
+pub enum Key<'a> {
+    Slice(&'a [u8]),
+    Hashed { hash: u64, len: usize },
+}
+
+impl<'a> Key<'a> {
+    /// The order of `HashScanner`.
+    pub fn hash(&self) -> u64 {
+        match self {
+            Key::Slice(k) => hash(k),
+            Key::Hashed { hash, .. } => *hash,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Key::Slice(k) => k.len(),
+            Key::Hashed { len, .. } => *len,
+        }
+    }
+}
+
+/// A fixed 64-bit hash of `k`, independent of the capacity of any map.
+pub fn hash(k: &[u8]) -> u64 {
+    // --- external ---
+    use crc::crc64::checksum_ecma;
+
+    checksum_ecma(k)
+}
+
+// a second hash, independent of `hash`, a `Hashed` key is told apart by both
+fn fingerprint(k: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(k);
+    hasher.finish()
+}
+
+/// Key -> `Entry` of its latest record, in the layout picked by `Options::key_dirs`.
+#[derive(Clone)]
+pub struct KeyDirs {
+    layout: Layout,
+    // entries that expire, the reaper skips a shard without any
+    expiring: usize,
+    // heap bytes of the keys of a `Standard` layout, kept up to date by `insert` and `remove`
+    key_bytes: usize,
+}
+
+#[derive(Clone)]
+enum Layout {
+    Standard(HashMap<Vec<u8>, Entry>),
+    Packed(PackedKeyDirs),
+}
+
+impl Default for KeyDirs {
+    fn default() -> Self {
+        Self::new(KeyDirsLayout::Standard)
+    }
+}
+
+impl KeyDirs {
+    // roughly the bytes of the allocator per `Standard` key
+    const KEY_OVERHEAD: usize = 16;
+
+    pub fn new(layout: KeyDirsLayout) -> Self {
+        KeyDirs {
+            layout: match layout {
+                KeyDirsLayout::Standard => Layout::Standard(HashMap::new()),
+                KeyDirsLayout::Compact => Layout::Packed(PackedKeyDirs::new(Some(vec![]))),
+                KeyDirsLayout::Hashed => Layout::Packed(PackedKeyDirs::new(None)),
+            },
+            expiring: 0,
+            key_bytes: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self.layout {
+            Layout::Standard(ref key_dirs) => key_dirs.len(),
+            Layout::Packed(ref key_dirs) => key_dirs.len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether any entry has an expiry, expired or not.
+    pub fn has_expiring(&self) -> bool {
+        self.expiring != 0
+    }
+
+    /// The entry of `k`, without its timestamp unless the layout is `Standard`.
+    pub fn get(&self, k: &[u8]) -> Option<Entry> {
+        match self.layout {
+            Layout::Standard(ref key_dirs) => key_dirs.get(k).cloned(),
+            Layout::Packed(ref key_dirs) => key_dirs.get(k),
+        }
+    }
 
-        file.seek(SeekFrom::Start(self.value_position))?;
-        let mut buffer = vec![0; self.value_size as _];
-        file.read_exact(&mut buffer)?;
+    pub fn insert(&mut self, k: Vec<u8>, entry: Entry) {
+        let expires = entry.expires_at != 0;
+        let previous = match self.layout {
+            Layout::Standard(ref mut key_dirs) => {
+                // an overwrite keeps the key already in the map
+                let key_bytes = k.capacity() + Self::KEY_OVERHEAD;
+                let previous = key_dirs.insert(k, entry);
+                if previous.is_none() {
+                    self.key_bytes += key_bytes;
+                }
 
-        Ok(buffer)
+                previous
+            }
+            Layout::Packed(ref mut key_dirs) => key_dirs.insert(&k, &entry),
+        };
+        self.count_expiring(previous.as_ref(), expires);
+    }
+
+    pub fn remove(&mut self, k: &[u8]) -> Option<Entry> {
+        let previous = match self.layout {
+            Layout::Standard(ref mut key_dirs) => key_dirs.remove_entry(k).map(|(k, previous)| {
+                self.key_bytes -= k.capacity() + Self::KEY_OVERHEAD;
+                previous
+            }),
+            Layout::Packed(ref mut key_dirs) => key_dirs.remove(k),
+        };
+        self.count_expiring(previous.as_ref(), false);
+
+        previous
+    }
+
+    pub fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = (Key<'a>, Entry)> + 'a> {
+        match self.layout {
+            Layout::Standard(ref key_dirs) => Box::new(
+                key_dirs
+                    .iter()
+                    .map(|(k, entry)| (Key::Slice(k), entry.clone())),
+            ),
+            Layout::Packed(ref key_dirs) => Box::new(key_dirs.iter()),
+        }
+    }
+
+    /// Replace every entry `f` maps to a new one.
+    pub fn update_entries(&mut self, mut f: impl FnMut(&Entry) -> Option<Entry>) {
+        let expiring = &mut self.expiring;
+        let mut f = |entry: &Entry| {
+            let new_entry = f(entry);
+            if let Some(ref new_entry) = new_entry {
+                *expiring -= (entry.expires_at != 0) as usize;
+                *expiring += (new_entry.expires_at != 0) as usize;
+            }
+
+            new_entry
+        };
+        match self.layout {
+            Layout::Standard(ref mut key_dirs) => {
+                for entry in key_dirs.values_mut() {
+                    if let Some(new_entry) = f(entry) {
+                        *entry = new_entry;
+                    }
+                }
+            }
+            Layout::Packed(ref mut key_dirs) => key_dirs.update_entries(f),
+        }
+    }
+
+    /// An estimate of the heap bytes held, the unused capacity included.
+    pub fn memory_usage(&self) -> u64 {
+        match self.layout {
+            Layout::Standard(ref key_dirs) => {
+                // a control byte per bucket
+                (key_dirs.capacity() * (size_of::<(Vec<u8>, Entry)>() + 1) + self.key_bytes) as _
+            }
+            Layout::Packed(ref key_dirs) => key_dirs.memory_usage(),
+        }
+    }
+
+    // an entry with an expiry replaced (or removed) by one with (or without) one
+    fn count_expiring(&mut self, previous: Option<&Entry>, expires: bool) {
+        if previous.map_or(false, |previous| previous.expires_at != 0) {
+            self.expiring -= 1;
+        }
+        if expires {
+            self.expiring += 1;
+        }
     }
 }
 
-pub type KeyDirs = HashMap<Vec<u8>, Entry>;
+/// `KeyDirs` split by the hash of the keys, every shard behind its own lock.
+///
+/// Shards are always locked in ascending order, after the active file and before the versions.
+pub struct ShardedKeyDirs {
+    shards: Vec<RwLock<KeyDirs>>,
+}
+
+impl ShardedKeyDirs {
+    pub fn new(layout: KeyDirsLayout, shards: usize) -> Self {
+        Self {
+            shards: (0..shards.max(1))
+                .map(|_| RwLock::new(KeyDirs::new(layout)))
+                .collect(),
+        }
+    }
+
+    pub fn shard_index(&self, k: &[u8]) -> usize {
+        (hash(k) % self.shards.len() as u64) as _
+    }
+
+    pub fn shard(&self, k: &[u8]) -> &RwLock<KeyDirs> {
+        &self.shards[self.shard_index(k)]
+    }
+
+    pub fn shards(&self) -> &[RwLock<KeyDirs>] {
+        &self.shards
+    }
+
+    /// The shard of `k` of a `ShardedKeyDirs` that isn't shared yet, without locking.
+    pub fn shard_mut(&mut self, k: &[u8]) -> &mut KeyDirs {
+        let i = self.shard_index(k);
+        self.shards[i].get_mut().unwrap()
+    }
+
+    /// Every shard, consistent with each other until the guards are dropped.
+    pub fn read_all(&self) -> Vec<RwLockReadGuard<'_, KeyDirs>> {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap())
+            .collect()
+    }
+
+    pub fn write_all(&self) -> Vec<RwLockWriteGuard<'_, KeyDirs>> {
+        self.shards
+            .iter()
+            .map(|shard| shard.write().unwrap())
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap().len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn memory_usage(&self) -> u64 {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap().memory_usage())
+            .sum()
+    }
+}
+
+// an `Entry` without its timestamp, and its key, 40 bytes
+#[derive(Clone, Copy)]
+struct Slot {
+    // offset of the key in the arena, its `fingerprint` without one
+    key: u64,
+    value_position: u64,
+    expires_at: u64,
+    key_len: u32,
+    // index in `PackedKeyDirs::files`
+    file: u32,
+    value_size: u32,
+    version: u8,
+}
+
+#[derive(Clone)]
+struct PackedKeyDirs {
+    // the keys back to back, `None` for `KeyDirsLayout::Hashed`
+    arena: Option<Vec<u8>>,
+    // bytes of the arena no slot points to anymore
+    garbage: usize,
+    // `hash` of the key -> its slot, and the other slots of the same hash, if any
+    slots: HashMap<u64, Slot>,
+    collisions: HashMap<u64, Vec<Slot>>,
+    len: usize,
+    // the ids of the data files, a file id is 8 bytes, its index 4
+    files: Vec<u64>,
+    file_indexes: HashMap<u64, u32>,
+}
+
+impl PackedKeyDirs {
+    // don't bother rewriting an arena smaller than this
+    const MIN_GARBAGE: usize = 0x100000;
+    const MIN_GROWTH: usize = 0x1000;
+
+    fn new(arena: Option<Vec<u8>>) -> Self {
+        Self {
+            arena,
+            garbage: 0,
+            slots: HashMap::new(),
+            collisions: HashMap::new(),
+            len: 0,
+            files: vec![],
+            file_indexes: HashMap::new(),
+        }
+    }
+
+    fn matches(arena: &Option<Vec<u8>>, slot: &Slot, k: &[u8]) -> bool {
+        slot.key_len as usize == k.len()
+            && match arena {
+                Some(arena) => &arena[slot.key as usize..slot.key as usize + k.len()] == k,
+                None => slot.key == fingerprint(k),
+            }
+    }
+
+    fn unpack(files: &[u64], slot: &Slot) -> Entry {
+        Entry {
+            file_id: files[slot.file as usize],
+            version: slot.version as _,
+            timestamp: 0,
+            value_size: slot.value_size,
+            value_position: slot.value_position,
+            expires_at: slot.expires_at,
+        }
+    }
+
+    fn pack(
+        files: &mut Vec<u64>,
+        file_indexes: &mut HashMap<u64, u32>,
+        slot: &mut Slot,
+        entry: &Entry,
+    ) {
+        slot.file = *file_indexes.entry(entry.file_id).or_insert_with(|| {
+            files.push(entry.file_id);
+            (files.len() - 1) as _
+        });
+        slot.version = entry.version as _;
+        slot.value_size = entry.value_size;
+        slot.value_position = entry.value_position;
+        slot.expires_at = entry.expires_at;
+    }
+
+    fn get(&self, k: &[u8]) -> Option<Entry> {
+        let hash = hash(k);
+        self.slots
+            .get(&hash)
+            .into_iter()
+            .chain(self.collisions.get(&hash).into_iter().flatten())
+            .find(|slot| Self::matches(&self.arena, slot, k))
+            .map(|slot| Self::unpack(&self.files, slot))
+    }
+
+    fn insert(&mut self, k: &[u8], entry: &Entry) -> Option<Entry> {
+        let hash = hash(k);
+        let PackedKeyDirs {
+            arena,
+            slots,
+            collisions,
+            files,
+            file_indexes,
+            ..
+        } = self;
+
+        // an overwrite keeps the key where it is
+        if let Some(slot) = slots
+            .get_mut(&hash)
+            .into_iter()
+            .chain(collisions.get_mut(&hash).into_iter().flatten())
+            .find(|slot| Self::matches(arena, slot, k))
+        {
+            let previous = Self::unpack(files, slot);
+            Self::pack(files, file_indexes, slot, entry);
+            return Some(previous);
+        }
+
+        let mut slot = Slot {
+            key: match arena {
+                Some(arena) => {
+                    // grow by an eighth, doubling would leave up to half of it unused
+                    if arena.capacity() - arena.len() < k.len() {
+                        arena.reserve_exact(k.len().max(arena.len() / 8).max(Self::MIN_GROWTH));
+                    }
+                    arena.extend_from_slice(k);
+                    (arena.len() - k.len()) as _
+                }
+                None => fingerprint(k),
+            },
+            value_position: 0,
+            expires_at: 0,
+            key_len: k.len() as _,
+            file: 0,
+            value_size: 0,
+            version: 0,
+        };
+        Self::pack(files, file_indexes, &mut slot, entry);
+        if slots.contains_key(&hash) {
+            collisions.entry(hash).or_insert_with(Vec::new).push(slot);
+        } else {
+            slots.insert(hash, slot);
+        }
+        self.len += 1;
+
+        None
+    }
+
+    fn remove(&mut self, k: &[u8]) -> Option<Entry> {
+        let hash = hash(k);
+        let slot = match self.slots.get(&hash) {
+            Some(slot) if Self::matches(&self.arena, slot, k) => {
+                let slot = self.slots.remove(&hash).unwrap();
+                // the hash stays in `slots` as long as any key has it
+                if let Some(mut others) = self.collisions.remove(&hash) {
+                    self.slots.insert(hash, others.pop().unwrap());
+                    if !others.is_empty() {
+                        self.collisions.insert(hash, others);
+                    }
+                }
+
+                slot
+            }
+            _ => {
+                let arena = &self.arena;
+                let others = self.collisions.get_mut(&hash)?;
+                let i = others
+                    .iter()
+                    .position(|slot| Self::matches(arena, slot, k))?;
+                let slot = others.swap_remove(i);
+                if others.is_empty() {
+                    self.collisions.remove(&hash);
+                }
+
+                slot
+            }
+        };
+        self.len -= 1;
+
+        if self.arena.is_some() {
+            self.garbage += k.len();
+            self.compact_arena();
+        }
+
+        Some(Self::unpack(&self.files, &slot))
+    }
+
+    // rewrite the arena once it's more garbage than keys
+    fn compact_arena(&mut self) {
+        let arena = match self.arena {
+            Some(ref arena)
+                if self.garbage >= Self::MIN_GARBAGE && self.garbage * 2 > arena.len() =>
+            {
+                arena
+            }
+            _ => return,
+        };
+
+        let mut compacted = Vec::with_capacity(arena.len() - self.garbage);
+        for slot in self
+            .slots
+            .values_mut()
+            .chain(self.collisions.values_mut().flatten())
+        {
+            let key = &arena[slot.key as usize..slot.key as usize + slot.key_len as usize];
+            slot.key = compacted.len() as _;
+            compacted.extend_from_slice(key);
+        }
+        self.arena = Some(compacted);
+        self.garbage = 0;
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Key<'a>, Entry)> + 'a {
+        self.slots
+            .iter()
+            .chain(
+                self.collisions
+                    .iter()
+                    .flat_map(|(hash, others)| others.iter().map(move |slot| (hash, slot))),
+            )
+            .map(move |(hash, slot)| {
+                let key = match self.arena {
+                    Some(ref arena) => Key::Slice(
+                        &arena[slot.key as usize..slot.key as usize + slot.key_len as usize],
+                    ),
+                    None => Key::Hashed {
+                        hash: *hash,
+                        len: slot.key_len as _,
+                    },
+                };
+
+                (key, Self::unpack(&self.files, slot))
+            })
+    }
+
+    fn update_entries(&mut self, mut f: impl FnMut(&Entry) -> Option<Entry>) {
+        let PackedKeyDirs {
+            slots,
+            collisions,
+            files,
+            file_indexes,
+            ..
+        } = self;
+
+        for slot in slots.values_mut().chain(collisions.values_mut().flatten()) {
+            if let Some(new_entry) = f(&Self::unpack(files, slot)) {
+                Self::pack(files, file_indexes, slot, &new_entry);
+            }
+        }
+
+        self.prune_files();
+    }
+
+    // forget the ids of the files no slot points to anymore, e.g. the merged ones
+    fn prune_files(&mut self) {
+        let PackedKeyDirs {
+            slots,
+            collisions,
+            files,
+            file_indexes,
+            ..
+        } = self;
+
+        let mut used = vec![false; files.len()];
+        for slot in slots.values().chain(collisions.values().flatten()) {
+            used[slot.file as usize] = true;
+        }
+        if used.iter().all(|used| *used) {
+            return;
+        }
+
+        let mut new_indexes = vec![0; files.len()];
+        let mut new_files = vec![];
+        for (i, file_id) in files.iter().enumerate() {
+            if used[i] {
+                new_indexes[i] = new_files.len() as u32;
+                new_files.push(*file_id);
+            }
+        }
+        for slot in slots.values_mut().chain(collisions.values_mut().flatten()) {
+            slot.file = new_indexes[slot.file as usize];
+        }
+        *file_indexes = new_files
+            .iter()
+            .enumerate()
+            .map(|(i, file_id)| (*file_id, i as u32))
+            .collect();
+        *files = new_files;
+    }
+
+    fn memory_usage(&self) -> u64 {
+        (self.slots.capacity() * (size_of::<(u64, Slot)>() + 1)
+            + self.collisions.capacity() * (size_of::<(u64, Vec<Slot>)>() + 1)
+            + self
+                .collisions
+                .values()
+                .map(|others| others.capacity() * size_of::<Slot>())
+                .sum::<usize>()
+            + self.arena.as_ref().map(Vec::capacity).unwrap_or(0)
+            + self.files.capacity() * size_of::<u64>()
+            + self.file_indexes.capacity() * (size_of::<(u64, u32)>() + 1)) as _
+    }
+}
@@ -0,0 +1,65 @@
+// --- std ---
+use std::{
+    fs::{read_to_string, rename, File},
+    io::{self, Write},
+};
+// --- external ---
+use failure::Error;
+
+/// Where a merge writes its copies, inside the storage dir.
+pub const MERGE_DIR: &'static str = "merge";
+const MANIFEST: &'static str = "MANIFEST";
+
+/// The commit record of a merge, once it's in the merge dir the copies next to it are
+/// complete and replace the merged files.
+///
+/// A merge interrupted before is rolled back on startup, one interrupted after is completed.
+pub struct MergeManifest {
+    // ids of the merged files, ascending
+    pub merged: Vec<u64>,
+}
+
+impl MergeManifest {
+    pub fn dir(storage_dir: &str) -> String {
+        format!("{}/{}", storage_dir, MERGE_DIR)
+    }
+
+    /// Write it through a temporary file, the rename is the commit point.
+    pub fn commit(&self, merge_dir: &str) -> Result<(), Error> {
+        let path = format!("{}/{}", merge_dir, MANIFEST);
+        let tmp_path = format!("{}.tmp", path);
+        {
+            let mut file = File::create(&tmp_path)?;
+            for file_id in self.merged.iter() {
+                writeln!(file, "{}", file_id)?;
+            }
+            file.sync_all()?;
+        }
+        rename(tmp_path, path)?;
+
+        sync_dir(merge_dir)
+    }
+
+    /// `Ok(None)` if the merge in `merge_dir` never committed.
+    pub fn read(merge_dir: &str) -> Result<Option<Self>, Error> {
+        let manifest = match read_to_string(format!("{}/{}", merge_dir, MANIFEST)) {
+            Ok(manifest) => manifest,
+            Err(e) => match e.kind() {
+                io::ErrorKind::NotFound => return Ok(None),
+                _ => return Err(e.into()),
+            },
+        };
+
+        Ok(Some(Self {
+            merged: manifest
+                .lines()
+                .map(|file_id| file_id.parse())
+                .collect::<Result<_, _>>()?,
+        }))
+    }
+}
+
+/// Fsync a directory, the renames in it survive a crash.
+pub fn sync_dir(path: &str) -> Result<(), Error> {
+    Ok(File::open(path)?.sync_all()?)
+}
@@ -1,25 +1,36 @@
 mod data_file;
 mod error;
 mod key_dirs;
+mod manifest;
 mod options;
 mod scanner;
+mod snapshot;
+mod syncer;
 
-pub use data_file::{DHFile, DataFiles};
+pub use data_file::{DHFile, DataFile, DataFiles, LEGACY_VERSION, VERSION};
 pub use error::HashEngineError;
-pub use key_dirs::{Entry, KeyDirs};
-pub use options::{MergePolicy, Options};
+pub use key_dirs::{Entry, Key, KeyDirs, KeyDirsLayout, ShardedKeyDirs};
+pub use options::{Durability, FileStats, MergePolicy, MergeThresholds, Options};
 pub use scanner::HashScanner;
+pub use snapshot::HashSnapshot;
+pub use syncer::Syncer;
 
 // --- std ---
 use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
     path::Path,
-    sync::{Arc, RwLock},
+    sync::{Arc, Mutex, RwLock, Weak},
+    time::Duration,
 };
 // --- external ---
 use chrono::Utc;
 use failure::Error;
+use regex::bytes::Regex;
 // --- custom ---
-use crate::Scanner;
+use crate::{MergeProgress, Order, Scanner, Stats, Transaction, Value, WriteBatch};
+use manifest::{sync_dir, MergeManifest};
+use snapshot::Versions;
 
 pub struct HashEngineBuilder {
     options: Options,
@@ -56,10 +67,45 @@ pub struct HashEngine {
     options: Options,
 
     storage_dir: String,
-    key_dirs: Arc<RwLock<KeyDirs>>,
+    key_dirs: Arc<ShardedKeyDirs>,
 
-    active_file: DHFile,
-    old_files: DataFiles,
+    // shared by every clone, concurrent writers must agree on the write offset
+    active_file: Arc<RwLock<DHFile>>,
+    // what the reads go through, the active file included, without any of its locks
+    data_files: DataFiles,
+    syncer: Arc<Syncer>,
+    versions: Arc<Mutex<Versions>>,
+    // held for a whole merge, a file can't be merged twice at once
+    merging: Arc<Mutex<()>>,
+}
+
+// what the background threads hold, they stop once the engine is dropped
+struct WeakHashEngine {
+    options: Options,
+
+    storage_dir: String,
+    key_dirs: Weak<ShardedKeyDirs>,
+
+    active_file: Weak<RwLock<DHFile>>,
+    data_files: DataFiles,
+    syncer: Weak<Syncer>,
+    versions: Weak<Mutex<Versions>>,
+    merging: Weak<Mutex<()>>,
+}
+
+impl WeakHashEngine {
+    fn upgrade(&self) -> Option<HashEngine> {
+        Some(HashEngine {
+            options: self.options.clone(),
+            storage_dir: self.storage_dir.clone(),
+            key_dirs: self.key_dirs.upgrade()?,
+            active_file: self.active_file.upgrade()?,
+            data_files: self.data_files.clone(),
+            syncer: self.syncer.upgrade()?,
+            versions: self.versions.upgrade()?,
+            merging: self.merging.upgrade()?,
+        })
+    }
 }
 
 impl HashEngine {
@@ -105,220 +151,1002 @@ impl HashEngine {
         Ok((files, active_file_id))
     }
 
-    fn load_hints(dir: &str, key_dirs: &mut KeyDirs) -> Result<u64, Error> {
-        let (files, active_file_id) = HashEngine::scan_and_sort_dh_files(dir, "hint")?;
+    // load every data file into `key_dirs`, returning the id of the next active file
+    //
+    // they're all sealed: the active file of the last run gets its hints once it's recovered,
+    // the writes go on in a new one, created on the first of them
+    fn load_hints(
+        dir: &str,
+        options: &Options,
+        key_dirs: &mut ShardedKeyDirs,
+    ) -> Result<u64, Error> {
+        // --- std ---
+        use std::fs::File;
+
+        // data files are the source of truth, hints only speed up the loading
+        let (files, last_file_id) = HashEngine::scan_and_sort_dh_files(dir, "data")?;
 
         for (path, file_id) in files.into_iter() {
-            DHFile::load_hint(&path, file_id, key_dirs)?;
-        }
+            let data_file = File::open(&path)?;
+            let version = DHFile::read_version(&data_file, file_id)?;
+            // the last one is the only one that could be left half written
+            if file_id == last_file_id && version != LEGACY_VERSION {
+                DHFile::recover(&path, file_id, options.truncate_torn_tail)?;
+            }
 
-        if active_file_id == 0 {
-            Ok(Utc::now().timestamp_nanos() as _)
-        } else {
-            Ok(active_file_id)
+            let hint_path = format!("{}/{}.hint", dir, file_id);
+            let data_len = data_file.metadata()?.len();
+            let hints = match DHFile::read_hints(&hint_path, file_id, version, data_len)? {
+                // legacy records can't be verified without hints
+                Some(hints) if !options.verify_hints || version == LEGACY_VERSION => hints,
+                hints => {
+                    let rebuilt_hints = DHFile::rebuild_hints(&path, file_id)?;
+                    if hints.as_ref() != Some(&rebuilt_hints) {
+                        DHFile::write_hints(&hint_path, &rebuilt_hints)?;
+                    }
+
+                    rebuilt_hints
+                }
+            };
+            DHFile::apply_hints(hints, key_dirs);
         }
+
+        // ids are timestamps, the new one is newer than any of them
+        Ok((Utc::now().timestamp_nanos() as u64).max(last_file_id + 1))
     }
 
     fn init(builder: HashEngineBuilder) -> Result<HashEngine, Error> {
         HashEngine::check_dir(&builder.storage_dir)?;
 
-        let mut key_dirs = KeyDirs::default();
-        let file_id = HashEngine::load_hints(&builder.storage_dir, &mut key_dirs)?;
-        let data_file = DHFile::set_active_file(&builder.storage_dir, file_id, "data")?;
-        let hint_file = DHFile::set_active_file(&builder.storage_dir, file_id, "hint")?;
+        HashEngine::recover_merge(&builder.storage_dir, builder.options.keep_old_files)?;
+        let mut key_dirs =
+            ShardedKeyDirs::new(builder.options.key_dirs, builder.options.key_dirs_shards);
+        let file_id =
+            HashEngine::load_hints(&builder.storage_dir, &builder.options, &mut key_dirs)?;
+        let active_file = DHFile::new(file_id);
+        let data_files = DataFiles::new(
+            builder.options.max_open_files,
+            builder.options.mmap_sealed_files,
+        );
+        let active_file = Arc::new(RwLock::new(active_file));
+        let syncer = Arc::new(Syncer::new(builder.options.durability));
+        Syncer::spawn_periodic(&syncer, &active_file);
 
-        Ok(HashEngine {
+        let engine = HashEngine {
             options: builder.options,
             storage_dir: builder.storage_dir,
-            key_dirs: Arc::new(RwLock::new(key_dirs)),
-            active_file: DHFile {
-                write_offset: data_file.metadata()?.len(),
-                file_id,
-                data_file: Arc::new(RwLock::new(data_file)),
-                hint_file: Arc::new(RwLock::new(hint_file)),
-            },
-            old_files: DataFiles::new(),
-        })
+            key_dirs: Arc::new(key_dirs),
+            active_file,
+            data_files,
+            syncer,
+            versions: Arc::new(Mutex::new(Versions::default())),
+            merging: Arc::new(Mutex::new(())),
+        };
+        if let Some(reap_interval) = engine.options.reap_interval {
+            HashEngine::spawn_reaper(reap_interval, engine.downgrade());
+        }
+        if let MergePolicy::Auto(ref thresholds) = engine.options.merge_policy {
+            HashEngine::spawn_merger(thresholds.clone(), engine.downgrade());
+        }
+
+        Ok(engine)
     }
 
-    fn check_file_size(&mut self) -> Result<(), Error> {
-        if self.active_file.write_offset >= self.options.file_size_limit {
-            let file_id = Utc::now().timestamp_nanos() as _;
-            self.active_file = DHFile {
-                write_offset: 0,
+    /// Size and dead bytes of every data file, as weighed by `MergePolicy::Auto`.
+    pub fn file_stats(&self) -> Result<Vec<FileStats>, Error> {
+        // --- std ---
+        use std::fs::File;
+
+        let mut live_bytes = HashMap::new();
+        let now = Utc::now().timestamp_nanos() as _;
+        for shard in self.key_dirs.shards() {
+            for (key, entry) in shard.read().unwrap().iter() {
+                if !entry.is_expired(now) {
+                    *live_bytes.entry(entry.file_id).or_insert(0) += entry.record_size(key.len());
+                }
+            }
+        }
+
+        let (files, _) = HashEngine::scan_and_sort_dh_files(&self.storage_dir, "data")?;
+        let active_file_id = self.active_file.read().unwrap().file_id;
+        let mut file_stats = vec![];
+        for (path, file_id) in files {
+            let file = File::open(&path)?;
+            let total_bytes = file.metadata()?.len();
+            // the file header is neither dead nor reclaimable
+            let live_bytes = live_bytes.get(&file_id).cloned().unwrap_or(0)
+                + DHFile::data_offset(DHFile::read_version(&file, file_id)?);
+
+            file_stats.push(FileStats {
                 file_id,
-                data_file: Arc::new(RwLock::new(DHFile::set_active_file(
-                    &self.storage_dir,
-                    file_id,
-                    "data",
-                )?)),
-                hint_file: Arc::new(RwLock::new(DHFile::set_active_file(
-                    &self.storage_dir,
-                    file_id,
-                    "hint",
-                )?)),
-            };
+                active: file_id == active_file_id,
+                total_bytes,
+                dead_bytes: total_bytes.saturating_sub(live_bytes),
+            });
         }
 
-        Ok(())
+        Ok(file_stats)
     }
-}
 
-impl super::Engine for HashEngine {
-    fn put(&mut self, k: Vec<u8>, v: Vec<u8>) -> Result<(), Error> {
-        self.check_file_size()?;
-        let entry = self.active_file.write(&k, &v)?;
-        self.key_dirs.write().unwrap().insert(k, entry);
-
-        Ok(())
+    fn downgrade(&self) -> WeakHashEngine {
+        WeakHashEngine {
+            options: self.options.clone(),
+            storage_dir: self.storage_dir.clone(),
+            key_dirs: Arc::downgrade(&self.key_dirs),
+            active_file: Arc::downgrade(&self.active_file),
+            data_files: self.data_files.clone(),
+            syncer: Arc::downgrade(&self.syncer),
+            versions: Arc::downgrade(&self.versions),
+            merging: Arc::downgrade(&self.merging),
+        }
     }
 
-    fn get(&mut self, k: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+    // evaluate the thresholds every `check_interval` until the engine is dropped
+    fn spawn_merger(thresholds: MergeThresholds, engine: WeakHashEngine) {
         // --- std ---
-        use std::io::{Seek, SeekFrom};
-
-        if let Some(entry) = self.key_dirs.read().unwrap().get(k) {
-            if self.active_file.file_id == entry.file_id {
-                let buffer = entry.seek_value(&mut self.active_file.data_file.write().unwrap())?;
-                self.active_file
-                    .data_file
-                    .write()
-                    .unwrap()
-                    .seek(SeekFrom::End(0))?;
-
-                Ok(Some(buffer))
-            } else {
-                if let Some(mut file) = self.old_files.try_get(&self.storage_dir, entry.file_id)? {
-                    Ok(Some(entry.seek_value(&mut file)?))
-                } else {
-                    Err(HashEngineError::FileNotFound {
+        use std::thread;
+        // --- external ---
+        use chrono::{Local, Timelike};
+
+        thread::spawn(move || loop {
+            thread::sleep(thresholds.check_interval);
+
+            let engine = match engine.upgrade() {
+                Some(engine) => engine,
+                None => break,
+            };
+            if !thresholds.in_window(Local::now().hour()) {
+                continue;
+            }
+
+            let result = engine.file_stats().and_then(|file_stats| {
+                engine.merge_files(&thresholds.select(&file_stats), &mut |_| true)
+            });
+            if let Err(e) = result {
+                eprintln!("Failed to merge: {:?}", e);
+            }
+        });
+    }
+
+    /// A consistent point-in-time view for gets and scans, until it's dropped.
+    pub fn snapshot(&self) -> HashSnapshot {
+        HashSnapshot::new(self.clone())
+    }
+
+    /// `Engine::get` without the copy of a value of a mapped file, see
+    /// `Options::mmap_sealed_files`.
+    ///
+    /// The mapping stays valid as long as the value, even if a merge removes its file.
+    pub fn get_value(&self, k: &[u8]) -> Result<Option<Value>, Error> {
+        self.get_at(k, None)
+    }
+
+    // the latest version of `k` with `None`, the one seen by a snapshot otherwise
+    fn get_at(&self, k: &[u8], seq: Option<u64>) -> Result<Option<Value>, Error> {
+        // held until it's read, a merge can't move the entry before
+        let key_dirs = self.key_dirs.shard(k).read().unwrap();
+        let entry = match seq {
+            Some(seq) => self.versions.lock().unwrap().get(&key_dirs, k, seq),
+            None => key_dirs.get(k),
+        };
+
+        match entry {
+            Some(ref entry) if !entry.is_expired(Utc::now().timestamp_nanos() as _) => {
+                Ok(Some(self.read_value(k, entry)?))
+            }
+            //            Err(HashEngineError::KeyNotFound {
+            //                k: k.to_vec()
+            //            }.into())
+            // expired, the reaper may not have dropped it yet
+            _ => Ok(None),
+        }
+    }
+
+    fn scan_at(
+        &self,
+        mut scanner: Scanner,
+        seq: Option<u64>,
+    ) -> Result<(Scanner, Vec<(Vec<u8>, Value)>), Error> {
+        let shards = self.key_dirs.read_all();
+        // the whole round is read from the same version, not key by key
+        let views = match seq {
+            Some(seq) => {
+                let versions = self.versions.lock().unwrap();
+                shards
+                    .iter()
+                    .enumerate()
+                    .map(|(i, shard)| {
+                        versions.view(shard, seq, |k| self.key_dirs.shard_index(k) == i)
+                    })
+                    .collect::<Vec<_>>()
+            }
+            None => shards.iter().map(|shard| Cow::Borrowed(&**shard)).collect(),
+        };
+        let now = Utc::now().timestamp_nanos() as _;
+        let mut kvs = vec![];
+
+        match scanner {
+            Scanner::HashScanner(ref mut scanner) => {
+                let entries = views.iter().flat_map(|view| view.iter());
+                let keys = scanner.scan(entries, &mut |key, entry| {
+                    Ok(self.resolve_key(key, entry)?.into_owned())
+                })?;
+                for (k, entry) in keys {
+                    // the reaper may not have dropped it yet
+                    if entry.is_expired(now) {
+                        continue;
+                    }
+
+                    let v = self.read_value(&k, &entry)?;
+                    kvs.push((k, v));
+                }
+            }
+            Scanner::RangeScanner(ref mut scanner) => {
+                // `KeyDirs` has no order, sort the keys in range
+                let mut keys = vec![];
+                for (key, entry) in views.iter().flat_map(|view| view.iter()) {
+                    if entry.is_expired(now) {
+                        continue;
+                    }
+                    let k = self.resolve_key(key, &entry)?;
+                    if scanner.contains(&k) {
+                        keys.push((k, entry));
+                    }
+                }
+                keys.sort_by(|(a, _), (b, _)| a.cmp(b));
+                if scanner.order == Order::Desc {
+                    keys.reverse();
+                }
+
+                for (k, entry) in keys {
+                    let v = self.read_value(&k, &entry)?;
+                    if !scanner.feed(&k, &v, &mut kvs) {
+                        break;
+                    }
+                }
+            }
+            _ => return Err(HashEngineError::UnsupportedScanner.into()),
+        }
+
+        Ok((scanner, kvs))
+    }
+
+    // the bytes of `key`, read back from the data file of `entry` if it's only hashed
+    fn resolve_key<'a>(&self, key: Key<'a>, entry: &Entry) -> Result<Cow<'a, [u8]>, Error> {
+        match key {
+            Key::Slice(k) => Ok(Cow::Borrowed(k)),
+            Key::Hashed { len, .. } => {
+                match self.data_files.try_get(&self.storage_dir, entry.file_id)? {
+                    Some(file) => Ok(Cow::Owned(entry.seek_key(&file, len)?)),
+                    None => Err(HashEngineError::FileNotFound {
                         path: format!("{}/{}", self.storage_dir, entry.file_id),
                     }
-                    .into())
-                    //                    Ok(None)
+                    .into()),
                 }
             }
+        }
+    }
+
+    // positional reads, the active file included, they never wait on the writers
+    fn read_value(&self, k: &[u8], entry: &Entry) -> Result<Value, Error> {
+        if let Some(file) = self.data_files.try_get(&self.storage_dir, entry.file_id)? {
+            entry.seek_value(&file, k)
         } else {
-            //            Err(HashEngineError::KeyNotFound {
-            //                k: k.to_vec()
-            //            }.into())
-            Ok(None)
+            Err(HashEngineError::FileNotFound {
+                path: format!("{}/{}", self.storage_dir, entry.file_id),
+            }
+            .into())
         }
     }
 
-    fn del(&mut self, k: &[u8]) -> Result<(), Error> {
-        if self.key_dirs.read().unwrap().get(k).is_none() {
-            //            Err(HashEngineError::KeyNotFound {
-            //                k: k.to_vec()
-            //            }.into())
-            Ok(())
+    // append a put (`Some`) or a delete (`None`), then wait for the syncer
+    fn append(&self, k: Vec<u8>, v: Option<&[u8]>, expires_at: u64) -> Result<(), Error> {
+        let seq = self.append_locked(&mut self.active_file.write().unwrap(), k, v, expires_at)?;
+
+        self.syncer.sync(&self.active_file, seq)
+    }
+
+    // append to the locked active file, returning its sequence for the syncer
+    fn append_locked(
+        &self,
+        active_file: &mut DHFile,
+        k: Vec<u8>,
+        v: Option<&[u8]>,
+        expires_at: u64,
+    ) -> Result<u64, Error> {
+        self.check_file_size(active_file)?;
+        self.open_active_file(active_file)?;
+        let entry = active_file.write(&k, v, expires_at)?;
+        // update the key dirs in the write order, readers may see it before the fsync
+        let mut key_dirs = self.key_dirs.shard(&k).write().unwrap();
+        let mut versions = self.versions.lock().unwrap();
+        let version = versions.next_seq();
+        if v.is_some() {
+            versions.record(version, &k, key_dirs.get(&k).as_ref(), Some(&entry));
+            key_dirs.insert(k, entry);
         } else {
-            self.check_file_size()?;
-            self.active_file.write(k, &[])?;
-            self.key_dirs.write().unwrap().remove(k);
+            versions.record(version, &k, key_dirs.get(&k).as_ref(), None);
+            key_dirs.remove(&k);
+        }
 
-            Ok(())
+        Ok(self.syncer.written())
+    }
+
+    // append a batch to the locked active file, returning its sequence for the syncer
+    fn apply_batch(&self, active_file: &mut DHFile, batch: WriteBatch) -> Result<u64, Error> {
+        self.check_file_size(active_file)?;
+        self.open_active_file(active_file)?;
+        let entries = active_file.write_batch(&batch.ops)?;
+
+        // hold every shard of the batch until it's applied, readers never see half of it
+        let mut shard_indexes = batch
+            .ops
+            .iter()
+            .map(|(k, _)| self.key_dirs.shard_index(k))
+            .collect::<Vec<_>>();
+        shard_indexes.sort_unstable();
+        shard_indexes.dedup();
+        let mut shards = shard_indexes
+            .into_iter()
+            .map(|i| (i, self.key_dirs.shards()[i].write().unwrap()))
+            .collect::<HashMap<_, _>>();
+        // and it's a single version, snapshots never see half of it either
+        let mut versions = self.versions.lock().unwrap();
+        let version = versions.next_seq();
+        for ((k, v), entry) in batch.ops.into_iter().zip(entries) {
+            let key_dirs = shards.get_mut(&self.key_dirs.shard_index(&k)).unwrap();
+            if v.is_some() {
+                versions.record(version, &k, key_dirs.get(&k).as_ref(), Some(&entry));
+                key_dirs.insert(k, entry);
+            } else {
+                versions.record(version, &k, key_dirs.get(&k).as_ref(), None);
+                key_dirs.remove(&k);
+            }
+        }
+
+        Ok(self.syncer.written())
+    }
+
+    /// Drop the expired keys from the key dirs, their records go away with the next merge.
+    ///
+    /// A shard at a time, the others are read and written meanwhile. The expired keys are
+    /// looked for under the read lock, the write lock is only held to drop them, and the
+    /// shards without any key that expires are skipped.
+    fn reap(&self) -> Result<(), Error> {
+        for shard in self.key_dirs.shards() {
+            let expired_keys = {
+                let key_dirs = shard.read().unwrap();
+                if !key_dirs.has_expiring() {
+                    continue;
+                }
+
+                let now = Utc::now().timestamp_nanos() as _;
+                key_dirs
+                    .iter()
+                    .filter(|(_, entry)| entry.is_expired(now))
+                    .map(|(key, entry)| Ok(self.resolve_key(key, &entry)?.into_owned()))
+                    .collect::<Result<Vec<_>, Error>>()?
+            };
+            if expired_keys.is_empty() {
+                continue;
+            }
+
+            let mut key_dirs = shard.write().unwrap();
+            let mut versions = self.versions.lock().unwrap();
+            let now = Utc::now().timestamp_nanos() as _;
+            // snapshots hide them anyway, but their history must agree with the key dirs
+            let version = versions.next_seq();
+            for k in expired_keys {
+                // unless it was written again in the meantime
+                match key_dirs.get(&k) {
+                    Some(ref entry) if entry.is_expired(now) => {
+                        versions.record(version, &k, Some(entry), None);
+                        key_dirs.remove(&k);
+                    }
+                    _ => (),
+                }
+            }
         }
+
+        Ok(())
     }
 
-    // TODO Optimize
-    fn scan(&mut self, mut scanner: Scanner) -> Result<(Scanner, Vec<(Vec<u8>, Vec<u8>)>), Error> {
-        let keys = {
-            let scanner = match scanner {
-                Scanner::HashScanner(ref mut scanner) => scanner,
-                //            _ => unreachable!(),
+    // reap every `interval` until the engine is dropped
+    fn spawn_reaper(interval: Duration, engine: WeakHashEngine) {
+        // --- std ---
+        use std::thread;
+
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+
+            let engine = match engine.upgrade() {
+                Some(engine) => engine,
+                None => break,
             };
+            if let Err(e) = engine.reap() {
+                eprintln!("Failed to reap: {:?}", e);
+            }
+        });
+    }
+
+    /// Compact the immutable files `file_ids`, reads and writes go on meanwhile.
+    ///
+    /// Their records still referenced, by the key dirs or a snapshot, are copied to new
+    /// files. A run of merged files without any unmerged file in between has its copies
+    /// ordered between the unmerged files around it, so a reload replays the same history,
+    /// whichever files are selected.
+    /// Then every entry still pointing to a copied record is moved to its copy, the others
+    /// were overwritten in the meantime, and the old files go away.
+    ///
+    /// `progress` hears about every file copied, and may cancel the merge until the last
+    /// one is, see `Engine::merge_with_progress`.
+    pub fn merge_files(
+        &self,
+        file_ids: &[u64],
+        progress: &mut dyn FnMut(&MergeProgress) -> bool,
+    ) -> Result<bool, Error> {
+        // --- std ---
+        use std::fs::{create_dir, remove_dir_all};
+
+        let _merging = self
+            .merging
+            .try_lock()
+            .map_err(|_| HashEngineError::MergeLocked)?;
+        let merge_dir = MergeManifest::dir(&self.storage_dir);
+        // an earlier merge failed after it committed, it's completed on startup
+        if Path::new(&merge_dir).exists() {
+            return Err(HashEngineError::MergeLocked.into());
+        }
+
+        let active_file_id = self.active_file.read().unwrap().file_id;
+        let merged = file_ids
+            .iter()
+            .cloned()
+            .filter(|file_id| *file_id != active_file_id)
+            .collect::<HashSet<_>>();
+        let (files, _) = HashEngine::scan_and_sort_dh_files(&self.storage_dir, "data")?;
+        let (files, others): (Vec<_>, Vec<_>) = files
+            .into_iter()
+            .partition(|(_, file_id)| merged.contains(file_id));
+        if files.is_empty() {
+            return Ok(true);
+        }
+        // the ids the copies of each merged file may take, between the unmerged files around
+        // its run, the active file is always a newer one
+        let gaps = files
+            .iter()
+            .map(|(_, merged_file_id)| {
+                let mut unmerged = others.iter().map(|(_, file_id)| *file_id);
+                let lower = unmerged
+                    .clone()
+                    .filter(|file_id| file_id < merged_file_id)
+                    .last()
+                    .unwrap_or(0);
+                let upper = unmerged
+                    .find(|file_id| file_id > merged_file_id)
+                    .unwrap_or(active_file_id);
+
+                (*merged_file_id, (lower, upper))
+            })
+            .collect::<HashMap<_, _>>();
+        // a tombstone may shadow a record of an older file that isn't merged
+        let oldest_unmerged = others
+            .first()
+            .map(|(_, file_id)| *file_id)
+            .unwrap_or(u64::max_value());
+
+        // the records to copy, the expired ones are dropped instead
+        let live = {
+            let shards = self.key_dirs.read_all();
+            let versions = self.versions.lock().unwrap();
+            let now = Utc::now().timestamp_nanos() as _;
 
-            scanner.scan(&self.key_dirs.read().unwrap())
+            shards
+                .iter()
+                .flat_map(|key_dirs| key_dirs.iter().map(|(_, entry)| entry))
+                .chain(versions.entries().cloned())
+                .filter(|entry| merged.contains(&entry.file_id) && !entry.is_expired(now))
+                .map(|entry| (entry.file_id, entry.value_position))
+                .collect::<HashSet<_>>()
         };
-        let mut kvs = vec![];
 
-        for k in keys {
-            if let Some(v) = self.get(&k)? {
-                kvs.push((k, v));
+        // the copies stay out of sight until the manifest commits them
+        create_dir(&merge_dir)?;
+        let manifest = MergeManifest {
+            merged: files.iter().map(|(_, file_id)| *file_id).collect(),
+        };
+        let moved = match self
+            .copy_records(&merge_dir, &files, &live, &gaps, oldest_unmerged, progress)
+            .and_then(|moved| match moved {
+                Some(moved) => manifest.commit(&merge_dir).map(|_| Some(moved)),
+                None => Ok(None),
+            }) {
+            Ok(Some(moved)) => moved,
+            // cancelled, or failed, before the commit: the merged files are untouched
+            result => {
+                remove_dir_all(&merge_dir)?;
+                return result.map(|_| false);
+            }
+        };
+        HashEngine::install_copies(&self.storage_dir)?;
+
+        {
+            // every shard at once, with the versions, a snapshot sees all of the swap or none
+            let mut shards = self.key_dirs.write_all();
+            let mut versions = self.versions.lock().unwrap();
+
+            let mut expired_keys = vec![];
+            for key_dirs in shards.iter_mut() {
+                key_dirs.update_entries(|entry| match merged.contains(&entry.file_id) {
+                    true => moved.get(&(entry.file_id, entry.value_position)).cloned(),
+                    false => None,
+                });
+                // the ones left weren't copied, they had expired
+                for (key, entry) in key_dirs.iter() {
+                    if merged.contains(&entry.file_id) {
+                        expired_keys.push(self.resolve_key(key, &entry)?.into_owned());
+                    }
+                }
+            }
+            if !expired_keys.is_empty() {
+                let version = versions.next_seq();
+                for k in expired_keys {
+                    let key_dirs = &mut shards[self.key_dirs.shard_index(&k)];
+                    versions.record(version, &k, key_dirs.get(&k).as_ref(), None);
+                    key_dirs.remove(&k);
+                }
+            }
+
+            for entry in versions.entries_mut() {
+                let location = match entry {
+                    Some(old) if merged.contains(&old.file_id) => (old.file_id, old.value_position),
+                    _ => continue,
+                };
+                // an expired version is as good as deleted for a snapshot
+                *entry = moved.get(&location).cloned();
             }
         }
 
-        Ok((scanner, kvs))
+        self.data_files.remove(&manifest.merged);
+        HashEngine::remove_merged(
+            &self.storage_dir,
+            self.options.keep_old_files,
+            &manifest.merged,
+        )?;
+
+        remove_dir_all(&merge_dir)?;
+
+        Ok(true)
     }
 
-    fn merge(&mut self) -> Result<(), Error> {
+    // copy the `live` records of `files` and the tombstones still needed to `merge_dir`,
+    // with ids in the `gaps` of their files, returning (file id, value position) of every
+    // copied record -> its copy, `None` if `progress` cancelled it
+    fn copy_records(
+        &self,
+        merge_dir: &str,
+        files: &[(String, u64)],
+        live: &HashSet<(u64, u64)>,
+        gaps: &HashMap<u64, (u64, u64)>,
+        oldest_unmerged: u64,
+        progress: &mut dyn FnMut(&MergeProgress) -> bool,
+    ) -> Result<Option<HashMap<(u64, u64), Entry>>, Error> {
         // --- std ---
         use std::{
-            collections::HashMap,
-            fs::{create_dir, remove_dir_all, rename, File},
+            fs::{create_dir, metadata, remove_dir, rename, File},
+            time::Instant,
         };
 
-        const MERGE_DIR: &'static str = ".merge";
-
-        create_dir(MERGE_DIR)?;
-        let mut file_id = Utc::now().timestamp_nanos() as _;
-        let mut dh_file = DHFile {
-            write_offset: 0,
-            file_id,
-            data_file: Arc::new(RwLock::new(DHFile::set_active_file(
-                MERGE_DIR, file_id, "data",
-            )?)),
-            hint_file: Arc::new(RwLock::new(DHFile::set_active_file(
-                MERGE_DIR, file_id, "hint",
-            )?)),
+        // the copies are numbered from 0 until they're all written, their ids depend on
+        // how many of them each gap gets
+        let copies_dir = format!("{}/copies", merge_dir);
+        create_dir(&copies_dir)?;
+        let now = Utc::now().timestamp_nanos() as _;
+        let start = Instant::now();
+        let mut bytes_total = 0;
+        for (path, _) in files.iter() {
+            bytes_total += metadata(path)?.len();
+        }
+        let mut bytes_read = 0;
+        let mut merge_progress = MergeProgress {
+            files_total: files.len() as _,
+            ..Default::default()
         };
+        let mut dh_files: Vec<((u64, u64), DHFile)> = vec![];
+        let mut moved = HashMap::new();
+        let mut copied_keys = HashSet::new();
+        for (path, merged_file_id) in files.iter() {
+            let file = Arc::new(File::open(path)?);
+            let hints = match DHFile::read_hints(
+                &format!("{}/{}.hint", self.storage_dir, merged_file_id),
+                *merged_file_id,
+                DHFile::read_version(&file, *merged_file_id)?,
+                file.metadata()?.len(),
+            )? {
+                Some(hints) => hints,
+                None => DHFile::rebuild_hints(path, *merged_file_id)?,
+            };
+            let data_file = DataFile::File(file.clone());
+            let gap = gaps[merged_file_id];
 
-        let mut w = self.key_dirs.write().unwrap();
+            for hint in hints {
+                let location = (hint.entry.file_id, hint.entry.value_position);
+                let v = if live.contains(&location) {
+                    Some(hint.entry.seek_value(&data_file, &hint.key)?)
+                } else if hint.tombstone || hint.entry.is_expired(now) {
+                    // a tombstone, or one in place of an expired record, shadows nothing
+                    // anymore once the older records are gone with the merge
+                    if oldest_unmerged > *merged_file_id && !copied_keys.contains(&hint.key) {
+                        continue;
+                    }
 
-        {
-            let mut file_map = HashMap::new();
-            let (files, _) = HashEngine::scan_and_sort_dh_files(&self.storage_dir, "data")?;
-            for (path, file_id) in files.iter() {
-                file_map.insert(file_id, File::open(path)?);
+                    None
+                } else {
+                    continue;
+                };
+
+                // a copy only holds the records of a single gap
+                if dh_files.last().map_or(true, |(dh_file_gap, dh_file)| {
+                    *dh_file_gap != gap || dh_file.is_full(self.options.file_size_limit)
+                }) {
+                    dh_files.push((gap, DHFile::open(&copies_dir, dh_files.len() as _)?));
+                }
+
+                let entry = dh_files.last_mut().unwrap().1.write(
+                    &hint.key,
+                    v.as_ref().map(|v| &v[..]),
+                    if v.is_some() {
+                        hint.entry.expires_at
+                    } else {
+                        0
+                    },
+                )?;
+                if v.is_some() {
+                    moved.insert(location, entry);
+                    copied_keys.insert(hint.key);
+                }
             }
 
-            for (k, entry) in w.iter_mut() {
-                if let Some(file) = file_map.get_mut(&entry.file_id) {
-                    if dh_file.write_offset >= self.options.file_size_limit {
-                        file_id = Utc::now().timestamp_nanos() as _;
-                        dh_file = DHFile {
-                            write_offset: 0,
-                            file_id,
-                            data_file: Arc::new(RwLock::new(DHFile::set_active_file(
-                                MERGE_DIR, file_id, "data",
-                            )?)),
-                            hint_file: Arc::new(RwLock::new(DHFile::set_active_file(
-                                MERGE_DIR, file_id, "hint",
-                            )?)),
-                        };
-                    }
+            bytes_read += file.metadata()?.len();
+            merge_progress.files_merged += 1;
+            merge_progress.bytes_rewritten = dh_files
+                .iter()
+                .map(|(_, dh_file)| dh_file.write_offset)
+                .sum();
+            merge_progress.bytes_reclaimed =
+                bytes_read.saturating_sub(merge_progress.bytes_rewritten);
+            // assuming the bytes left go as fast as the ones read so far
+            merge_progress.eta = Some(
+                start
+                    .elapsed()
+                    .mul_f64((bytes_total - bytes_read) as f64 / bytes_read.max(1) as f64),
+            );
+            if !progress(&merge_progress) {
+                return Ok(None);
+            }
+        }
+
+        // the copies replace the merged files, they're synced whatever the mode
+        let merged = files
+            .iter()
+            .map(|(_, file_id)| *file_id)
+            .collect::<HashSet<_>>();
+        let mut file_ids = vec![];
+        for (i, (gap, dh_file)) in dh_files.iter().enumerate() {
+            dh_file.sync()?;
+            dh_file.seal(&copies_dir)?;
+            if i == 0 || dh_files[i - 1].0 != *gap {
+                let count = dh_files[i..].iter().take_while(|(g, _)| g == gap).count();
+                file_ids.extend(HashEngine::spread_file_ids(*gap, count, &merged)?);
+            }
+        }
+        for (copy, file_id) in file_ids.iter().enumerate() {
+            for extension in &["hint", "data"] {
+                rename(
+                    format!("{}/{}.{}", copies_dir, copy, extension),
+                    format!("{}/{}.{}", merge_dir, file_id, extension),
+                )?;
+            }
+        }
+        remove_dir(&copies_dir)?;
+        for entry in moved.values_mut() {
+            entry.file_id = file_ids[entry.file_id as usize];
+        }
 
-                    let v = entry.seek_value(file)?;
-                    let new_entry = dh_file.write(k, &v)?;
-                    *entry = new_entry;
+        Ok(Some(moved))
+    }
+
+    // `count` ascending ids spread evenly over the gap `(lower, upper)`, each leaving room
+    // for the copies of later merges, none of them `taken`
+    fn spread_file_ids(
+        (lower, upper): (u64, u64),
+        count: usize,
+        taken: &HashSet<u64>,
+    ) -> Result<Vec<u64>, Error> {
+        let step = (upper - lower) / (count as u64 + 1);
+        (1..=count as u64)
+            .map(|i| {
+                // the merged files are still there, any id up to the next slot will do
+                (lower + step * i..lower + step * (i + 1))
+                    .find(|file_id| !taken.contains(file_id))
+                    .ok_or_else(|| HashEngineError::FileIdsExhausted { lower, upper }.into())
+            })
+            .collect()
+    }
+
+    // move the committed copies of a merge from the merge dir to the storage dir
+    fn install_copies(storage_dir: &str) -> Result<(), Error> {
+        // --- std ---
+        use std::fs::rename;
+
+        let merge_dir = MergeManifest::dir(storage_dir);
+        let (copies, _) = HashEngine::scan_and_sort_dh_files(&merge_dir, "data")?;
+        for (path, file_id) in copies {
+            // the hint first, a data file without it would only get it rebuilt
+            let hint_path = format!("{}/{}.hint", merge_dir, file_id);
+            if Path::new(&hint_path).is_file() {
+                rename(hint_path, format!("{}/{}.hint", storage_dir, file_id))?;
+            }
+            rename(path, format!("{}/{}.data", storage_dir, file_id))?;
+        }
+
+        sync_dir(storage_dir)
+    }
+
+    // remove (or back up) the merged files once their copies are installed
+    fn remove_merged(storage_dir: &str, keep_old_files: bool, merged: &[u64]) -> Result<(), Error> {
+        // --- std ---
+        use std::fs::{create_dir_all, remove_file, rename};
+
+        let backup_dir = Path::new(storage_dir)
+            .parent()
+            .unwrap()
+            .join(&format!("backup-data-{}", Utc::now().timestamp_nanos()));
+        // oldest first, a tombstone never goes before the records it shadows, and the
+        // data before the hint, a legacy file can't be read without it
+        for file_id in merged {
+            for extension in &["data", "hint"] {
+                let path = format!("{}/{}.{}", storage_dir, file_id, extension);
+                if !Path::new(&path).is_file() {
+                    continue;
+                }
+
+                if keep_old_files {
+                    create_dir_all(&backup_dir)?;
+                    rename(&path, backup_dir.join(format!("{}.{}", file_id, extension)))?;
                 } else {
-                    return Err(HashEngineError::FileNotFound {
-                        path: format!("{}/{}", self.storage_dir, entry.file_id),
-                    }
-                    .into());
+                    remove_file(&path)?;
                 }
             }
         }
 
-        self.active_file = dh_file;
-        self.old_files = DataFiles::new();
+        sync_dir(storage_dir)
+    }
 
-        if self.options.keep_old_files {
-            rename(
-                &self.storage_dir,
-                Path::new(&self.storage_dir)
-                    .parent()
-                    .unwrap()
-                    .join(&format!("backup-data-{}", Utc::now().timestamp_nanos())),
-            )?;
-        } else {
-            remove_dir_all(&self.storage_dir)?;
+    // complete a merge interrupted after its commit, roll back one interrupted before
+    fn recover_merge(storage_dir: &str, keep_old_files: bool) -> Result<(), Error> {
+        // --- std ---
+        use std::fs::remove_dir_all;
+
+        let merge_dir = MergeManifest::dir(storage_dir);
+        if !Path::new(&merge_dir).is_dir() {
+            return Ok(());
         }
-        rename(MERGE_DIR, &self.storage_dir)?;
 
-        drop(w);
+        // without a manifest the merged files are untouched, the copies are just dropped
+        if let Some(manifest) = MergeManifest::read(&merge_dir)? {
+            HashEngine::install_copies(storage_dir)?;
+            HashEngine::remove_merged(storage_dir, keep_old_files, &manifest.merged)?;
+        }
+
+        Ok(remove_dir_all(merge_dir)?)
+    }
+
+    fn check_file_size(&self, active_file: &mut DHFile) -> Result<(), Error> {
+        if active_file.is_full(self.options.file_size_limit) {
+            self.rotate(active_file)?;
+        }
+
+        Ok(())
+    }
+
+    // unix nanos `ttl` from now, saturating: a ttl beyond what the clock can count never expires
+    fn expires_at(ttl: Duration) -> u64 {
+        // --- std ---
+        use std::convert::TryFrom;
+
+        let ttl = u64::try_from(ttl.as_nanos()).unwrap_or(u64::max_value());
+
+        (Utc::now().timestamp_nanos() as u64).saturating_add(ttl)
+    }
+
+    // seal the active file and carry on in a new one, created on its first write
+    fn rotate(&self, active_file: &mut DHFile) -> Result<(), Error> {
+        self.syncer.rotate(active_file)?;
+        active_file.seal(&self.storage_dir)?;
+        self.data_files.seal(active_file.file_id);
+        *active_file = DHFile::new(Utc::now().timestamp_nanos() as _);
 
         Ok(())
     }
+
+    // create the active file if it's not on the disk yet, its readers share the handle
+    // it's appended through
+    fn open_active_file(&self, active_file: &mut DHFile) -> Result<(), Error> {
+        if active_file.data_file.is_none() {
+            *active_file = DHFile::open(&self.storage_dir, active_file.file_id)?;
+            if let Some(ref data_file) = active_file.data_file {
+                self.data_files
+                    .insert(active_file.file_id, data_file.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl super::Engine for HashEngine {
+    fn put(&mut self, k: Vec<u8>, v: Vec<u8>) -> Result<(), Error> {
+        self.append(k, Some(&v), 0)
+    }
+
+    fn put_with_ttl(&mut self, k: Vec<u8>, v: Vec<u8>, ttl: Duration) -> Result<(), Error> {
+        self.append(k, Some(&v), HashEngine::expires_at(ttl))
+    }
+
+    fn get(&mut self, k: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        Ok(self.get_at(k, None)?.map(Value::into_vec))
+    }
+
+    fn get_value(&mut self, k: &[u8]) -> Result<Option<Value>, Error> {
+        self.get_at(k, None)
+    }
+
+    fn del(&mut self, k: &[u8]) -> Result<(), Error> {
+        if self.key_dirs.shard(k).read().unwrap().get(k).is_none() {
+            //            Err(HashEngineError::KeyNotFound {
+            //                k: k.to_vec()
+            //            }.into())
+            Ok(())
+        } else {
+            self.append(k.to_vec(), None, 0)
+        }
+    }
+
+    fn replace(
+        &mut self,
+        k: Vec<u8>,
+        v: Option<Vec<u8>>,
+        ttl: Option<Duration>,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        let expires_at = match ttl {
+            Some(ttl) => HashEngine::expires_at(ttl),
+            None => 0,
+        };
+        let (previous, seq) = {
+            // writers and merges wait on the active file, `k` can't change before the write
+            let mut active_file = self.active_file.write().unwrap();
+            let previous = {
+                let key_dirs = self.key_dirs.shard(&k).read().unwrap();
+                match key_dirs.get(&k) {
+                    Some(ref entry) if !entry.is_expired(Utc::now().timestamp_nanos() as _) => {
+                        Some(self.read_value(&k, entry)?.into_vec())
+                    }
+                    _ => None,
+                }
+            };
+            // nothing to delete, like `del`
+            if v.is_none() && previous.is_none() {
+                return Ok(None);
+            }
+
+            (
+                previous,
+                self.append_locked(&mut active_file, k, v.as_deref(), expires_at)?,
+            )
+        };
+        self.syncer.sync(&self.active_file, seq)?;
+
+        Ok(previous)
+    }
+
+    fn write_batch(&mut self, batch: WriteBatch) -> Result<(), Error> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let seq = self.apply_batch(&mut self.active_file.write().unwrap(), batch)?;
+
+        self.syncer.sync(&self.active_file, seq)
+    }
+
+    fn commit(&mut self, transaction: Transaction) -> Result<bool, Error> {
+        let seq = {
+            // writers and merges wait on the active file, the reads can't change before the writes
+            let mut active_file = self.active_file.write().unwrap();
+            {
+                let now = Utc::now().timestamp_nanos() as _;
+                for (k, v) in transaction.reads.iter() {
+                    let key_dirs = self.key_dirs.shard(k).read().unwrap();
+                    let current = match key_dirs.get(k) {
+                        Some(ref entry) if !entry.is_expired(now) => {
+                            Some(self.read_value(k, entry)?)
+                        }
+                        _ => None,
+                    };
+                    if current.as_deref() != v.as_deref() {
+                        return Ok(false);
+                    }
+                }
+            }
+            if transaction.writes.is_empty() {
+                return Ok(true);
+            }
+
+            self.apply_batch(&mut active_file, transaction.writes)?
+        };
+        self.syncer.sync(&self.active_file, seq)?;
+
+        Ok(true)
+    }
+
+    fn scanner(&self, cursor: Vec<u8>, range: i64, regex: Option<Regex>) -> Scanner {
+        Scanner::HashScanner(HashScanner {
+            cursor,
+            range,
+            regex,
+        })
+    }
+
+    fn scan(&mut self, scanner: Scanner) -> Result<(Scanner, Vec<(Vec<u8>, Vec<u8>)>), Error> {
+        let (scanner, kvs) = self.scan_at(scanner, None)?;
+
+        Ok((
+            scanner,
+            kvs.into_iter().map(|(k, v)| (k, v.into_vec())).collect(),
+        ))
+    }
+
+    fn scan_values(&mut self, scanner: Scanner) -> Result<(Scanner, Vec<(Vec<u8>, Value)>), Error> {
+        self.scan_at(scanner, None)
+    }
+
+    fn merge_with_progress(
+        &mut self,
+        progress: &mut dyn FnMut(&MergeProgress) -> bool,
+    ) -> Result<bool, Error> {
+        // everything written so far is merged, the writes go on in a new active file
+        let merged_file_id = {
+            let mut active_file = self.active_file.write().unwrap();
+            let merged_file_id = active_file.file_id;
+            self.rotate(&mut active_file)?;
+
+            merged_file_id
+        };
+        let (files, _) = HashEngine::scan_and_sort_dh_files(&self.storage_dir, "data")?;
+
+        self.merge_files(
+            &files
+                .into_iter()
+                .map(|(_, file_id)| file_id)
+                .filter(|file_id| *file_id <= merged_file_id)
+                .collect::<Vec<_>>(),
+            progress,
+        )
+    }
+
+    fn stats(&self) -> Stats {
+        Stats {
+            engine: "hash".to_string(),
+            durability: self.syncer.durability().to_string(),
+            writes: self.syncer.writes(),
+            syncs: self.syncer.syncs(),
+            keys: self.key_dirs.len() as _,
+            key_dirs_bytes: self.key_dirs.memory_usage(),
+            open_files: self.data_files.open_files(),
+            file_cache_hits: self.data_files.hits(),
+            file_cache_misses: self.data_files.misses(),
+            file_cache_evictions: self.data_files.evictions(),
+        }
+    }
 }
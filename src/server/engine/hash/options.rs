@@ -1,7 +1,123 @@
-// TODO
-#[derive(Clone)]
+// --- std ---
+use std::{fmt, time::Duration};
+// --- custom ---
+use super::KeyDirsLayout;
+
+/// When `HashEngine` merges its data files.
+#[derive(Clone, Debug)]
 pub enum MergePolicy {
-    Test,
+    /// Only merge when asked to, through `Engine::merge`.
+    Manual,
+    /// Merge from a background thread once one of the thresholds is reached.
+    Auto(MergeThresholds),
+}
+
+/// Bitcask-style merge triggers, any of them reached starts a merge.
+#[derive(Clone, Debug)]
+pub struct MergeThresholds {
+    // how often they're evaluated
+    pub check_interval: Duration,
+    // the local hours `[start, end)` a merge may start in, e.g. `(2, 5)`, wrapping
+    // around midnight if `start > end`, `None` for any time
+    pub window: Option<(u32, u32)>,
+    // ratio of dead bytes in a single data file
+    pub fragmentation: f64,
+    // dead bytes across all the data files
+    pub dead_bytes: u64,
+    // number of immutable data files, the active one isn't merged
+    pub file_count: usize,
+}
+
+impl Default for MergeThresholds {
+    fn default() -> Self {
+        Self {
+            check_interval: Duration::from_secs(3 * 60),
+            window: None,
+            fragmentation: 0.6,
+            dead_bytes: 512 * 0x100000,
+            file_count: 64,
+        }
+    }
+}
+
+/// The bytes of a data file, and how many of them a merge would reclaim.
+#[derive(Clone, Debug)]
+pub struct FileStats {
+    pub file_id: u64,
+    // the one being appended to, it's never merged
+    pub active: bool,
+    pub total_bytes: u64,
+    // records that are overwritten, deleted or expired, and tombstones
+    pub dead_bytes: u64,
+}
+
+impl MergeThresholds {
+    pub fn in_window(&self, hour: u32) -> bool {
+        match self.window {
+            Some((start, end)) if start <= end => start <= hour && hour < end,
+            Some((start, end)) => hour >= start || hour < end,
+            None => true,
+        }
+    }
+
+    /// The immutable files to merge, none if no threshold is reached.
+    ///
+    /// The fragmented files alone, unless there are too many files or dead bytes
+    /// overall, then every one of them.
+    pub fn select(&self, files: &[FileStats]) -> Vec<u64> {
+        let immutable_files = files.iter().filter(|file| !file.active);
+        let selected = if immutable_files.clone().count() >= self.file_count
+            || files.iter().map(|file| file.dead_bytes).sum::<u64>() >= self.dead_bytes
+        {
+            immutable_files.collect::<Vec<_>>()
+        } else {
+            immutable_files
+                .filter(|file| {
+                    file.total_bytes != 0
+                        && file.dead_bytes as f64 / file.total_bytes as f64 >= self.fragmentation
+                })
+                .collect()
+        };
+
+        // rewriting files without anything to reclaim would only move them around
+        if selected.iter().all(|file| file.dead_bytes == 0) {
+            vec![]
+        } else {
+            selected.into_iter().map(|file| file.file_id).collect()
+        }
+    }
+}
+
+/// When a write reaches the disk, from the safest to the fastest.
+///
+/// A batch, or the writes of a committed transaction, is appended and synced as a single
+/// write, and recovered all-or-nothing after a crash whatever the mode. The mode only
+/// decides whether it's on the disk once acknowledged.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Durability {
+    /// Fsync every write before acknowledging it, an acknowledged batch or commit survives
+    /// a crash.
+    Sync,
+    /// Writers arriving within `window` of each other share a single fsync, acknowledged
+    /// once it's done, as safe as `Sync`.
+    GroupCommit { window: Duration },
+    /// Fsync in the background every `interval`, a crash loses at most that much, the
+    /// batches and commits acknowledged in it included.
+    Periodic { interval: Duration },
+    /// Only fsync a file as it's rotated, leave the rest of the flushing to the OS, a crash
+    /// may lose any acknowledged batch or commit not flushed yet.
+    Buffered,
+}
+
+impl fmt::Display for Durability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Durability::Sync => write!(f, "sync"),
+            Durability::GroupCommit { window } => write!(f, "group-commit({:?})", window),
+            Durability::Periodic { interval } => write!(f, "periodic({:?})", interval),
+            Durability::Buffered => write!(f, "buffered"),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -9,6 +125,22 @@ pub struct Options {
     pub file_size_limit: u64,
     pub keep_old_files: bool,
     pub merge_policy: MergePolicy,
+    // truncate a torn record at the tail of the active file on startup instead of failing
+    pub truncate_torn_tail: bool,
+    // rebuild every hint from the data on startup and rewrite the ones that don't match
+    pub verify_hints: bool,
+    pub durability: Durability,
+    // how often the expired keys are dropped from the key dirs, `None` (the default) to only
+    // drop them on merge, they're hidden from reads either way
+    pub reap_interval: Option<Duration>,
+    // how the key dirs hold the keys, see `Stats::key_dirs_bytes` for what it costs
+    pub key_dirs: KeyDirsLayout,
+    // the key dirs are split in that many shards by key hash, each behind its own lock
+    pub key_dirs_shards: usize,
+    // map the sealed data files in memory, `HashEngine::get_value` borrows from the mapping
+    pub mmap_sealed_files: bool,
+    // data files kept open (or mapped) at once, the least recently read ones are closed first
+    pub max_open_files: usize,
 }
 
 impl Default for Options {
@@ -16,7 +148,15 @@ impl Default for Options {
         Self {
             file_size_limit: 100 * 0x100000,
             keep_old_files: true,
-            merge_policy: MergePolicy::Test,
+            merge_policy: MergePolicy::Manual,
+            truncate_torn_tail: false,
+            verify_hints: false,
+            durability: Durability::Sync,
+            reap_interval: None,
+            key_dirs: KeyDirsLayout::Standard,
+            key_dirs_shards: 16,
+            mmap_sealed_files: false,
+            max_open_files: 1024,
         }
     }
 }
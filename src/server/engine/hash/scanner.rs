@@ -1,34 +1,78 @@
 // --- external ---
+use failure::Error;
 use regex::bytes::Regex;
 // --- custom ---
-use crate::server::engine::hash::KeyDirs;
+use crate::server::engine::hash::{Entry, HashEngineError, Key};
 
+/// Redis-like `SCAN` over the entries of `KeyDirs`, every shard of them.
+///
+/// Keys are visited in the order of a fixed 64-bit hash, independent of the
+/// capacity of the map, so a key present for the whole duration of a scan is
+/// returned at least once no matter how `KeyDirs` grows or shrinks in between.
+/// `cursor` is the next hash to visit (big-endian), empty means start over,
+/// and it's set back to empty once the scan is complete.
+///
+/// The order is the hash `KeyDirs` is indexed by, a `Hashed` key is only read back
+/// from the disk once it's in range.
 pub struct HashScanner {
+    pub cursor: Vec<u8>,
     pub range: i64,
     pub regex: Option<Regex>,
 }
 
 impl HashScanner {
-    pub fn scan(&mut self, key_dirs: &KeyDirs) -> Vec<Vec<u8>> {
-        let mut matched_keys = vec![];
+    /// The keys of the round and their entries, `resolve` reads back a `Hashed` key.
+    pub fn scan<'a>(
+        &mut self,
+        entries: impl Iterator<Item = (Key<'a>, Entry)>,
+        resolve: &mut dyn FnMut(Key, &Entry) -> Result<Vec<u8>, Error>,
+    ) -> Result<Vec<(Vec<u8>, Entry)>, Error> {
+        let cursor = match self.cursor.len() {
+            0 => 0,
+            8 => {
+                let mut cursor = [0; 8];
+                cursor.copy_from_slice(&self.cursor);
+                u64::from_be_bytes(cursor)
+            }
+            _ => {
+                return Err(HashEngineError::InvalidCursor {
+                    cursor: self.cursor.clone(),
+                }
+                .into())
+            }
+        };
 
-        let mut iter = key_dirs.iter();
-        while self.range != 0 {
-            self.range -= 1;
+        let mut keys = entries
+            .map(|(key, entry)| (key.hash(), key, entry))
+            .filter(|(hash, _, _)| *hash >= cursor)
+            .collect::<Vec<_>>();
+        keys.sort_unstable_by_key(|(hash, _, _)| *hash);
 
-            if let Some((k, _)) = iter.next() {
-                if let Some(ref regex) = self.regex {
-                    if regex.is_match(k) {
-                        matched_keys.push(k.to_owned());
-                    }
-                } else {
-                    matched_keys.push(k.to_owned())
+        let mut matched_keys = vec![];
+        let mut last_hash = None;
+        for (hash, key, entry) in keys {
+            // a hash is never split across two calls
+            if self.range == 0 && last_hash != Some(hash) {
+                self.cursor = hash.to_be_bytes().to_vec();
+                return Ok(matched_keys);
+            }
+            if self.range > 0 {
+                self.range -= 1;
+            }
+            last_hash = Some(hash);
+
+            let k = resolve(key, &entry)?;
+            if let Some(ref regex) = self.regex {
+                if regex.is_match(&k) {
+                    matched_keys.push((k, entry));
                 }
             } else {
-                break;
+                matched_keys.push((k, entry))
             }
         }
 
-        matched_keys
+        self.cursor.clear();
+
+        Ok(matched_keys)
     }
 }
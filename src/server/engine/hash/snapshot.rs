@@ -0,0 +1,170 @@
+// --- std ---
+use std::{borrow::Cow, collections::BTreeMap};
+// --- external ---
+use failure::Error;
+use hashbrown::HashMap;
+// --- custom ---
+use super::{Entry, HashEngine, KeyDirs, Value};
+use crate::Scanner;
+
+/// The versions of the keys written while a snapshot is alive.
+///
+/// Every write (a whole batch counts as one) bumps `seq`. Without any snapshot
+/// nothing is recorded, `KeyDirs` alone is the latest version. Otherwise the
+/// first write of a key records its previous state, along with the new one, and
+/// the history is dropped once no snapshot can see it anymore.
+#[derive(Default)]
+pub struct Versions {
+    // sequence of the last write
+    seq: u64,
+    // sequence of a snapshot -> handles on it
+    snapshots: BTreeMap<u64, usize>,
+    // key -> (since, state) ascending, `None` is a delete and the last one is the
+    // current state, the first one is valid since 0: any snapshot older than it
+    // would have recorded the write it comes from
+    history: HashMap<Vec<u8>, Vec<(u64, Option<Entry>)>>,
+}
+
+impl Versions {
+    /// Sequence of the next write.
+    pub fn next_seq(&mut self) -> u64 {
+        self.seq += 1;
+        self.seq
+    }
+
+    /// Record a write of `k` from `previous` to `current`, if a snapshot may need it.
+    pub fn record(
+        &mut self,
+        seq: u64,
+        k: &[u8],
+        previous: Option<&Entry>,
+        current: Option<&Entry>,
+    ) {
+        if self.snapshots.is_empty() {
+            return;
+        }
+
+        self.history
+            .entry(k.to_vec())
+            .or_insert_with(|| vec![(0, previous.cloned())])
+            .push((seq, current.cloned()));
+    }
+
+    /// The entry of `k` as seen at `seq`.
+    pub fn get(&self, key_dirs: &KeyDirs, k: &[u8], seq: u64) -> Option<Entry> {
+        match self.history.get(k) {
+            Some(versions) => versions
+                .iter()
+                .rev()
+                .find(|(since, _)| *since <= seq)
+                .and_then(|(_, entry)| entry.clone()),
+            None => key_dirs.get(k),
+        }
+    }
+
+    /// A shard of `KeyDirs` as seen at `seq`, only copied if it changed since.
+    pub fn view<'a>(
+        &self,
+        key_dirs: &'a KeyDirs,
+        seq: u64,
+        in_shard: impl Fn(&[u8]) -> bool,
+    ) -> Cow<'a, KeyDirs> {
+        let mut keys = self.history.keys().filter(|k| in_shard(k)).peekable();
+        if keys.peek().is_none() {
+            return Cow::Borrowed(key_dirs);
+        }
+
+        let mut view = key_dirs.clone();
+        for k in keys {
+            match self.get(key_dirs, k, seq) {
+                Some(entry) => view.insert(k.clone(), entry),
+                None => {
+                    view.remove(k);
+                }
+            }
+        }
+
+        Cow::Owned(view)
+    }
+
+    /// Every version still referenced, the current ones included.
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.history
+            .values()
+            .flat_map(|versions| versions.iter().filter_map(|(_, entry)| entry.as_ref()))
+    }
+
+    pub fn entries_mut(&mut self) -> impl Iterator<Item = &mut Option<Entry>> {
+        self.history
+            .values_mut()
+            .flat_map(|versions| versions.iter_mut().map(|(_, entry)| entry))
+    }
+
+    fn acquire(&mut self) -> u64 {
+        *self.snapshots.entry(self.seq).or_insert(0) += 1;
+        self.seq
+    }
+
+    fn release(&mut self, seq: u64) {
+        if let Some(handles) = self.snapshots.get_mut(&seq) {
+            *handles -= 1;
+            if *handles == 0 {
+                self.snapshots.remove(&seq);
+            }
+        }
+
+        // versions superseded before the oldest snapshot are out of sight
+        match self.snapshots.keys().next().cloned() {
+            Some(oldest) => self.history.retain(|_, versions| {
+                let visible = versions
+                    .iter()
+                    .rposition(|(since, _)| *since <= oldest)
+                    .unwrap_or(0);
+                versions.drain(..visible);
+
+                versions.len() > 1
+            }),
+            None => self.history.clear(),
+        }
+    }
+}
+
+/// A consistent point-in-time view of a `HashEngine`, see `HashEngine::snapshot`.
+///
+/// The versions it sees are kept, even across merges, until it's dropped.
+pub struct HashSnapshot {
+    engine: HashEngine,
+    seq: u64,
+}
+
+impl HashSnapshot {
+    pub(super) fn new(engine: HashEngine) -> Self {
+        let seq = engine.versions.lock().unwrap().acquire();
+
+        Self { engine, seq }
+    }
+
+    /// Sequence of the last write visible to this snapshot.
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    pub fn get(&self, k: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        Ok(self.engine.get_at(k, Some(self.seq))?.map(Value::into_vec))
+    }
+
+    pub fn scan(&self, scanner: Scanner) -> Result<(Scanner, Vec<(Vec<u8>, Vec<u8>)>), Error> {
+        let (scanner, kvs) = self.engine.scan_at(scanner, Some(self.seq))?;
+
+        Ok((
+            scanner,
+            kvs.into_iter().map(|(k, v)| (k, v.into_vec())).collect(),
+        ))
+    }
+}
+
+impl Drop for HashSnapshot {
+    fn drop(&mut self) {
+        self.engine.versions.lock().unwrap().release(self.seq);
+    }
+}
@@ -0,0 +1,162 @@
+// --- std ---
+use std::{
+    fs::File,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Condvar, Mutex, RwLock, Weak,
+    },
+    thread,
+};
+// --- external ---
+use failure::Error;
+// --- custom ---
+use super::{DHFile, Durability};
+
+#[derive(Default)]
+struct SyncState {
+    // sequence of the last write appended
+    written: u64,
+    // sequence of the last write known to be on the disk
+    synced: u64,
+    // a group commit leader is waiting for its window or syncing
+    syncing: bool,
+}
+
+/// Decides when the writes to the active file get synced, following `Durability`.
+///
+/// Writes are numbered as they're appended, a writer then waits in `sync` until its
+/// own write is on the disk, or not at all, depending on the mode.
+pub struct Syncer {
+    durability: Durability,
+
+    state: Mutex<SyncState>,
+    synced: Condvar,
+    syncs: AtomicU64,
+}
+
+impl Syncer {
+    pub fn new(durability: Durability) -> Self {
+        Self {
+            durability,
+            state: Mutex::new(SyncState::default()),
+            synced: Condvar::new(),
+            syncs: AtomicU64::new(0),
+        }
+    }
+
+    pub fn durability(&self) -> Durability {
+        self.durability
+    }
+
+    pub fn writes(&self) -> u64 {
+        self.state.lock().unwrap().written
+    }
+
+    pub fn syncs(&self) -> u64 {
+        self.syncs.load(Ordering::Relaxed)
+    }
+
+    /// Number a write, must be called while holding the active file's write lock.
+    pub fn written(&self) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        state.written += 1;
+        state.written
+    }
+
+    /// Sync a file leaving the active position, whatever the mode.
+    ///
+    /// Its hint file is written next and must never describe records that aren't on the
+    /// disk, it's trusted on startup. Must be called while holding the active file's write
+    /// lock, a leader syncing the next active file relies on everything before it being on
+    /// the disk already.
+    pub fn rotate(&self, dh_file: &DHFile) -> Result<(), Error> {
+        if let Some(ref data_file) = dh_file.data_file {
+            self.sync_file(data_file)?;
+        }
+
+        Ok(())
+    }
+
+    /// Wait until the write numbered `seq` is as durable as the mode promises.
+    pub fn sync(&self, active_file: &RwLock<DHFile>, seq: u64) -> Result<(), Error> {
+        match self.durability {
+            Durability::Sync => self.sync_active_file(active_file).map(|_| ()),
+            Durability::GroupCommit { window } => {
+                let mut state = self.state.lock().unwrap();
+                loop {
+                    if state.synced >= seq {
+                        return Ok(());
+                    }
+                    if !state.syncing {
+                        break;
+                    }
+                    state = self.synced.wait(state).unwrap();
+                }
+                // the first writer of a group leads it, the others wait for its fsync
+                state.syncing = true;
+                drop(state);
+
+                thread::sleep(window);
+                let result = self.sync_active_file(active_file);
+
+                let mut state = self.state.lock().unwrap();
+                state.syncing = false;
+                if let Ok(synced) = result {
+                    state.synced = state.synced.max(synced);
+                }
+                self.synced.notify_all();
+
+                result.map(|_| ())
+            }
+            // acknowledged before the fsync, a crash may lose the write, a batch or a commit
+            // whole, never half of it
+            Durability::Periodic { .. } | Durability::Buffered => Ok(()),
+        }
+    }
+
+    /// Sync the active file every `interval` until the engine is dropped.
+    pub fn spawn_periodic(syncer: &Arc<Syncer>, active_file: &Arc<RwLock<DHFile>>) {
+        if let Durability::Periodic { interval } = syncer.durability {
+            let syncer = Arc::downgrade(syncer);
+            let active_file: Weak<RwLock<DHFile>> = Arc::downgrade(active_file);
+
+            thread::spawn(move || loop {
+                thread::sleep(interval);
+
+                match (syncer.upgrade(), active_file.upgrade()) {
+                    (Some(syncer), Some(active_file)) => {
+                        if let Err(e) = syncer.sync_active_file(&active_file) {
+                            eprintln!("Failed to sync: {:?}", e);
+                        }
+                    }
+                    _ => break,
+                }
+            });
+        }
+    }
+
+    // sync the active file, returning the sequence of the last write it covers
+    fn sync_active_file(&self, active_file: &RwLock<DHFile>) -> Result<u64, Error> {
+        // writers number their writes under the write lock, nothing moves under the read lock
+        let (data_file, written) = {
+            let active_file = active_file.read().unwrap();
+            (
+                active_file.data_file.clone(),
+                self.state.lock().unwrap().written,
+            )
+        };
+        // a rotation in the meantime already synced the writes of this file
+        if let Some(data_file) = data_file {
+            self.sync_file(&data_file)?;
+        }
+
+        Ok(written)
+    }
+
+    fn sync_file(&self, data_file: &File) -> Result<(), Error> {
+        data_file.sync_data()?;
+        self.syncs.fetch_add(1, Ordering::Relaxed);
+
+        Ok(())
+    }
+}
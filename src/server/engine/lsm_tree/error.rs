@@ -0,0 +1,13 @@
+#[derive(Debug, Fail)]
+pub enum LSMTreeEngineError {
+    #[fail(display = "File: `{}`, corrupted", path)]
+    Corruption { path: String },
+    #[fail(display = "Manifest line: `{}`, invalid", line)]
+    InvalidManifest { line: String },
+    #[fail(display = "Unsupported scanner")]
+    UnsupportedScanner,
+    #[fail(display = "Cursor: `{:?}`, invalid", cursor)]
+    InvalidCursor { cursor: Vec<u8> },
+    #[fail(display = "TTL unsupported")]
+    UnsupportedTtl,
+}
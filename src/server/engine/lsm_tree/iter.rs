@@ -0,0 +1,88 @@
+// --- external ---
+use failure::Error;
+// --- custom ---
+use super::record::Value;
+
+pub type Source<'a> = Box<dyn Iterator<Item = Result<(Vec<u8>, Value), Error>> + 'a>;
+
+/// Merge sorted sources into one sorted stream.
+///
+/// Sources are ordered from newest to oldest, the newest record of a key
+/// shadows the others.
+pub struct MergeIter<'a> {
+    sources: Vec<Source<'a>>,
+    heads: Vec<Option<(Vec<u8>, Value)>>,
+    // sources sorted in descending key order, merged from the greatest key
+    reverse: bool,
+}
+
+impl<'a> MergeIter<'a> {
+    pub fn new(sources: Vec<Source<'a>>) -> Result<Self, Error> {
+        Self::with_order(sources, false)
+    }
+
+    /// Like `new`, for sources in descending key order.
+    pub fn rev(sources: Vec<Source<'a>>) -> Result<Self, Error> {
+        Self::with_order(sources, true)
+    }
+
+    fn with_order(mut sources: Vec<Source<'a>>, reverse: bool) -> Result<Self, Error> {
+        let mut heads = vec![];
+        for source in sources.iter_mut() {
+            heads.push(source.next().transpose()?);
+        }
+
+        Ok(Self {
+            sources,
+            heads,
+            reverse,
+        })
+    }
+
+    /// Whether `a` comes out before (or together with) `b`.
+    fn precedes(&self, a: &[u8], b: &[u8]) -> bool {
+        if self.reverse {
+            a >= b
+        } else {
+            a <= b
+        }
+    }
+
+    fn advance(&mut self, i: usize) -> Result<(), Error> {
+        self.heads[i] = self.sources[i].next().transpose()?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Iterator for MergeIter<'a> {
+    type Item = Result<(Vec<u8>, Value), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut newest: Option<usize> = None;
+        for (i, head) in self.heads.iter().enumerate() {
+            if let Some((k, _)) = head {
+                match newest {
+                    Some(j) if self.precedes(&self.heads[j].as_ref().unwrap().0, k) => (),
+                    _ => newest = Some(i),
+                }
+            }
+        }
+
+        let newest = newest?;
+        let record = self.heads[newest].take().unwrap();
+        for i in 0..self.heads.len() {
+            let shadowed = i == newest
+                || self.heads[i]
+                    .as_ref()
+                    .map_or(false, |(k, _)| *k == record.0);
+            if shadowed {
+                if let Err(e) = self.advance(i) {
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        Some(Ok(record))
+    }
+}
@@ -0,0 +1,72 @@
+// --- std ---
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+};
+// --- external ---
+use failure::Error;
+// --- custom ---
+use super::LSMTreeEngineError;
+
+const MANIFEST_FILE: &'static str = "MANIFEST";
+
+/// The tables of every level, one `level file_id` line per table.
+///
+/// It is replaced via rename, so a crash leaves either the old or the new one.
+pub fn load(storage_dir: &str) -> Result<Vec<Vec<u64>>, Error> {
+    let mut s = String::new();
+    match File::open(format!("{}/{}", storage_dir, MANIFEST_FILE)) {
+        Ok(mut file) => file.read_to_string(&mut s)?,
+        Err(e) => match e.kind() {
+            io::ErrorKind::NotFound => return Ok(vec![]),
+            _ => return Err(e.into()),
+        },
+    };
+
+    let mut levels: Vec<Vec<u64>> = vec![];
+    for line in s.lines().filter(|line| !line.is_empty()) {
+        let invalid = || LSMTreeEngineError::InvalidManifest {
+            line: line.to_string(),
+        };
+        let mut fields = line.split(' ');
+        let level: usize = fields
+            .next()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(invalid)?;
+        let file_id: u64 = fields
+            .next()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(invalid)?;
+
+        if levels.len() <= level {
+            levels.resize(level + 1, vec![]);
+        }
+        levels[level].push(file_id);
+    }
+
+    Ok(levels)
+}
+
+pub fn store(storage_dir: &str, levels: &[Vec<u64>]) -> Result<(), Error> {
+    // --- std ---
+    use std::fs::rename;
+
+    let mut s = String::new();
+    for (level, file_ids) in levels.iter().enumerate() {
+        for file_id in file_ids {
+            s.push_str(&format!("{} {}\n", level, file_id));
+        }
+    }
+
+    let path = format!("{}/{}", storage_dir, MANIFEST_FILE);
+    let tmp_path = format!("{}.tmp", path);
+    {
+        let mut file = File::create(&tmp_path)?;
+        file.write_all(s.as_bytes())?;
+        file.sync_all()?;
+    }
+    rename(&tmp_path, &path)?;
+    File::open(storage_dir)?.sync_all()?;
+
+    Ok(())
+}
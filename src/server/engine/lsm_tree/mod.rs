@@ -1 +1,210 @@
+mod error;
+mod iter;
+mod manifest;
+mod options;
+mod record;
+mod scanner;
+mod table;
+mod tree;
+mod wal;
 
+pub use error::LSMTreeEngineError;
+pub use options::Options;
+pub use scanner::LSMTreeScanner;
+pub use tree::LSMTree;
+
+// --- std ---
+use std::{
+    sync::{Arc, Mutex, RwLock},
+    time::Duration,
+};
+// --- external ---
+use failure::Error;
+use regex::bytes::Regex;
+// --- custom ---
+use crate::{MergeProgress, Order, Scanner, Stats, Transaction, WriteBatch};
+
+pub struct LSMTreeEngineBuilder {
+    options: Options,
+    storage_dir: String,
+}
+
+impl LSMTreeEngineBuilder {
+    pub fn new() -> Self {
+        Self {
+            options: Default::default(),
+            storage_dir: "kv-server-lsm-tree-engine-data".to_string(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn storage_dir(mut self, path: &str) -> Self {
+        self.storage_dir = path.to_string();
+        self
+    }
+
+    pub fn build(self) -> Result<LSMTreeEngine, Error> {
+        LSMTreeEngine::init(self)
+    }
+}
+
+#[derive(Clone)]
+pub struct LSMTreeEngine {
+    tree: Arc<RwLock<LSMTree>>,
+    // held by whoever is flushing or compacting the tree
+    compacting: Arc<Mutex<()>>,
+}
+
+impl LSMTreeEngine {
+    fn init(builder: LSMTreeEngineBuilder) -> Result<LSMTreeEngine, Error> {
+        // --- std ---
+        use std::fs::create_dir_all;
+
+        create_dir_all(&builder.storage_dir)?;
+
+        Ok(LSMTreeEngine {
+            tree: Arc::new(RwLock::new(LSMTree::open(
+                &builder.storage_dir,
+                builder.options,
+            )?)),
+            compacting: Arc::new(Mutex::new(())),
+        })
+    }
+
+    /// Flush a full memtable, unless another writer or a merge is already at it.
+    fn maintain(&self) -> Result<(), Error> {
+        if !self.tree.read().unwrap().is_full() {
+            return Ok(());
+        }
+
+        match self.compacting.try_lock() {
+            Ok(_compacting) => LSMTree::maintain(&self.tree),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+impl super::Engine for LSMTreeEngine {
+    fn put(&mut self, k: Vec<u8>, v: Vec<u8>) -> Result<(), Error> {
+        self.tree.write().unwrap().write(k, Some(v))?;
+        self.maintain()
+    }
+
+    fn put_with_ttl(&mut self, _: Vec<u8>, _: Vec<u8>, _: Duration) -> Result<(), Error> {
+        Err(LSMTreeEngineError::UnsupportedTtl.into())
+    }
+
+    fn get(&mut self, k: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        self.tree.read().unwrap().get(k)
+    }
+
+    fn del(&mut self, k: &[u8]) -> Result<(), Error> {
+        self.tree.write().unwrap().write(k.to_vec(), None)?;
+        self.maintain()
+    }
+
+    fn replace(
+        &mut self,
+        k: Vec<u8>,
+        v: Option<Vec<u8>>,
+        ttl: Option<Duration>,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        if ttl.is_some() {
+            return Err(LSMTreeEngineError::UnsupportedTtl.into());
+        }
+
+        let previous = {
+            let mut tree = self.tree.write().unwrap();
+            let previous = tree.get(&k)?;
+            tree.write(k, v)?;
+            previous
+        };
+        self.maintain()?;
+
+        Ok(previous)
+    }
+
+    fn write_batch(&mut self, batch: WriteBatch) -> Result<(), Error> {
+        self.tree.write().unwrap().write_batch(batch.ops)?;
+        self.maintain()
+    }
+
+    fn commit(&mut self, transaction: Transaction) -> Result<bool, Error> {
+        {
+            let mut tree = self.tree.write().unwrap();
+            for (k, v) in transaction.reads.iter() {
+                if tree.get(k)? != *v {
+                    return Ok(false);
+                }
+            }
+            if !transaction.writes.is_empty() {
+                tree.write_batch(transaction.writes.ops)?;
+            }
+        }
+        self.maintain()?;
+
+        Ok(true)
+    }
+
+    fn scanner(&self, cursor: Vec<u8>, range: i64, regex: Option<Regex>) -> Scanner {
+        Scanner::LSMTreeScanner(LSMTreeScanner {
+            cursor,
+            range,
+            regex,
+        })
+    }
+
+    fn scan(&mut self, mut scanner: Scanner) -> Result<(Scanner, Vec<(Vec<u8>, Vec<u8>)>), Error> {
+        let kvs = match scanner {
+            Scanner::LSMTreeScanner(ref mut scanner) => scanner.scan(&self.tree.read().unwrap())?,
+            Scanner::RangeScanner(ref mut scanner) => {
+                let tree = self.tree.read().unwrap();
+                let (start, end) = (
+                    scanner.start_key().map(|k| k.to_vec()),
+                    scanner.end_key().map(|k| k.to_vec()),
+                );
+                let mut kvs = vec![];
+
+                let records = match scanner.order {
+                    Order::Asc => tree.iter_from(start.as_ref().map(|k| k.as_slice()))?,
+                    Order::Desc => tree.iter_rev_to(end.as_ref().map(|k| k.as_slice()))?,
+                };
+                for record in records {
+                    if let (k, Some(v)) = record? {
+                        if !scanner.feed(&k, &v, &mut kvs) {
+                            break;
+                        }
+                    }
+                }
+
+                kvs
+            }
+            _ => return Err(LSMTreeEngineError::UnsupportedScanner.into()),
+        };
+
+        Ok((scanner, kvs))
+    }
+
+    fn merge_with_progress(
+        &mut self,
+        progress: &mut dyn FnMut(&MergeProgress) -> bool,
+    ) -> Result<bool, Error> {
+        let _compacting = self.compacting.lock().unwrap();
+        LSMTree::compact_all(&self.tree, progress)
+    }
+
+    fn stats(&self) -> Stats {
+        Stats {
+            engine: "lsm-tree".to_string(),
+            // every write is fsynced to the WAL
+            durability: "sync".to_string(),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,22 @@
+#[derive(Clone)]
+pub struct Options {
+    pub memtable_size: u64,
+    pub table_size: u64,
+    pub index_interval: usize,
+    pub level0_limit: usize,
+    pub level_size_base: u64,
+    pub level_size_multiplier: u64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            memtable_size: 4 * 0x100000,
+            table_size: 2 * 0x100000,
+            index_interval: 16,
+            level0_limit: 4,
+            level_size_base: 10 * 0x100000,
+            level_size_multiplier: 10,
+        }
+    }
+}
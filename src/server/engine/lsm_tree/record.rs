@@ -0,0 +1,68 @@
+// kind : key size : value size :  key :  value
+//   u8 :      u32 :        u32 :    ? :      ?
+pub const RECORD_HEADER_SIZE: usize = 9;
+
+const PUT: u8 = 0;
+const DEL: u8 = 1;
+
+/// `None` is a tombstone.
+pub type Value = Option<Vec<u8>>;
+
+pub fn encode(buffer: &mut Vec<u8>, k: &[u8], v: Option<&[u8]>) {
+    buffer.push(if v.is_some() { PUT } else { DEL });
+    buffer.extend_from_slice(&(k.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(&(v.map_or(0, |v| v.len()) as u32).to_le_bytes());
+    buffer.extend_from_slice(k);
+    if let Some(v) = v {
+        buffer.extend_from_slice(v);
+    }
+}
+
+/// Decode the record at the head of `bytes`, returning it with its encoded size.
+pub fn decode(bytes: &[u8]) -> Option<((Vec<u8>, Value), usize)> {
+    if bytes.len() < RECORD_HEADER_SIZE {
+        return None;
+    }
+
+    let key_size = read_u32(bytes, 1) as usize;
+    let value_size = read_u32(bytes, 5) as usize;
+    let size = RECORD_HEADER_SIZE + key_size + value_size;
+    if bytes.len() < size {
+        return None;
+    }
+
+    let k = bytes[RECORD_HEADER_SIZE..RECORD_HEADER_SIZE + key_size].to_vec();
+    let v = match bytes[0] {
+        PUT => Some(bytes[RECORD_HEADER_SIZE + key_size..size].to_vec()),
+        DEL => None,
+        _ => return None,
+    };
+
+    Some(((k, v), size))
+}
+
+pub fn decode_all(bytes: &[u8]) -> Option<Vec<(Vec<u8>, Value)>> {
+    let mut records = vec![];
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let (record, size) = decode(&bytes[offset..])?;
+        records.push(record);
+        offset += size;
+    }
+
+    Some(records)
+}
+
+pub fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    let mut buffer = [0; 4];
+    buffer.copy_from_slice(&bytes[offset..offset + 4]);
+
+    u32::from_le_bytes(buffer)
+}
+
+pub fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    let mut buffer = [0; 8];
+    buffer.copy_from_slice(&bytes[offset..offset + 8]);
+
+    u64::from_le_bytes(buffer)
+}
@@ -0,0 +1,56 @@
+// --- external ---
+use failure::Error;
+use regex::bytes::Regex;
+// --- custom ---
+use super::{tree::LSMTree, LSMTreeEngineError};
+use crate::server::scanner::{cursor_key, key_cursor};
+
+/// `cursor` is the last key visited, see `key_cursor`, empty means start over,
+/// and it's set back to empty once the scan is complete.
+pub struct LSMTreeScanner {
+    pub cursor: Vec<u8>,
+    pub range: i64,
+    pub regex: Option<Regex>,
+}
+
+impl LSMTreeScanner {
+    pub fn scan(&mut self, tree: &LSMTree) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Error> {
+        let mut matched_kvs = vec![];
+        let start = cursor_key(&self.cursor)
+            .map_err(|_| LSMTreeEngineError::InvalidCursor {
+                cursor: self.cursor.clone(),
+            })?
+            .map(|k| k.to_vec());
+        let mut last_key: Option<Vec<u8>> = None;
+
+        for record in tree.iter_from(start.as_ref().map(|k| k.as_slice()))? {
+            let (k, v) = record?;
+            if start.as_ref().map_or(false, |start| &k <= start) {
+                continue;
+            }
+            if let Some(v) = v {
+                if self.range == 0 {
+                    // nothing visited yet, resume from the same place
+                    if let Some(k) = last_key {
+                        self.cursor = key_cursor(&k);
+                    }
+                    return Ok(matched_kvs);
+                }
+                self.range -= 1;
+                last_key = Some(k.clone());
+
+                if let Some(ref regex) = self.regex {
+                    if regex.is_match(&k) {
+                        matched_kvs.push((k, v));
+                    }
+                } else {
+                    matched_kvs.push((k, v));
+                }
+            }
+        }
+
+        self.cursor.clear();
+
+        Ok(matched_kvs)
+    }
+}
@@ -0,0 +1,390 @@
+// --- std ---
+use std::{fs::File, io::Write, os::unix::fs::FileExt};
+// --- external ---
+use failure::Error;
+// --- custom ---
+use super::{
+    record::{self, read_u32, read_u64, Value},
+    LSMTreeEngineError,
+};
+
+const MAGIC: u32 = 0x4b56_4c53;
+// index offset : index crc : magic
+//          u64 :       u32 :   u32
+const FOOTER_SIZE: usize = 16;
+
+struct IndexEntry {
+    first_key: Vec<u8>,
+    offset: u64,
+    crc: u32,
+}
+
+/// Immutable sorted string table.
+///
+/// data blocks : index : footer
+///
+/// A data block holds up to `index_interval` records, the index holds the
+/// first key, offset and CRC of every block.
+pub struct Table {
+    pub file_id: u64,
+    pub size: u64,
+    pub min_key: Vec<u8>,
+    pub max_key: Vec<u8>,
+
+    path: String,
+    file: File,
+    index: Vec<IndexEntry>,
+    index_offset: u64,
+}
+
+impl Table {
+    pub fn path(storage_dir: &str, file_id: u64) -> String {
+        format!("{}/{}.sst", storage_dir, file_id)
+    }
+
+    pub fn open(storage_dir: &str, file_id: u64) -> Result<Self, Error> {
+        // --- external ---
+        use crc::crc32::checksum_ieee;
+
+        let path = Table::path(storage_dir, file_id);
+        let corruption = || LSMTreeEngineError::Corruption { path: path.clone() };
+
+        let file = File::open(&path)?;
+        let size = file.metadata()?.len();
+        if size < FOOTER_SIZE as u64 {
+            return Err(corruption().into());
+        }
+
+        let mut footer = [0; FOOTER_SIZE];
+        file.read_exact_at(&mut footer, size - FOOTER_SIZE as u64)?;
+        let index_offset = read_u64(&footer, 0);
+        if read_u32(&footer, 12) != MAGIC || index_offset > size - FOOTER_SIZE as u64 {
+            return Err(corruption().into());
+        }
+
+        let mut bytes = vec![0; (size - FOOTER_SIZE as u64 - index_offset) as usize];
+        file.read_exact_at(&mut bytes, index_offset)?;
+        if read_u32(&footer, 8) != checksum_ieee(&bytes) {
+            return Err(corruption().into());
+        }
+
+        let mut index = vec![];
+        let mut offset = 0;
+        while offset < bytes.len() {
+            if offset + 4 > bytes.len() {
+                return Err(corruption().into());
+            }
+            let key_size = read_u32(&bytes, offset) as usize;
+            offset += 4;
+            if offset + key_size + 12 > bytes.len() {
+                return Err(corruption().into());
+            }
+            index.push(IndexEntry {
+                first_key: bytes[offset..offset + key_size].to_vec(),
+                offset: read_u64(&bytes, offset + key_size),
+                crc: read_u32(&bytes, offset + key_size + 8),
+            });
+            offset += key_size + 12;
+        }
+
+        let mut table = Self {
+            file_id,
+            size,
+            min_key: index
+                .first()
+                .map(|e| e.first_key.clone())
+                .unwrap_or_default(),
+            max_key: vec![],
+            path,
+            file,
+            index,
+            index_offset,
+        };
+        if !table.index.is_empty() {
+            let block = table.read_block(table.index.len() - 1)?;
+            table.max_key = block.last().map(|(k, _)| k.clone()).unwrap_or_default();
+        }
+
+        Ok(table)
+    }
+
+    pub fn contains(&self, k: &[u8]) -> bool {
+        !self.index.is_empty() && self.min_key.as_slice() <= k && k <= self.max_key.as_slice()
+    }
+
+    /// `None` if the table doesn't hold `k`, `Some(None)` for a tombstone.
+    pub fn get(&self, k: &[u8]) -> Result<Option<Value>, Error> {
+        if !self.contains(k) {
+            return Ok(None);
+        }
+
+        let i = match self
+            .index
+            .binary_search_by(|entry| entry.first_key.as_slice().cmp(k))
+        {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+
+        Ok(self
+            .read_block(i)?
+            .into_iter()
+            .find(|(key, _)| key.as_slice() == k)
+            .map(|(_, v)| v))
+    }
+
+    pub fn iter(&self) -> TableIter {
+        self.iter_from(None)
+    }
+
+    /// Iterate from the first record not less than `start`.
+    pub fn iter_from(&self, start: Option<&[u8]>) -> TableIter {
+        let block = match start {
+            Some(k) => match self
+                .index
+                .binary_search_by(|entry| entry.first_key.as_slice().cmp(k))
+            {
+                Ok(i) => i,
+                Err(i) => i.max(1) - 1,
+            },
+            None => 0,
+        };
+
+        TableIter {
+            table: self,
+            block,
+            start: start.map(|k| k.to_vec()),
+            records: vec![].into_iter(),
+        }
+    }
+
+    /// Iterate backwards from the last record not greater than `end`.
+    pub fn iter_rev_to(&self, end: Option<&[u8]>) -> TableRevIter {
+        let block = match end {
+            Some(k) => match self
+                .index
+                .binary_search_by(|entry| entry.first_key.as_slice().cmp(k))
+            {
+                Ok(i) => i + 1,
+                Err(i) => i,
+            },
+            None => self.index.len(),
+        };
+
+        TableRevIter {
+            table: self,
+            block,
+            end: end.map(|k| k.to_vec()),
+            records: vec![].into_iter().rev(),
+        }
+    }
+
+    /// Unlink the file, the readers still holding the table go on reading it.
+    pub fn remove(&self) -> Result<(), Error> {
+        // --- std ---
+        use std::fs::remove_file;
+
+        remove_file(&self.path)?;
+
+        Ok(())
+    }
+
+    fn read_block(&self, i: usize) -> Result<Vec<(Vec<u8>, Value)>, Error> {
+        // --- external ---
+        use crc::crc32::checksum_ieee;
+
+        let start = self.index[i].offset;
+        let end = self
+            .index
+            .get(i + 1)
+            .map_or(self.index_offset, |entry| entry.offset);
+        let mut bytes = vec![0; (end - start) as usize];
+        self.file.read_exact_at(&mut bytes, start)?;
+
+        if checksum_ieee(&bytes) != self.index[i].crc {
+            return Err(LSMTreeEngineError::Corruption {
+                path: self.path.clone(),
+            }
+            .into());
+        }
+
+        record::decode_all(&bytes).ok_or_else(|| {
+            LSMTreeEngineError::Corruption {
+                path: self.path.clone(),
+            }
+            .into()
+        })
+    }
+}
+
+pub struct TableIter<'a> {
+    table: &'a Table,
+    block: usize,
+    start: Option<Vec<u8>>,
+    records: std::vec::IntoIter<(Vec<u8>, Value)>,
+}
+
+impl<'a> Iterator for TableIter<'a> {
+    type Item = Result<(Vec<u8>, Value), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.records.next() {
+                if self.start.as_ref().map_or(true, |start| record.0 >= *start) {
+                    self.start = None;
+
+                    return Some(Ok(record));
+                }
+
+                continue;
+            }
+
+            if self.block == self.table.index.len() {
+                return None;
+            }
+
+            match self.table.read_block(self.block) {
+                Ok(records) => self.records = records.into_iter(),
+                Err(e) => {
+                    self.block = self.table.index.len();
+                    return Some(Err(e));
+                }
+            }
+            self.block += 1;
+        }
+    }
+}
+
+/// Like `TableIter`, reading the blocks from the last one.
+pub struct TableRevIter<'a> {
+    table: &'a Table,
+    // one past the next block to read
+    block: usize,
+    end: Option<Vec<u8>>,
+    records: std::iter::Rev<std::vec::IntoIter<(Vec<u8>, Value)>>,
+}
+
+impl<'a> Iterator for TableRevIter<'a> {
+    type Item = Result<(Vec<u8>, Value), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.records.next() {
+                if self.end.as_ref().map_or(true, |end| record.0 <= *end) {
+                    self.end = None;
+
+                    return Some(Ok(record));
+                }
+
+                continue;
+            }
+
+            if self.block == 0 {
+                return None;
+            }
+
+            self.block -= 1;
+            match self.table.read_block(self.block) {
+                Ok(records) => self.records = records.into_iter().rev(),
+                Err(e) => {
+                    self.block = 0;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Write a table from records in key order.
+pub struct TableBuilder {
+    file_id: u64,
+    storage_dir: String,
+    index_interval: usize,
+
+    file: File,
+    size: u64,
+    index: Vec<IndexEntry>,
+    block: Vec<u8>,
+    block_first_key: Vec<u8>,
+    block_records: usize,
+}
+
+impl TableBuilder {
+    pub fn create(storage_dir: &str, file_id: u64, index_interval: usize) -> Result<Self, Error> {
+        Ok(Self {
+            file_id,
+            storage_dir: storage_dir.to_string(),
+            index_interval,
+            file: File::create(Table::path(storage_dir, file_id))?,
+            size: 0,
+            index: vec![],
+            block: vec![],
+            block_first_key: vec![],
+            block_records: 0,
+        })
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size + self.block.len() as u64
+    }
+
+    pub fn add(&mut self, k: &[u8], v: Option<&[u8]>) -> Result<(), Error> {
+        if self.block_records == 0 {
+            self.block_first_key = k.to_vec();
+        }
+
+        record::encode(&mut self.block, k, v);
+        self.block_records += 1;
+
+        if self.block_records == self.index_interval {
+            self.flush_block()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<Table, Error> {
+        // --- external ---
+        use crc::crc32::checksum_ieee;
+
+        self.flush_block()?;
+
+        let mut buffer = vec![];
+        for entry in &self.index {
+            buffer.extend_from_slice(&(entry.first_key.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(&entry.first_key);
+            buffer.extend_from_slice(&entry.offset.to_le_bytes());
+            buffer.extend_from_slice(&entry.crc.to_le_bytes());
+        }
+        let crc = checksum_ieee(&buffer);
+        buffer.extend_from_slice(&self.size.to_le_bytes());
+        buffer.extend_from_slice(&crc.to_le_bytes());
+        buffer.extend_from_slice(&MAGIC.to_le_bytes());
+
+        self.file.write_all(&buffer)?;
+        self.file.sync_all()?;
+
+        Table::open(&self.storage_dir, self.file_id)
+    }
+
+    fn flush_block(&mut self) -> Result<(), Error> {
+        // --- external ---
+        use crc::crc32::checksum_ieee;
+
+        if self.block_records == 0 {
+            return Ok(());
+        }
+
+        self.file.write_all(&self.block)?;
+        self.index.push(IndexEntry {
+            first_key: self.block_first_key.clone(),
+            offset: self.size,
+            crc: checksum_ieee(&self.block),
+        });
+        self.size += self.block.len() as u64;
+        self.block.clear();
+        self.block_records = 0;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,604 @@
+// --- std ---
+use std::{
+    collections::{BTreeMap, HashSet},
+    mem::replace,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+};
+// --- external ---
+use failure::Error;
+// --- custom ---
+use super::{
+    iter::{MergeIter, Source},
+    manifest,
+    record::Value,
+    table::{Table, TableBuilder},
+    wal::Wal,
+    Options,
+};
+use crate::MergeProgress;
+
+type Memtable = BTreeMap<Vec<u8>, Value>;
+
+/// Memtable + write-ahead log in front of leveled SSTables.
+///
+/// Level 0 holds flushed memtables which may overlap, ordered from oldest to
+/// newest. Every deeper level is a sorted run of disjoint tables, ordered by key.
+///
+/// Flushes and compactions only lock the tree to pick their inputs and to swap
+/// their outputs in, the tables are written meanwhile, see `maintain`.
+pub struct LSMTree {
+    options: Options,
+    storage_dir: String,
+    next_file_id: Arc<AtomicU64>,
+
+    memtable: Memtable,
+    memtable_size: u64,
+    wal: Wal,
+    // a full memtable being flushed, read right after `memtable`, and the id of its log
+    immutable: Option<(Arc<Memtable>, u64)>,
+
+    levels: Vec<Vec<Arc<Table>>>,
+}
+
+/// A frozen memtable, written to a level 0 table without holding the tree.
+pub struct Flush {
+    memtable: Arc<Memtable>,
+    storage_dir: String,
+    index_interval: usize,
+    file_id: u64,
+}
+
+impl Flush {
+    pub fn write(&self) -> Result<Table, Error> {
+        let mut builder =
+            TableBuilder::create(&self.storage_dir, self.file_id, self.index_interval)?;
+        for (k, v) in self.memtable.iter() {
+            builder.add(k, v.as_ref().map(|v| v.as_slice()))?;
+        }
+
+        builder.finish()
+    }
+}
+
+/// The tables a compaction rewrites, picked under the lock and written without it.
+pub struct Compaction {
+    options: Options,
+    storage_dir: String,
+    next_file_id: Arc<AtomicU64>,
+
+    // from the newest to the oldest, each a sorted run of disjoint tables
+    sources: Vec<Vec<Arc<Table>>>,
+    level: usize,
+    drop_tombstones: bool,
+}
+
+impl Compaction {
+    pub fn write(&self) -> Result<Vec<Table>, Error> {
+        let sources = self
+            .sources
+            .iter()
+            .map(|tables| -> Source { Box::new(tables.iter().flat_map(|table| table.iter())) })
+            .collect();
+
+        LSMTree::write_tables(
+            &self.storage_dir,
+            &self.options,
+            &self.next_file_id,
+            MergeIter::new(sources)?,
+            self.drop_tombstones,
+        )
+    }
+
+    /// Remove the files of the inputs once the outputs replaced them, a reader still
+    /// holding one of them goes on with its open file.
+    pub fn remove_inputs(&self) -> Result<(), Error> {
+        for table in self.sources.iter().flatten() {
+            table.remove()?;
+        }
+
+        Ok(())
+    }
+
+    fn input_ids(&self) -> HashSet<u64> {
+        self.sources
+            .iter()
+            .flatten()
+            .map(|table| table.file_id)
+            .collect()
+    }
+}
+
+impl LSMTree {
+    pub fn open(storage_dir: &str, options: Options) -> Result<Self, Error> {
+        // --- std ---
+        use std::fs::{read_dir, remove_file};
+
+        let mut levels = vec![];
+        let mut max_file_id = 0;
+        for file_ids in manifest::load(storage_dir)? {
+            let mut tables = vec![];
+            for file_id in file_ids {
+                max_file_id = max_file_id.max(file_id);
+                tables.push(Arc::new(Table::open(storage_dir, file_id)?));
+            }
+            levels.push(tables);
+        }
+
+        let mut wal_file_ids = vec![];
+        for entry in read_dir(storage_dir)? {
+            let path = entry?.path();
+            let file_id: u64 = match path.file_stem().and_then(|s| s.to_str()) {
+                Some(stem) => match stem.parse() {
+                    Ok(file_id) => file_id,
+                    Err(_) => continue,
+                },
+                None => continue,
+            };
+
+            match path.extension().and_then(|s| s.to_str()) {
+                Some("wal") => wal_file_ids.push(file_id),
+                // written by an interrupted flush or compaction
+                Some("sst") if !levels.iter().flatten().any(|t| t.file_id == file_id) => {
+                    remove_file(&path)?;
+                    continue;
+                }
+                _ => (),
+            }
+            max_file_id = max_file_id.max(file_id);
+        }
+        wal_file_ids.sort();
+
+        let mut tree = LSMTree {
+            options,
+            storage_dir: storage_dir.to_string(),
+            next_file_id: Arc::new(AtomicU64::new(max_file_id + 2)),
+            memtable: BTreeMap::new(),
+            memtable_size: 0,
+            wal: Wal::create(storage_dir, max_file_id + 1)?,
+            immutable: None,
+            levels,
+        };
+
+        for &file_id in wal_file_ids.iter() {
+            for (k, v) in Wal::replay(&Wal::path(storage_dir, file_id))? {
+                tree.insert(k, v);
+            }
+        }
+        if let Some(flush) = tree.freeze(true)? {
+            tree.install(flush.write()?)?;
+        }
+        for file_id in wal_file_ids {
+            remove_file(Wal::path(storage_dir, file_id))?;
+        }
+
+        Ok(tree)
+    }
+
+    /// Flush a full memtable and compact the levels over their limits, one job at a time.
+    ///
+    /// `tree` is only locked to pick a job and to swap its outputs in, reads and writes
+    /// go on while the tables are written. The caller makes sure it doesn't run twice at
+    /// the same time.
+    pub fn maintain(tree: &RwLock<LSMTree>) -> Result<(), Error> {
+        let flush = tree.write().unwrap().freeze(false)?;
+        if let Some(flush) = flush {
+            let table = flush.write()?;
+            tree.write().unwrap().install(table)?;
+        }
+
+        loop {
+            let compaction = tree.write().unwrap().pick_compaction();
+            let compaction = match compaction {
+                Some(compaction) => compaction,
+                None => return Ok(()),
+            };
+
+            let tables = compaction.write()?;
+            tree.write().unwrap().apply(&compaction, tables)?;
+            compaction.remove_inputs()?;
+        }
+    }
+
+    /// Flush the memtable and rewrite every table into the last level, unless `progress`
+    /// cancels it before the new tables replace the old ones.
+    ///
+    /// Locks `tree` like `maintain`, the writes made meanwhile stay in the memtable.
+    pub fn compact_all(
+        tree: &RwLock<LSMTree>,
+        progress: &mut dyn FnMut(&MergeProgress) -> bool,
+    ) -> Result<bool, Error> {
+        let flush = tree.write().unwrap().freeze(true)?;
+        if let Some(flush) = flush {
+            let table = flush.write()?;
+            tree.write().unwrap().install(table)?;
+        }
+
+        let compaction = tree.write().unwrap().pick_all();
+        let tables = compaction.write()?;
+
+        let inputs = compaction.sources.iter().flatten();
+        let input_count = inputs.clone().count() as u64;
+        let input_bytes = inputs.map(|table| table.size).sum::<u64>();
+        let bytes_rewritten = tables.iter().map(|table| table.size).sum::<u64>();
+        if !progress(&MergeProgress {
+            files_merged: input_count,
+            files_total: input_count,
+            bytes_rewritten,
+            bytes_reclaimed: input_bytes.saturating_sub(bytes_rewritten),
+            eta: None,
+        }) {
+            for table in tables {
+                table.remove()?;
+            }
+            return Ok(false);
+        }
+
+        tree.write().unwrap().apply(&compaction, tables)?;
+        compaction.remove_inputs()?;
+
+        Ok(true)
+    }
+
+    /// Whether the memtable is due for a flush by `maintain`.
+    pub fn is_full(&self) -> bool {
+        self.memtable_size >= self.options.memtable_size
+    }
+
+    pub fn get(&self, k: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        if let Some(v) = self.memtable.get(k) {
+            return Ok(v.clone());
+        }
+        if let Some((memtable, _)) = &self.immutable {
+            if let Some(v) = memtable.get(k) {
+                return Ok(v.clone());
+            }
+        }
+
+        if let Some(level) = self.levels.first() {
+            for table in level.iter().rev() {
+                if let Some(v) = table.get(k)? {
+                    return Ok(v);
+                }
+            }
+        }
+
+        for level in self.levels.iter().skip(1) {
+            let i = match level.binary_search_by(|table| table.min_key.as_slice().cmp(k)) {
+                Ok(i) => i,
+                Err(0) => continue,
+                Err(i) => i - 1,
+            };
+
+            if let Some(v) = level[i].get(k)? {
+                return Ok(v);
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn write(&mut self, k: Vec<u8>, v: Value) -> Result<(), Error> {
+        self.wal.append(&k, v.as_ref().map(|v| v.as_slice()))?;
+        self.insert(k, v);
+
+        Ok(())
+    }
+
+    /// Log every record in one frame first, so a crash never leaves half of them.
+    pub fn write_batch(&mut self, records: Vec<(Vec<u8>, Value)>) -> Result<(), Error> {
+        self.wal.append_batch(
+            &records
+                .iter()
+                .map(|(k, v)| (k.as_slice(), v.as_ref().map(|v| v.as_slice())))
+                .collect::<Vec<_>>(),
+        )?;
+        for (k, v) in records {
+            self.insert(k, v);
+        }
+
+        Ok(())
+    }
+
+    /// Every live and deleted record, in key order.
+    pub fn iter(&self) -> Result<MergeIter, Error> {
+        self.iter_from(None)
+    }
+
+    /// Like `iter`, starting from the first record not less than `start`.
+    pub fn iter_from<'a>(&'a self, start: Option<&'a [u8]>) -> Result<MergeIter<'a>, Error> {
+        MergeIter::new(Self::sources(
+            &self.memtable,
+            self.immutable.as_ref().map(|(memtable, _)| &**memtable),
+            &self.levels,
+            start,
+        ))
+    }
+
+    /// Every live and deleted record in descending key order, starting from the last
+    /// record not greater than `end`.
+    pub fn iter_rev_to<'a>(&'a self, end: Option<&'a [u8]>) -> Result<MergeIter<'a>, Error> {
+        // --- std ---
+        use std::ops::Bound;
+
+        let mut sources: Vec<Source> = vec![];
+        let memtables = Some(&self.memtable)
+            .into_iter()
+            .chain(self.immutable.as_ref().map(|(memtable, _)| &**memtable));
+        for memtable in memtables {
+            sources.push(Box::new(
+                memtable
+                    .range::<[u8], _>((
+                        Bound::Unbounded,
+                        end.map_or(Bound::Unbounded, Bound::Included),
+                    ))
+                    .rev()
+                    .map(|(k, v)| Ok((k.clone(), v.clone()))),
+            ));
+        }
+        for (i, level) in self.levels.iter().enumerate() {
+            if i == 0 {
+                for table in level.iter().rev() {
+                    sources.push(Box::new(table.iter_rev_to(end)));
+                }
+            } else {
+                sources.push(Box::new(
+                    level
+                        .iter()
+                        .rev()
+                        .filter(move |table| end.map_or(true, |k| table.min_key.as_slice() <= k))
+                        .flat_map(move |table| table.iter_rev_to(end)),
+                ));
+            }
+        }
+
+        MergeIter::rev(sources)
+    }
+
+    fn insert(&mut self, k: Vec<u8>, v: Value) {
+        self.memtable_size += (k.len() + v.as_ref().map_or(0, |v| v.len())) as u64;
+        self.memtable.insert(k, v);
+    }
+
+    fn sources<'a>(
+        memtable: &'a Memtable,
+        immutable: Option<&'a Memtable>,
+        levels: &'a [Vec<Arc<Table>>],
+        start: Option<&'a [u8]>,
+    ) -> Vec<Source<'a>> {
+        // --- std ---
+        use std::ops::Bound;
+
+        let mut sources: Vec<Source> = vec![];
+        for memtable in Some(memtable).into_iter().chain(immutable) {
+            sources.push(Box::new(
+                memtable
+                    .range::<[u8], _>((
+                        start.map_or(Bound::Unbounded, Bound::Included),
+                        Bound::Unbounded,
+                    ))
+                    .map(|(k, v)| Ok((k.clone(), v.clone()))),
+            ));
+        }
+        for (i, level) in levels.iter().enumerate() {
+            if i == 0 {
+                for table in level.iter().rev() {
+                    sources.push(Box::new(table.iter_from(start)));
+                }
+            } else {
+                sources.push(Box::new(
+                    level
+                        .iter()
+                        .filter(move |table| start.map_or(true, |k| table.max_key.as_slice() >= k))
+                        .flat_map(move |table| table.iter_from(start)),
+                ));
+            }
+        }
+
+        sources
+    }
+
+    fn store_manifest(&self) -> Result<(), Error> {
+        manifest::store(
+            &self.storage_dir,
+            &self
+                .levels
+                .iter()
+                .map(|level| level.iter().map(|table| table.file_id).collect())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn next_file_id(&self) -> u64 {
+        self.next_file_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Move the memtable aside for a flush and log the next writes to a new WAL, once it's
+    /// full or whenever `force`d. A memtable whose flush failed is handed out again first.
+    fn freeze(&mut self, force: bool) -> Result<Option<Flush>, Error> {
+        if self.immutable.is_none() {
+            if self.memtable.is_empty() || !(force || self.is_full()) {
+                return Ok(None);
+            }
+
+            let wal = Wal::create(&self.storage_dir, self.next_file_id())?;
+            let wal = replace(&mut self.wal, wal);
+            self.immutable = Some((
+                Arc::new(replace(&mut self.memtable, BTreeMap::new())),
+                wal.file_id,
+            ));
+            self.memtable_size = 0;
+        }
+
+        Ok(self.immutable.as_ref().map(|(memtable, _)| Flush {
+            memtable: memtable.clone(),
+            storage_dir: self.storage_dir.clone(),
+            index_interval: self.options.index_interval,
+            file_id: self.next_file_id(),
+        }))
+    }
+
+    /// Add the table flushed from the frozen memtable to level 0 and drop its WAL.
+    fn install(&mut self, table: Table) -> Result<(), Error> {
+        // --- std ---
+        use std::fs::remove_file;
+
+        if self.levels.is_empty() {
+            self.levels.push(vec![]);
+        }
+        self.levels[0].push(Arc::new(table));
+        self.store_manifest()?;
+
+        if let Some((_, wal_file_id)) = self.immutable.take() {
+            remove_file(Wal::path(&self.storage_dir, wal_file_id))?;
+        }
+
+        Ok(())
+    }
+
+    fn level_size_limit(&self, level: usize) -> u64 {
+        self.options.level_size_base * self.options.level_size_multiplier.pow(level as u32 - 1)
+    }
+
+    /// The next level over its limit, merged into the tables of the level below it
+    /// overlaps with.
+    fn pick_compaction(&mut self) -> Option<Compaction> {
+        let level =
+            if self.levels.first().map_or(0, |level| level.len()) > self.options.level0_limit {
+                0
+            } else {
+                (1..self.levels.len()).find(|&level| {
+                    self.levels[level]
+                        .iter()
+                        .map(|table| table.size)
+                        .sum::<u64>()
+                        > self.level_size_limit(level)
+                })?
+            };
+        if self.levels.len() <= level + 1 {
+            self.levels.push(vec![]);
+        }
+
+        let min_key = self.levels[level]
+            .iter()
+            .map(|table| &table.min_key)
+            .min()
+            .cloned()
+            .unwrap_or_default();
+        let max_key = self.levels[level]
+            .iter()
+            .map(|table| &table.max_key)
+            .max()
+            .cloned()
+            .unwrap_or_default();
+
+        let mut sources = vec![];
+        if level == 0 {
+            for table in self.levels[0].iter().rev() {
+                sources.push(vec![table.clone()]);
+            }
+        } else {
+            sources.push(self.levels[level].clone());
+        }
+        sources.push(
+            self.levels[level + 1]
+                .iter()
+                .filter(|table| table.max_key >= min_key && table.min_key <= max_key)
+                .cloned()
+                .collect(),
+        );
+
+        Some(Compaction {
+            options: self.options.clone(),
+            storage_dir: self.storage_dir.clone(),
+            next_file_id: self.next_file_id.clone(),
+            sources,
+            level: level + 1,
+            // nothing older can be shadowed by a tombstone written into the last level
+            drop_tombstones: self.levels[level + 2..]
+                .iter()
+                .all(|level| level.is_empty()),
+        })
+    }
+
+    /// Every table, rewritten into the last level.
+    fn pick_all(&mut self) -> Compaction {
+        let bottom = self.levels.len().max(2) - 1;
+        if self.levels.len() <= bottom {
+            self.levels.resize_with(bottom + 1, Vec::new);
+        }
+
+        let mut sources = vec![];
+        for (i, level) in self.levels.iter().enumerate() {
+            if i == 0 {
+                for table in level.iter().rev() {
+                    sources.push(vec![table.clone()]);
+                }
+            } else {
+                sources.push(level.clone());
+            }
+        }
+
+        Compaction {
+            options: self.options.clone(),
+            storage_dir: self.storage_dir.clone(),
+            next_file_id: self.next_file_id.clone(),
+            sources,
+            level: bottom,
+            drop_tombstones: true,
+        }
+    }
+
+    /// Swap the inputs of `compaction` for the `tables` it wrote.
+    fn apply(&mut self, compaction: &Compaction, tables: Vec<Table>) -> Result<(), Error> {
+        let inputs = compaction.input_ids();
+        for level in self.levels.iter_mut() {
+            level.retain(|table| !inputs.contains(&table.file_id));
+        }
+
+        let level = &mut self.levels[compaction.level];
+        level.extend(tables.into_iter().map(Arc::new));
+        level.sort_by(|a, b| a.min_key.cmp(&b.min_key));
+        self.store_manifest()
+    }
+
+    fn write_tables(
+        storage_dir: &str,
+        options: &Options,
+        next_file_id: &AtomicU64,
+        iter: MergeIter,
+        drop_tombstones: bool,
+    ) -> Result<Vec<Table>, Error> {
+        let mut tables = vec![];
+        let mut builder: Option<TableBuilder> = None;
+
+        for record in iter {
+            let (k, v) = record?;
+            if v.is_none() && drop_tombstones {
+                continue;
+            }
+
+            if builder.is_none() {
+                builder = Some(TableBuilder::create(
+                    storage_dir,
+                    next_file_id.fetch_add(1, Ordering::SeqCst),
+                    options.index_interval,
+                )?);
+            }
+
+            let b = builder.as_mut().unwrap();
+            b.add(&k, v.as_ref().map(|v| v.as_slice()))?;
+            if b.size() >= options.table_size {
+                tables.push(builder.take().unwrap().finish()?);
+            }
+        }
+
+        if let Some(builder) = builder {
+            tables.push(builder.finish()?);
+        }
+
+        Ok(tables)
+    }
+}
@@ -0,0 +1,101 @@
+// --- std ---
+use std::{
+    fs::File,
+    io::{Read, Write},
+};
+// --- external ---
+use failure::Error;
+// --- custom ---
+use super::record::{self, read_u32, Value};
+
+// crc : frame size : records
+// u32 :        u32 :       ?
+// a frame holds a whole batch, it's replayed entirely or not at all
+const FRAME_HEADER_SIZE: usize = 8;
+
+/// Write-ahead log of the records in the memtable.
+pub struct Wal {
+    pub file_id: u64,
+    file: File,
+}
+
+impl Wal {
+    pub fn create(storage_dir: &str, file_id: u64) -> Result<Self, Error> {
+        // --- std ---
+        use std::fs::OpenOptions;
+
+        Ok(Self {
+            file_id,
+            file: OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(Self::path(storage_dir, file_id))?,
+        })
+    }
+
+    pub fn path(storage_dir: &str, file_id: u64) -> String {
+        format!("{}/{}.wal", storage_dir, file_id)
+    }
+
+    /// Read back every intact record, a torn tail left by a crash is cut off.
+    pub fn replay(path: &str) -> Result<Vec<(Vec<u8>, Value)>, Error> {
+        // --- external ---
+        use crc::crc32::checksum_ieee;
+
+        let mut bytes = vec![];
+        File::open(path)?.read_to_end(&mut bytes)?;
+
+        let mut records = vec![];
+        let mut offset = 0;
+        while offset + FRAME_HEADER_SIZE <= bytes.len() {
+            let crc = read_u32(&bytes, offset);
+            let end = offset + FRAME_HEADER_SIZE + read_u32(&bytes, offset + 4) as usize;
+            if end > bytes.len() || crc != checksum_ieee(&bytes[offset + 4..end]) {
+                break;
+            }
+            match record::decode_all(&bytes[offset + FRAME_HEADER_SIZE..end]) {
+                Some(frame) => {
+                    records.extend(frame);
+                    offset = end;
+                }
+                None => break,
+            }
+        }
+
+        if offset < bytes.len() {
+            // --- std ---
+            use std::fs::OpenOptions;
+
+            OpenOptions::new()
+                .write(true)
+                .open(path)?
+                .set_len(offset as u64)?;
+        }
+
+        Ok(records)
+    }
+
+    pub fn append(&mut self, k: &[u8], v: Option<&[u8]>) -> Result<(), Error> {
+        self.append_batch(&[(k, v)])
+    }
+
+    /// Append the records as a single frame, with a single fsync.
+    pub fn append_batch(&mut self, records: &[(&[u8], Option<&[u8]>)]) -> Result<(), Error> {
+        // --- external ---
+        use crc::crc32::checksum_ieee;
+
+        let mut buffer = vec![0; FRAME_HEADER_SIZE];
+        for &(k, v) in records {
+            record::encode(&mut buffer, k, v);
+        }
+        let frame_size = (buffer.len() - FRAME_HEADER_SIZE) as u32;
+        buffer[4..FRAME_HEADER_SIZE].copy_from_slice(&frame_size.to_le_bytes());
+        let crc = checksum_ieee(&buffer[4..]);
+        buffer[..4].copy_from_slice(&crc.to_le_bytes());
+
+        self.file.write_all(&buffer)?;
+        self.file.sync_data()?;
+
+        Ok(())
+    }
+}
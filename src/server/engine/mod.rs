@@ -1,18 +1,74 @@
-// TODO
 pub mod btree;
-// TODO
 pub mod hash;
 pub mod lsm_tree;
+mod value;
 
+pub use value::Value;
+
+// --- std ---
+use std::time::Duration;
 // --- external ---
 use failure::Error;
+use regex::bytes::Regex;
 // --- custom ---
-use crate::Scanner;
+use crate::{MergeProgress, Scanner, Stats, Transaction, WriteBatch};
 
 pub trait Engine {
     fn put(&mut self, k: Vec<u8>, v: Vec<u8>) -> Result<(), Error>;
+    /// `put`, but `k` is gone once `ttl` elapsed.
+    fn put_with_ttl(&mut self, k: Vec<u8>, v: Vec<u8>, ttl: Duration) -> Result<(), Error>;
     fn get(&mut self, k: &[u8]) -> Result<Option<Vec<u8>>, Error>;
+    /// `get`, but the value may be served from where it's stored, without a copy.
+    fn get_value(&mut self, k: &[u8]) -> Result<Option<Value>, Error> {
+        Ok(self.get(k)?.map(Value::Owned))
+    }
     fn del(&mut self, k: &[u8]) -> Result<(), Error>;
+    /// `put`, `put_with_ttl` with a `ttl`, or `del` with `None`, returning the value `k` had
+    /// right before, no other write of `k` can come in between.
+    fn replace(
+        &mut self,
+        k: Vec<u8>,
+        v: Option<Vec<u8>>,
+        ttl: Option<Duration>,
+    ) -> Result<Option<Vec<u8>>, Error>;
+    fn write_batch(&mut self, batch: WriteBatch) -> Result<(), Error>;
+    /// Apply the writes of `transaction` if none of the values it read changed, `false` otherwise.
+    fn commit(&mut self, transaction: Transaction) -> Result<bool, Error>;
+    /// Set `k` to `v`, or delete it with `None`, only if its value is `expected` (`None` if absent).
+    fn compare_and_swap(
+        &mut self,
+        k: Vec<u8>,
+        expected: Option<Vec<u8>>,
+        v: Option<Vec<u8>>,
+    ) -> Result<bool, Error> {
+        let mut transaction = Transaction::new();
+        transaction.read(k.clone(), expected);
+        match v {
+            Some(v) => transaction.put(k, v),
+            None => transaction.del(k),
+        }
+
+        self.commit(transaction)
+    }
+    fn scanner(&self, cursor: Vec<u8>, range: i64, regex: Option<Regex>) -> Scanner;
     fn scan(&mut self, scanner: Scanner) -> Result<(Scanner, Vec<(Vec<u8>, Vec<u8>)>), Error>;
-    fn merge(&mut self) -> Result<(), Error>;
+    /// `scan`, but the values may be served from where they're stored, without a copy.
+    fn scan_values(&mut self, scanner: Scanner) -> Result<(Scanner, Vec<(Vec<u8>, Value)>), Error> {
+        let (scanner, kvs) = self.scan(scanner)?;
+
+        Ok((
+            scanner,
+            kvs.into_iter().map(|(k, v)| (k, Value::Owned(v))).collect(),
+        ))
+    }
+    fn merge(&mut self) -> Result<(), Error> {
+        self.merge_with_progress(&mut |_| true).map(|_| ())
+    }
+    /// `merge`, reporting to `progress` as it goes, it's cancelled once `progress` returns
+    /// `false`: nothing is merged and it returns `false`.
+    fn merge_with_progress(
+        &mut self,
+        progress: &mut dyn FnMut(&MergeProgress) -> bool,
+    ) -> Result<bool, Error>;
+    fn stats(&self) -> Stats;
 }
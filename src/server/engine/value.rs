@@ -0,0 +1,34 @@
+// --- std ---
+use std::{
+    ops::{Deref, Range},
+    sync::Arc,
+};
+// --- external ---
+use memmap::Mmap;
+
+/// A value read from an engine, a slice of the mapping of its file if it's mapped.
+#[derive(Clone)]
+pub enum Value {
+    Owned(Vec<u8>),
+    Mapped(Arc<Mmap>, Range<usize>),
+}
+
+impl Value {
+    pub fn into_vec(self) -> Vec<u8> {
+        match self {
+            Value::Owned(v) => v,
+            Value::Mapped(map, range) => map[range].to_vec(),
+        }
+    }
+}
+
+impl Deref for Value {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Value::Owned(v) => v,
+            Value::Mapped(map, range) => &map[range.clone()],
+        }
+    }
+}
@@ -1,19 +1,40 @@
+mod batch;
 mod engine;
+mod progress;
 mod scanner;
+mod stats;
+mod transaction;
+mod v2;
 
+pub use batch::WriteBatch;
 pub use engine::{
+    btree::{self, BTreeEngine, BTreeEngineBuilder, BTreeScanner},
     hash::{self, HashEngine, HashEngineBuilder, HashScanner},
-    Engine,
+    lsm_tree::{self, LSMTreeEngine, LSMTreeEngineBuilder, LSMTreeScanner},
+    Engine, Value,
 };
-pub use scanner::Scanner;
+pub use progress::MergeProgress;
+pub use scanner::{Order, RangeScanner, Scanner};
+pub use stats::Stats;
+pub use transaction::Transaction;
 
+// --- std ---
+use std::{ops::Bound, time::Duration};
 // --- external ---
 use failure::Error;
 use grpcio::{RpcContext, ServerStreamingSink, UnarySink};
+use regex::bytes::Regex;
 // --- custom ---
-use crate::protos::{
-    kv_server::{Request, Response, ScanRequest, ScanResponse, Status},
-    kv_server_grpc::KvServer,
+use crate::{
+    hash::HashEngineError,
+    protos::{
+        kv_server::{
+            DeleteRequest, DeleteResponse, GetRequest, GetResponse, MergeRequest, MergeResponse,
+            Operation, PutRequest, PutResponse, RangeScanRequest, Request, Response, ScanRequest,
+            ScanResponse, Status,
+        },
+        kv_server_grpc::KvServer,
+    },
 };
 
 #[derive(Clone)]
@@ -32,18 +53,144 @@ where
     pub fn put(&mut self, k: Vec<u8>, v: Vec<u8>) -> Result<(), Error> {
         self.engine.put(k, v)
     }
+    pub fn put_with_ttl(&mut self, k: Vec<u8>, v: Vec<u8>, ttl: Duration) -> Result<(), Error> {
+        self.engine.put_with_ttl(k, v, ttl)
+    }
     pub fn get(&mut self, k: &[u8]) -> Result<Option<Vec<u8>>, Error> {
         self.engine.get(k)
     }
+    pub fn get_value(&mut self, k: &[u8]) -> Result<Option<Value>, Error> {
+        self.engine.get_value(k)
+    }
     pub fn del(&mut self, k: &[u8]) -> Result<(), Error> {
         self.engine.del(k)
     }
+    pub fn scanner(&self, cursor: Vec<u8>, range: i64, regex: Option<Regex>) -> Scanner {
+        self.engine.scanner(cursor, range, regex)
+    }
     pub fn scan(&mut self, scanner: Scanner) -> Result<(Scanner, Vec<(Vec<u8>, Vec<u8>)>), Error> {
         self.engine.scan(scanner)
     }
+    pub fn scan_values(
+        &mut self,
+        scanner: Scanner,
+    ) -> Result<(Scanner, Vec<(Vec<u8>, Value)>), Error> {
+        self.engine.scan_values(scanner)
+    }
+    pub fn write_batch(&mut self, batch: WriteBatch) -> Result<(), Error> {
+        self.engine.write_batch(batch)
+    }
+    pub fn compare_and_swap(
+        &mut self,
+        k: Vec<u8>,
+        expected: Option<Vec<u8>>,
+        v: Option<Vec<u8>>,
+    ) -> Result<bool, Error> {
+        self.engine.compare_and_swap(k, expected, v)
+    }
+    pub fn commit(&mut self, transaction: Transaction) -> Result<bool, Error> {
+        self.engine.commit(transaction)
+    }
     pub fn merge(&mut self) -> Result<(), Error> {
         self.engine.merge()
     }
+    pub fn merge_with_progress(
+        &mut self,
+        progress: &mut dyn FnMut(&MergeProgress) -> bool,
+    ) -> Result<bool, Error> {
+        self.engine.merge_with_progress(progress)
+    }
+    pub fn stats(&self) -> Stats {
+        self.engine.stats()
+    }
+    /// `put`, `put_with_ttl` with a `ttl`, or `del` with `None`, returning the previous value
+    /// of `k`, atomically.
+    pub fn replace(
+        &mut self,
+        k: Vec<u8>,
+        v: Option<Vec<u8>>,
+        ttl: Option<Duration>,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        self.engine.replace(k, v, ttl)
+    }
+
+    // the requests below are shared by every protocol version
+
+    // a typed put, or delete with `None`, the previous value is only looked up if asked for
+    fn write_key(
+        &mut self,
+        k: Vec<u8>,
+        v: Option<Vec<u8>>,
+        ttl: Option<Duration>,
+        return_previous: bool,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        if return_previous {
+            return self.replace(k, v, ttl);
+        }
+
+        match (v, ttl) {
+            (Some(v), Some(ttl)) => self.put_with_ttl(k, v, ttl),
+            (Some(v), None) => self.put(k, v),
+            (None, _) => self.del(&k),
+        }
+        .map(|_| None)
+    }
+
+    // the value of a get is served from where it's stored, copied only into the response
+    fn execute(&mut self, operation: Operation, k: Vec<u8>, v: Vec<u8>) -> (Status, Value) {
+        let result = match operation {
+            Operation::PUT => self.put(k, v).map(|_| Some(Value::Owned(vec![]))),
+            Operation::GET => self.get_value(&k),
+            Operation::DEL => self.del(&k).map(|_| Some(Value::Owned(vec![]))),
+            // blocks until it's over, the `Merge` RPC of v2 streams its progress
+            Operation::MERGE => self.merge().map(|_| Some(Value::Owned(vec![]))),
+        };
+
+        match result {
+            Ok(Some(v)) => (Status::OK, v),
+            Ok(None) => (Status::NotFound, Value::Owned(vec![])),
+            Err(e) => (error_status(&e), Value::Owned(vec![])),
+        }
+    }
+
+    fn cursor_scan(
+        &mut self,
+        cursor: Vec<u8>,
+        range: i64,
+        regex: &str,
+    ) -> Result<(Vec<(Vec<u8>, Value)>, Vec<u8>), Status> {
+        let scanner = self.engine.scanner(cursor, range, parse_regex(regex)?);
+
+        match self.scan_values(scanner) {
+            Ok((scanner, kvs)) => Ok((kvs, scanner.cursor().to_vec())),
+            Err(e) => Err(error_status(&e)),
+        }
+    }
+
+    fn bounded_scan(
+        &mut self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+        order: crate::protos::kv_server::Order,
+        limit: i64,
+        regex: &str,
+    ) -> Result<Vec<(Vec<u8>, Value)>, Status> {
+        let scanner = Scanner::RangeScanner(RangeScanner {
+            start,
+            end,
+            order: match order {
+                crate::protos::kv_server::Order::ASC => Order::Asc,
+                crate::protos::kv_server::Order::DESC => Order::Desc,
+            },
+            limit,
+            regex: parse_regex(regex)?,
+        });
+
+        match self.scan_values(scanner) {
+            Ok((_, kvs)) => Ok(kvs),
+            Err(e) => Err(error_status(&e)),
+        }
+    }
 }
 
 impl<E> KvServer for Server<E>
@@ -51,93 +198,233 @@ where
     E: Engine,
 {
     fn serve(&mut self, ctx: RpcContext, req: Request, sink: UnarySink<Response>) {
-        // --- external ---
-        use futures::Future;
-        // --- custom ---
-        use crate::protos::kv_server::Operation;
+        let (status, v) = self.execute(req.operation, req.key.into_bytes(), req.value.into_bytes());
 
         let mut response = Response::new();
-        match req.operation {
-            Operation::PUT => {
-                match self.put(req.key.clone().into_bytes(), req.value.clone().into_bytes()) {
-                    Ok(_) => response.set_status(Status::OK),
-                    Err(_) => response.set_status(Status::Err),
+        response.set_status(status);
+        response.set_value(String::from_utf8_lossy(&v).to_string());
+
+        respond(ctx, sink, response);
+    }
+
+    fn scan(&mut self, ctx: RpcContext, req: ScanRequest, sink: ServerStreamingSink<ScanResponse>) {
+        let result = self.cursor_scan(req.cursor, req.range, &req.regex);
+
+        respond_stream(ctx, sink, scan_responses(result, scan_response));
+    }
+
+    fn range_scan(
+        &mut self,
+        ctx: RpcContext,
+        req: RangeScanRequest,
+        sink: ServerStreamingSink<ScanResponse>,
+    ) {
+        // --- custom ---
+        use crate::protos::kv_server::KeyBound;
+
+        fn bound(key_bound: Option<&KeyBound>) -> Bound<Vec<u8>> {
+            match key_bound {
+                Some(key_bound) if key_bound.inclusive => {
+                    Bound::Included(key_bound.key.clone().into_bytes())
                 }
+                Some(key_bound) => Bound::Excluded(key_bound.key.clone().into_bytes()),
+                None => Bound::Unbounded,
             }
-            Operation::GET => match self.get(req.key.as_bytes()) {
-                Ok(option) => {
-                    if let Some(v) = option {
-                        response.set_value(String::from_utf8_lossy(&v).to_string());
-                        response.set_status(Status::OK);
-                    } else {
-                        response.set_status(Status::NotFound);
-                    }
+        }
+
+        let result = self
+            .bounded_scan(
+                bound(req.start.as_ref()),
+                bound(req.end.as_ref()),
+                req.order,
+                req.limit,
+                &req.regex,
+            )
+            .map(|kvs| (kvs, vec![]));
+
+        respond_stream(ctx, sink, scan_responses(result, scan_response));
+    }
+
+    fn put(&mut self, ctx: RpcContext, req: PutRequest, sink: UnarySink<PutResponse>) {
+        let PutRequest {
+            key,
+            value,
+            return_previous,
+            ttl_ms,
+            ..
+        } = req;
+        let ttl = if ttl_ms != 0 {
+            Some(Duration::from_millis(ttl_ms))
+        } else {
+            None
+        };
+        let result = self.write_key(
+            key.into_bytes(),
+            Some(value.into_bytes()),
+            ttl,
+            return_previous,
+        );
+
+        let mut response = PutResponse::new();
+        match result {
+            Ok(previous) => {
+                response.set_status(Status::OK);
+                if let Some(previous) = previous {
+                    response.set_existed(true);
+                    response.set_previous_value(String::from_utf8_lossy(&previous).to_string());
                 }
-                Err(_) => response.set_status(Status::Err),
-            },
-            Operation::DEL => match self.del(req.key.as_bytes()) {
-                Ok(_) => response.set_status(Status::OK),
-                Err(_) => response.set_status(Status::Err),
-            },
-            // TODO stream progress
-            Operation::MERGE => match self.merge() {
-                Ok(_) => response.set_status(Status::OK),
-                Err(_) => response.set_status(Status::Err),
-            },
+            }
+            Err(e) => {
+                response.set_status(error_status(&e));
+                response.set_error(e.to_string());
+            }
         }
 
-        let f = sink
-            .success(response.clone())
-            .map(move |_| println!("Responded with result"))
-            .map_err(move |e| eprintln!("Failed to reply: {:?}", e));
+        respond(ctx, sink, response);
+    }
+
+    fn get(&mut self, ctx: RpcContext, req: GetRequest, sink: UnarySink<GetResponse>) {
+        let mut response = GetResponse::new();
+        match Server::get(self, req.key.as_bytes()) {
+            Ok(v) => {
+                response.set_status(Status::OK);
+                if let Some(v) = v {
+                    response.set_found(true);
+                    response.set_value(String::from_utf8_lossy(&v).to_string());
+                }
+            }
+            Err(e) => {
+                response.set_status(error_status(&e));
+                response.set_error(e.to_string());
+            }
+        }
 
-        ctx.spawn(f);
+        respond(ctx, sink, response);
     }
 
-    fn scan(&mut self, ctx: RpcContext, req: ScanRequest, sink: ServerStreamingSink<ScanResponse>) {
-        // --- external ---
-        use futures::{stream, Future, Sink};
-        use grpcio::{Error, WriteFlags};
-        use regex::bytes::Regex;
-
-        let scanner = Scanner::HashScanner(HashScanner {
-            range: req.range,
-            regex: if req.regex.is_empty() {
-                None
-            } else if let Ok(regex) = Regex::new(&req.regex) {
-                Some(regex)
-            } else {
-                let mut scan_response = ScanResponse::new();
-                scan_response.set_status(Status::InvalidRegex);
-                let f = sink
-                    .send_all(stream::iter_ok::<_, Error>(vec![(
-                        scan_response,
-                        WriteFlags::default(),
-                    )]))
-                    .map(|_| println!("Responded with result"))
-                    .map_err(move |e| eprintln!("Failed to handle scan request: {:?}", e));
-
-                ctx.spawn(f);
-
-                return;
-            },
-        });
+    fn delete(&mut self, ctx: RpcContext, req: DeleteRequest, sink: UnarySink<DeleteResponse>) {
+        let result = self.write_key(req.key.into_bytes(), None, None, req.return_previous);
+
+        let mut response = DeleteResponse::new();
+        match result {
+            Ok(previous) => {
+                response.set_status(Status::OK);
+                if let Some(previous) = previous {
+                    response.set_existed(true);
+                    response.set_previous_value(String::from_utf8_lossy(&previous).to_string());
+                }
+            }
+            Err(e) => {
+                response.set_status(error_status(&e));
+                response.set_error(e.to_string());
+            }
+        }
+
+        respond(ctx, sink, response);
+    }
 
-        let mut data = vec![];
-        let kvs = self.scan(scanner).unwrap().1;
-        for (k, v) in kvs {
-            let mut scan_response = ScanResponse::new();
-            scan_response.set_status(Status::OK);
-            scan_response.set_key(String::from_utf8_lossy(&k).to_string());
-            scan_response.set_value(String::from_utf8_lossy(&v).to_string());
-            data.push((scan_response, WriteFlags::default()));
+    fn merge(&mut self, ctx: RpcContext, _: MergeRequest, sink: UnarySink<MergeResponse>) {
+        let mut response = MergeResponse::new();
+        // the last progress is what the whole merge did
+        let result = Server::merge_with_progress(self, &mut |progress| {
+            response.set_files_merged(progress.files_merged);
+            response.set_files_total(progress.files_total);
+            response.set_bytes_rewritten(progress.bytes_rewritten);
+            response.set_bytes_reclaimed(progress.bytes_reclaimed);
+            true
+        });
+        match result {
+            Ok(_) => response.set_status(Status::OK),
+            Err(e) => {
+                response.set_status(error_status(&e));
+                response.set_error(e.to_string());
+            }
         }
 
-        let f = sink
-            .send_all(stream::iter_ok::<_, Error>(data))
-            .map(|_| println!("Responded with result"))
-            .map_err(move |e| eprintln!("Failed to handle scan request: {:?}", e));
+        respond(ctx, sink, response);
+    }
+}
+
+// the responses to a scan round, for either protocol, `response` builds one of them from its
+// status, its key and value if any, and the cursor
+//
+// they're built as they're sent, a value is only copied into its response then
+fn scan_responses<R: Send>(
+    result: Result<(Vec<(Vec<u8>, Value)>, Vec<u8>), Status>,
+    response: fn(Status, Option<(Vec<u8>, Value)>, Vec<u8>) -> R,
+) -> impl Iterator<Item = R> + Send {
+    let (kvs, cursor, last) = match result {
+        // nothing matched in this round, but the scan is not complete yet
+        Ok((ref kvs, ref cursor)) if kvs.is_empty() && !cursor.is_empty() => (
+            vec![],
+            vec![],
+            Some(response(Status::NotFound, None, cursor.clone())),
+        ),
+        Ok((kvs, cursor)) => (kvs, cursor, None),
+        Err(status) => (vec![], vec![], Some(response(status, None, vec![]))),
+    };
+
+    kvs.into_iter()
+        .map(move |kv| response(Status::OK, Some(kv), cursor.clone()))
+        .chain(last)
+}
+
+fn scan_response(status: Status, kv: Option<(Vec<u8>, Value)>, cursor: Vec<u8>) -> ScanResponse {
+    let mut scan_response = ScanResponse::new();
+    scan_response.set_status(status);
+    if let Some((k, v)) = kv {
+        scan_response.set_key(String::from_utf8_lossy(&k).to_string());
+        scan_response.set_value(String::from_utf8_lossy(&v).to_string());
+    }
+    scan_response.set_cursor(cursor);
+
+    scan_response
+}
+
+fn parse_regex(regex: &str) -> Result<Option<Regex>, Status> {
+    if regex.is_empty() {
+        Ok(None)
+    } else {
+        Regex::new(regex)
+            .map(Some)
+            .map_err(|_| Status::InvalidRegex)
+    }
+}
 
-        ctx.spawn(f)
+fn error_status(e: &Error) -> Status {
+    match e.downcast_ref() {
+        Some(HashEngineError::Corruption { .. }) => Status::Corruption,
+        _ => Status::Err,
     }
 }
+
+fn respond<T: Send + 'static>(ctx: RpcContext, sink: UnarySink<T>, response: T) {
+    // --- external ---
+    use futures::Future;
+
+    let f = sink
+        .success(response)
+        .map(move |_| println!("Responded with result"))
+        .map_err(move |e| eprintln!("Failed to reply: {:?}", e));
+
+    ctx.spawn(f);
+}
+
+fn respond_stream<T: Send + 'static>(
+    ctx: RpcContext,
+    sink: ServerStreamingSink<T>,
+    responses: impl Iterator<Item = T> + Send + 'static,
+) {
+    // --- external ---
+    use futures::{stream, Future, Sink};
+    use grpcio::{Error, WriteFlags};
+
+    let f = sink
+        .send_all(stream::iter_ok::<_, Error>(
+            responses.map(|response| (response, WriteFlags::default())),
+        ))
+        .map(|_| println!("Responded with result"))
+        .map_err(move |e| eprintln!("Failed to handle scan request: {:?}", e));
+
+    ctx.spawn(f);
+}
@@ -0,0 +1,16 @@
+// --- std ---
+use std::time::Duration;
+
+/// How far a merge is, reported by `Engine::merge_with_progress`.
+#[derive(Clone, Debug, Default)]
+pub struct MergeProgress {
+    // data files, 0 for the engines that don't merge by files
+    pub files_merged: u64,
+    pub files_total: u64,
+    // bytes written to the merged files
+    pub bytes_rewritten: u64,
+    // bytes of the old files that weren't rewritten
+    pub bytes_reclaimed: u64,
+    // `None` until there's something to estimate it from
+    pub eta: Option<Duration>,
+}
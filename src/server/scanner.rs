@@ -1,6 +1,136 @@
+// --- std ---
+use std::ops::Bound;
 // --- external ---
-use crate::HashScanner;
+use regex::bytes::Regex;
+// --- custom ---
+use crate::{BTreeScanner, HashScanner, LSMTreeScanner};
+
+// the cursors of the ordered engines start with it, followed by the last key visited, an
+// empty key doesn't read as a complete scan
+const KEY_CURSOR: u8 = 1;
 
 pub enum Scanner {
+    BTreeScanner(BTreeScanner),
     HashScanner(HashScanner),
+    LSMTreeScanner(LSMTreeScanner),
+    RangeScanner(RangeScanner),
+}
+
+impl Scanner {
+    /// Where to resume the scan, empty once it's complete.
+    pub fn cursor(&self) -> &[u8] {
+        match self {
+            Scanner::BTreeScanner(scanner) => &scanner.cursor,
+            Scanner::HashScanner(scanner) => &scanner.cursor,
+            Scanner::LSMTreeScanner(scanner) => &scanner.cursor,
+            Scanner::RangeScanner(_) => &[],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+/// Ordered scan over `[start, end]`, supported by every engine.
+///
+/// After a scan the bound on the scan side is moved past the last visited key,
+/// so passing the returned scanner again (with a new `limit`) resumes the scan.
+pub struct RangeScanner {
+    pub start: Bound<Vec<u8>>,
+    pub end: Bound<Vec<u8>>,
+    pub order: Order,
+    // set -1 to scan the whole range
+    pub limit: i64,
+    pub regex: Option<Regex>,
+}
+
+impl RangeScanner {
+    pub fn start_key(&self) -> Option<&[u8]> {
+        match self.start {
+            Bound::Included(ref k) | Bound::Excluded(ref k) => Some(k),
+            Bound::Unbounded => None,
+        }
+    }
+
+    pub fn end_key(&self) -> Option<&[u8]> {
+        match self.end {
+            Bound::Included(ref k) | Bound::Excluded(ref k) => Some(k),
+            Bound::Unbounded => None,
+        }
+    }
+
+    pub fn after_start(&self, k: &[u8]) -> bool {
+        match self.start {
+            Bound::Included(ref start) => k >= start.as_slice(),
+            Bound::Excluded(ref start) => k > start.as_slice(),
+            Bound::Unbounded => true,
+        }
+    }
+
+    pub fn before_end(&self, k: &[u8]) -> bool {
+        match self.end {
+            Bound::Included(ref end) => k <= end.as_slice(),
+            Bound::Excluded(ref end) => k < end.as_slice(),
+            Bound::Unbounded => true,
+        }
+    }
+
+    pub fn contains(&self, k: &[u8]) -> bool {
+        self.after_start(k) && self.before_end(k)
+    }
+
+    /// Feed the next key in scan order, returns `false` once the scan is complete.
+    pub fn feed<V>(&mut self, k: &[u8], v: &V, kvs: &mut Vec<(Vec<u8>, V::Owned)>) -> bool
+    where
+        V: ?Sized + ToOwned,
+    {
+        if self.limit == 0 {
+            return false;
+        }
+
+        let (reached, passed) = match self.order {
+            Order::Asc => (self.after_start(k), !self.before_end(k)),
+            Order::Desc => (self.before_end(k), !self.after_start(k)),
+        };
+        if passed {
+            return false;
+        }
+        if !reached {
+            return true;
+        }
+
+        if self.regex.as_ref().map_or(true, |regex| regex.is_match(k)) {
+            kvs.push((k.to_owned(), v.to_owned()));
+            self.limit -= 1;
+        }
+
+        match self.order {
+            Order::Asc => self.start = Bound::Excluded(k.to_owned()),
+            Order::Desc => self.end = Bound::Excluded(k.to_owned()),
+        }
+
+        true
+    }
+}
+
+/// The cursor resuming an ordered scan after `k`, see `cursor_key`.
+pub fn key_cursor(k: &[u8]) -> Vec<u8> {
+    let mut cursor = Vec::with_capacity(1 + k.len());
+    cursor.push(KEY_CURSOR);
+    cursor.extend_from_slice(k);
+
+    cursor
+}
+
+/// The last key visited before `cursor`, `None` to start over, `Err` if it's not a cursor
+/// made by `key_cursor`.
+pub fn cursor_key(cursor: &[u8]) -> Result<Option<&[u8]>, ()> {
+    match cursor.split_first() {
+        None => Ok(None),
+        Some((&KEY_CURSOR, k)) => Ok(Some(k)),
+        Some(_) => Err(()),
+    }
 }
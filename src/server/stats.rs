@@ -0,0 +1,18 @@
+/// Runtime figures of an engine, reported by `Engine::stats`.
+#[derive(Clone, Debug, Default)]
+pub struct Stats {
+    pub engine: String,
+    // when a write reaches the disk
+    pub durability: String,
+    // writes and fsyncs since startup, 0 for the engines that don't count them
+    pub writes: u64,
+    pub syncs: u64,
+    // keys held in memory and an estimate of the bytes they take, 0 for the engines on disk
+    pub keys: u64,
+    pub key_dirs_bytes: u64,
+    // data files open for reads, and how often a read found its file open or closed one
+    pub open_files: u64,
+    pub file_cache_hits: u64,
+    pub file_cache_misses: u64,
+    pub file_cache_evictions: u64,
+}
@@ -0,0 +1,32 @@
+// --- custom ---
+use crate::WriteBatch;
+
+/// An optimistic transaction, applied by `Engine::commit`.
+///
+/// Nothing is locked while it's built: the values it read are checked again at
+/// commit, and its writes are applied all-or-nothing only if none of them changed.
+#[derive(Clone, Debug, Default)]
+pub struct Transaction {
+    // the value read for each key, `None` if it was absent
+    pub reads: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+    pub writes: WriteBatch,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Record `v` as the value read for `k`.
+    pub fn read(&mut self, k: Vec<u8>, v: Option<Vec<u8>>) {
+        self.reads.push((k, v));
+    }
+
+    pub fn put(&mut self, k: Vec<u8>, v: Vec<u8>) {
+        self.writes.put(k, v);
+    }
+
+    pub fn del(&mut self, k: Vec<u8>) {
+        self.writes.del(k);
+    }
+}
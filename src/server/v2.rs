@@ -0,0 +1,321 @@
+// --- std ---
+use std::{ops::Bound, time::Duration};
+// --- external ---
+use grpcio::{RpcContext, ServerStreamingSink, UnarySink};
+// --- custom ---
+use super::{
+    error_status, respond, respond_stream, scan_responses, Engine, MergeProgress, Server,
+    Transaction, Value, WriteBatch,
+};
+use crate::protos::{
+    kv_server::Status,
+    kv_server_v2::{
+        BatchRequest, BatchResponse, BatchWrite, CommitRequest, CommitResponse,
+        CompareAndSwapRequest, CompareAndSwapResponse, DeleteRequest, DeleteResponse, GetRequest,
+        GetResponse, KeyBound, MergeRequest, MergeResponse, PutRequest, PutResponse,
+        RangeScanRequest, Request, Response, ScanRequest, ScanResponse, StatsRequest,
+        StatsResponse,
+    },
+    kv_server_v2_grpc::KvServer,
+};
+
+impl<E> KvServer for Server<E>
+where
+    E: Engine + Clone + Send + 'static,
+{
+    fn serve(&mut self, ctx: RpcContext, req: Request, sink: UnarySink<Response>) {
+        let (status, v) = self.execute(req.operation, req.key, req.value);
+
+        let mut response = Response::new();
+        response.set_status(status);
+        response.set_value(v.into_vec());
+
+        respond(ctx, sink, response);
+    }
+
+    fn put(&mut self, ctx: RpcContext, req: PutRequest, sink: UnarySink<PutResponse>) {
+        let PutRequest {
+            key,
+            value,
+            return_previous,
+            ttl_ms,
+            ..
+        } = req;
+        let ttl = if ttl_ms != 0 {
+            Some(Duration::from_millis(ttl_ms))
+        } else {
+            None
+        };
+        let result = self.write_key(key, Some(value), ttl, return_previous);
+
+        let mut response = PutResponse::new();
+        match result {
+            Ok(previous) => {
+                response.set_status(Status::OK);
+                if let Some(previous) = previous {
+                    response.set_existed(true);
+                    response.set_previous_value(previous);
+                }
+            }
+            Err(e) => {
+                response.set_status(error_status(&e));
+                response.set_error(e.to_string());
+            }
+        }
+
+        respond(ctx, sink, response);
+    }
+
+    fn get(&mut self, ctx: RpcContext, req: GetRequest, sink: UnarySink<GetResponse>) {
+        let mut response = GetResponse::new();
+        match Server::get_value(self, &req.key) {
+            Ok(v) => {
+                response.set_status(Status::OK);
+                if let Some(v) = v {
+                    response.set_found(true);
+                    response.set_value(v.into_vec());
+                }
+            }
+            Err(e) => {
+                response.set_status(error_status(&e));
+                response.set_error(e.to_string());
+            }
+        }
+
+        respond(ctx, sink, response);
+    }
+
+    fn delete(&mut self, ctx: RpcContext, req: DeleteRequest, sink: UnarySink<DeleteResponse>) {
+        let result = self.write_key(req.key, None, None, req.return_previous);
+
+        let mut response = DeleteResponse::new();
+        match result {
+            Ok(previous) => {
+                response.set_status(Status::OK);
+                if let Some(previous) = previous {
+                    response.set_existed(true);
+                    response.set_previous_value(previous);
+                }
+            }
+            Err(e) => {
+                response.set_status(error_status(&e));
+                response.set_error(e.to_string());
+            }
+        }
+
+        respond(ctx, sink, response);
+    }
+
+    fn merge(
+        &mut self,
+        ctx: RpcContext,
+        _: MergeRequest,
+        sink: ServerStreamingSink<MergeResponse>,
+    ) {
+        // --- std ---
+        use std::thread;
+        // --- external ---
+        use futures::{sync::mpsc, Future, Sink, Stream};
+        use grpcio::{Error, WriteFlags};
+
+        let (tx, rx) = mpsc::unbounded();
+        let mut server = self.clone();
+        // a merge may take minutes, it reports from its own thread
+        thread::spawn(move || {
+            // the receiver is dropped once the client cancels the call, which cancels the merge
+            let result = server.merge_with_progress(&mut |progress| {
+                tx.unbounded_send(merge_response(progress)).is_ok()
+            });
+
+            let mut response = MergeResponse::new();
+            match result {
+                Ok(true) => response.set_status(Status::OK),
+                Ok(false) => return,
+                Err(e) => {
+                    response.set_status(error_status(&e));
+                    response.set_error(e.to_string());
+                }
+            }
+            response.set_done(true);
+            let _ = tx.unbounded_send(response);
+        });
+
+        let f = sink
+            .send_all(
+                rx.map(|response| (response, WriteFlags::default()))
+                    .map_err(|_| -> Error { unreachable!() }),
+            )
+            .map(|_| println!("Responded with result"))
+            .map_err(move |e| eprintln!("Failed to stream merge progress: {:?}", e));
+
+        ctx.spawn(f);
+    }
+
+    fn batch(&mut self, ctx: RpcContext, req: BatchRequest, sink: UnarySink<BatchResponse>) {
+        let batch = write_batch(req.writes.into_vec());
+
+        let mut response = BatchResponse::new();
+        match self.write_batch(batch) {
+            Ok(_) => response.set_status(Status::OK),
+            Err(e) => {
+                response.set_status(error_status(&e));
+                response.set_error(e.to_string());
+            }
+        }
+
+        respond(ctx, sink, response);
+    }
+
+    fn compare_and_swap(
+        &mut self,
+        ctx: RpcContext,
+        req: CompareAndSwapRequest,
+        sink: UnarySink<CompareAndSwapResponse>,
+    ) {
+        let CompareAndSwapRequest {
+            key,
+            expect_absent,
+            expected_value,
+            value,
+            delete,
+            ..
+        } = req;
+        let expected = if expect_absent {
+            None
+        } else {
+            Some(expected_value)
+        };
+        let v = if delete { None } else { Some(value) };
+
+        let mut response = CompareAndSwapResponse::new();
+        match Server::compare_and_swap(self, key, expected, v) {
+            Ok(swapped) => {
+                response.set_status(Status::OK);
+                response.set_swapped(swapped);
+            }
+            Err(e) => {
+                response.set_status(error_status(&e));
+                response.set_error(e.to_string());
+            }
+        }
+
+        respond(ctx, sink, response);
+    }
+
+    fn commit(&mut self, ctx: RpcContext, req: CommitRequest, sink: UnarySink<CommitResponse>) {
+        let CommitRequest { reads, writes, .. } = req;
+        let mut transaction = Transaction::new();
+        for mut read in reads.into_iter() {
+            let v = if read.absent {
+                None
+            } else {
+                Some(read.take_value())
+            };
+            transaction.read(read.take_key(), v);
+        }
+        transaction.writes = write_batch(writes.into_vec());
+
+        let mut response = CommitResponse::new();
+        match Server::commit(self, transaction) {
+            Ok(committed) => {
+                response.set_status(Status::OK);
+                response.set_committed(committed);
+            }
+            Err(e) => {
+                response.set_status(error_status(&e));
+                response.set_error(e.to_string());
+            }
+        }
+
+        respond(ctx, sink, response);
+    }
+
+    fn stats(&mut self, ctx: RpcContext, _: StatsRequest, sink: UnarySink<StatsResponse>) {
+        let stats = Server::stats(self);
+
+        let mut response = StatsResponse::new();
+        response.set_status(Status::OK);
+        response.set_engine(stats.engine);
+        response.set_durability(stats.durability);
+        response.set_writes(stats.writes);
+        response.set_syncs(stats.syncs);
+        response.set_keys(stats.keys);
+        response.set_key_dirs_bytes(stats.key_dirs_bytes);
+        response.set_open_files(stats.open_files);
+        response.set_file_cache_hits(stats.file_cache_hits);
+        response.set_file_cache_misses(stats.file_cache_misses);
+        response.set_file_cache_evictions(stats.file_cache_evictions);
+
+        respond(ctx, sink, response);
+    }
+
+    fn scan(&mut self, ctx: RpcContext, req: ScanRequest, sink: ServerStreamingSink<ScanResponse>) {
+        let result = self.cursor_scan(req.cursor, req.range, &req.regex);
+
+        respond_stream(ctx, sink, scan_responses(result, scan_response));
+    }
+
+    fn range_scan(
+        &mut self,
+        ctx: RpcContext,
+        req: RangeScanRequest,
+        sink: ServerStreamingSink<ScanResponse>,
+    ) {
+        fn bound(key_bound: Option<KeyBound>) -> Bound<Vec<u8>> {
+            match key_bound {
+                Some(key_bound) if key_bound.inclusive => Bound::Included(key_bound.key),
+                Some(key_bound) => Bound::Excluded(key_bound.key),
+                None => Bound::Unbounded,
+            }
+        }
+
+        let result = self
+            .bounded_scan(
+                bound(req.start.into_option()),
+                bound(req.end.into_option()),
+                req.order,
+                req.limit,
+                &req.regex,
+            )
+            .map(|kvs| (kvs, vec![]));
+
+        respond_stream(ctx, sink, scan_responses(result, scan_response));
+    }
+}
+
+fn merge_response(progress: &MergeProgress) -> MergeResponse {
+    let mut response = MergeResponse::new();
+    response.set_status(Status::OK);
+    response.set_files_merged(progress.files_merged);
+    response.set_files_total(progress.files_total);
+    response.set_bytes_rewritten(progress.bytes_rewritten);
+    response.set_bytes_reclaimed(progress.bytes_reclaimed);
+    response.set_eta_ms(progress.eta.map_or(0, |eta| eta.as_millis() as _));
+
+    response
+}
+
+fn scan_response(status: Status, kv: Option<(Vec<u8>, Value)>, cursor: Vec<u8>) -> ScanResponse {
+    let mut scan_response = ScanResponse::new();
+    scan_response.set_status(status);
+    if let Some((k, v)) = kv {
+        scan_response.set_key(k);
+        scan_response.set_value(v.into_vec());
+    }
+    scan_response.set_cursor(cursor);
+
+    scan_response
+}
+
+fn write_batch(writes: Vec<BatchWrite>) -> WriteBatch {
+    let mut batch = WriteBatch::new();
+    for mut write in writes.into_iter() {
+        if write.delete {
+            batch.del(write.take_key());
+        } else {
+            batch.put(write.take_key(), write.take_value());
+        }
+    }
+
+    batch
+}
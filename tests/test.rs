@@ -4,6 +4,8 @@ extern crate kv_server;
 use std::{
     fs::{read_dir, remove_dir_all},
     io,
+    ops::Bound,
+    path::Path,
     sync::Arc,
 };
 // --- external ---
@@ -11,12 +13,15 @@ use futures::{Future, Stream};
 use hashbrown::HashMap;
 // --- custom ---
 use kv_server::{
-    hash::{MergePolicy, Options},
+    hash::{Durability, KeyDirsLayout, MergePolicy, Options},
+    lsm_tree,
     protos::{
-        kv_server::{Operation, Request, ScanRequest, Status},
+        kv_server::{KeyBound, Operation, RangeScanRequest, Request, ScanRequest, Status},
         kv_server_grpc::KvServerClient,
     },
-    HashEngine, HashEngineBuilder, HashScanner, Scanner, Server,
+    BTreeEngine, BTreeEngineBuilder, BTreeScanner, Engine, HashEngine, HashEngineBuilder,
+    HashScanner, LSMTreeEngine, LSMTreeEngineBuilder, LSMTreeScanner, Order, RangeScanner, Scanner,
+    Server, Transaction, Value, WriteBatch,
 };
 
 const STORAGE_DIR: &'static str = "tests/data/test-all";
@@ -24,13 +29,25 @@ const STORAGE_DIR: &'static str = "tests/data/test-all";
 fn new_server(options: Options) -> Server<HashEngine> {
     let _ = remove_dir_all(STORAGE_DIR);
 
-    Server::new(
-        HashEngineBuilder::new()
-            .storage_dir(STORAGE_DIR)
-            .options(options)
-            .build()
-            .unwrap(),
-    )
+    Server::new(new_hash_engine(STORAGE_DIR, options))
+}
+
+// only the options a test relies on are set, `Options { <field>, ..Default::default() }`
+fn new_hash_engine(dir: &str, options: Options) -> HashEngine {
+    HashEngineBuilder::new()
+        .storage_dir(dir)
+        .options(options)
+        .build()
+        .unwrap()
+}
+
+// the keys and values of the tests writing many of them, of different sizes
+fn key(i: u16) -> Vec<u8> {
+    i.to_be_bytes().to_vec()
+}
+
+fn value(i: u16) -> Vec<u8> {
+    vec![i as u8; 32 + i as usize % 32]
 }
 
 #[test]
@@ -40,7 +57,8 @@ fn del() {
     let mut server = new_server(Options {
         file_size_limit: 200,
         keep_old_files: true,
-        merge_policy: MergePolicy::Test,
+        merge_policy: MergePolicy::Manual,
+        ..Default::default()
     });
 
     for i in 0..N {
@@ -54,6 +72,57 @@ fn del() {
     }
 }
 
+#[test]
+fn replace() {
+    // --- std ---
+    use std::{collections::HashSet, thread};
+
+    let mut server = new_server(Options::default());
+
+    assert_eq!(
+        server
+            .replace(b"k".to_vec(), Some(b"1".to_vec()), None)
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        server
+            .replace(b"k".to_vec(), Some(b"2".to_vec()), None)
+            .unwrap(),
+        Some(b"1".to_vec())
+    );
+    assert_eq!(
+        server.replace(b"k".to_vec(), None, None).unwrap(),
+        Some(b"2".to_vec())
+    );
+    assert_eq!(server.get(b"k").unwrap(), None);
+
+    // racing writers, every value is replaced once, none of them is seen twice
+    let writers = (0..4u8)
+        .map(|w| {
+            let mut server = server.clone();
+            thread::spawn(move || {
+                (0..100u8)
+                    .map(|i| {
+                        server
+                            .replace(b"race".to_vec(), Some(vec![w, i]), None)
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut previous = HashSet::new();
+    for writer in writers {
+        for v in writer.join().unwrap() {
+            assert!(previous.insert(v));
+        }
+    }
+    assert_eq!(previous.len(), 400);
+    assert!(previous.contains(&None));
+    assert!(!previous.contains(&server.get(b"race").unwrap()));
+}
+
 #[test]
 fn scan() {
     const N: u8 = 100;
@@ -61,7 +130,8 @@ fn scan() {
     let mut server = new_server(Options {
         file_size_limit: 5 * 0x100000,
         keep_old_files: true,
-        merge_policy: MergePolicy::Test,
+        merge_policy: MergePolicy::Manual,
+        ..Default::default()
     });
 
     for i in 0..N {
@@ -69,6 +139,7 @@ fn scan() {
     }
 
     let scanner = Scanner::HashScanner(HashScanner {
+        cursor: vec![],
         range: 20,
         regex: None,
     });
@@ -76,6 +147,7 @@ fn scan() {
     assert_eq!(kvs.len(), 20);
 
     let scanner = Scanner::HashScanner(HashScanner {
+        cursor: vec![],
         range: -1,
         regex: None,
     });
@@ -86,213 +158,2214 @@ fn scan() {
         server.del(&vec![i; 8]).unwrap();
     }
 
-    let scanner = Scanner::HashScanner(HashScanner {
-        range: -1,
+    let scanner = Scanner::HashScanner(HashScanner {
+        cursor: vec![],
+        range: -1,
+        regex: None,
+    });
+    let kvs = server.scan(scanner).unwrap().1;
+    assert_eq!(kvs.len(), 0);
+}
+
+#[test]
+fn merge() {
+    const N: u8 = 5;
+
+    let mut server = new_server(Options {
+        file_size_limit: 200,
+        keep_old_files: true,
+        merge_policy: MergePolicy::Manual,
+        ..Default::default()
+    });
+
+    for i in 0..N {
+        server.put(vec![i; 8], vec![i; 256]).unwrap();
+    }
+    for i in 0..N {
+        server.put(vec![i; 8], vec![i; 256]).unwrap();
+    }
+
+    server.merge().unwrap();
+
+    for i in 0..N {
+        //        assert!(server.get(&vec![i; 8]).is_ok());
+        assert_eq!(server.get(&vec![i; 8]).unwrap().unwrap(), vec![i; 256]);
+    }
+
+    assert_eq!(
+        read_dir(STORAGE_DIR)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.unwrap())
+            .filter(|e| if let Some(extension) = e.path().extension() {
+                if extension == "data" || extension == "hint" {
+                    true
+                } else {
+                    false
+                }
+            } else {
+                false
+            })
+            .count(),
+        N as usize * 2
+    );
+}
+
+#[test]
+fn merge_policy() {
+    // --- std ---
+    use std::{thread, time::Duration};
+    // --- custom ---
+    use kv_server::hash::{FileStats, MergeThresholds};
+
+    const TEST_DIR: &'static str = "tests/data/test-merge-policy";
+
+    let thresholds = MergeThresholds {
+        check_interval: Duration::from_millis(20),
+        window: Some((22, 2)),
+        fragmentation: 0.5,
+        dead_bytes: 0x100000,
+        file_count: 100,
+    };
+    assert!(thresholds.in_window(23) && thresholds.in_window(1));
+    assert!(!thresholds.in_window(2) && !thresholds.in_window(12));
+    let file = |file_id, total_bytes, dead_bytes| FileStats {
+        file_id,
+        active: file_id == 3,
+        total_bytes,
+        dead_bytes,
+    };
+    assert!(thresholds
+        .select(&[file(1, 100, 10), file(2, 100, 40), file(3, 100, 90)])
+        .is_empty());
+    assert_eq!(
+        thresholds.select(&[file(1, 100, 10), file(2, 100, 50), file(3, 100, 0)]),
+        vec![2]
+    );
+    assert_eq!(
+        thresholds.select(&[file(1, 0x100000, 0x80000), file(2, 0x100000, 0x80000)]),
+        vec![1, 2]
+    );
+    let files = (1..=100)
+        .map(|file_id| file(file_id, 100, 0))
+        .collect::<Vec<_>>();
+    assert!(thresholds.select(&files).is_empty());
+    // the active file doesn't count
+    assert!(thresholds
+        .select(&[&files[..99], &[file(100, 100, 10)]].concat())
+        .is_empty());
+    assert_eq!(
+        thresholds
+            .select(&[&files[..], &[file(101, 100, 10)]].concat())
+            .len(),
+        100
+    );
+
+    let _ = remove_dir_all(TEST_DIR);
+    let mut engine = new_hash_engine(
+        TEST_DIR,
+        Options {
+            file_size_limit: 256,
+            keep_old_files: false,
+            merge_policy: MergePolicy::Auto(MergeThresholds {
+                window: None,
+                ..thresholds
+            }),
+            durability: Durability::Buffered,
+            ..Default::default()
+        },
+    );
+    assert!(engine.file_stats().unwrap().is_empty());
+
+    // every record but the last one of each key is dead
+    for i in 0..10u8 {
+        for j in 0..4u8 {
+            engine.put(vec![j; 8], vec![i; 64]).unwrap();
+        }
+    }
+    engine.put(vec![4; 8], vec![]).unwrap();
+    engine.del(&vec![4; 8]).unwrap();
+
+    // the background thread merges the fragmented files, leaving the active one
+    let mut merged = false;
+    for _ in 0..50 {
+        thread::sleep(Duration::from_millis(20));
+        let file_stats = engine.file_stats().unwrap();
+        if thresholds.select(&file_stats).is_empty() {
+            merged = true;
+            break;
+        }
+    }
+    assert!(merged);
+    for j in 0..4u8 {
+        assert_eq!(engine.get(&vec![j; 8]).unwrap(), Some(vec![9; 64]));
+    }
+    assert_eq!(engine.get(&vec![4; 8]).unwrap(), None);
+}
+
+#[test]
+fn incremental_merge() {
+    // --- std ---
+    use std::thread;
+
+    const TEST_DIR: &'static str = "tests/data/test-incremental-merge";
+
+    let options = Options {
+        file_size_limit: 256,
+        keep_old_files: false,
+        durability: Durability::Buffered,
+        ..Default::default()
+    };
+    let _ = remove_dir_all(TEST_DIR);
+    let mut engine = new_hash_engine(TEST_DIR, options.clone());
+
+    for i in 0..4u8 {
+        for j in 0..8u8 {
+            engine.put(vec![j; 8], vec![i; 64]).unwrap();
+        }
+    }
+    engine.del(&vec![0; 8]).unwrap();
+    let snapshot = engine.snapshot();
+
+    // half of the files are merged while the odd keys keep being overwritten
+    let file_stats = engine.file_stats().unwrap();
+    let file_ids = file_stats[..file_stats.len() / 2]
+        .iter()
+        .map(|file| file.file_id)
+        .collect::<Vec<_>>();
+    let writer = {
+        let mut engine = engine.clone();
+        thread::spawn(move || {
+            for i in 4..20u8 {
+                for j in (1..8u8).step_by(2) {
+                    engine.put(vec![j; 8], vec![i; 64]).unwrap();
+                }
+            }
+        })
+    };
+    assert!(engine.merge_files(&file_ids, &mut |_| true).unwrap());
+    writer.join().unwrap();
+
+    let file_stats = engine.file_stats().unwrap();
+    assert!(file_stats
+        .iter()
+        .all(|file| !file_ids.contains(&file.file_id)));
+    let check = |engine: &mut HashEngine| {
+        assert_eq!(engine.get(&vec![0; 8]).unwrap(), None);
+        for j in 1..8u8 {
+            let i = if j % 2 == 0 { 3 } else { 19 };
+            assert_eq!(engine.get(&vec![j; 8]).unwrap(), Some(vec![i; 64]));
+        }
+    };
+    check(&mut engine);
+    assert_eq!(snapshot.get(&vec![0; 8]).unwrap(), None);
+    for j in 1..8u8 {
+        assert_eq!(snapshot.get(&vec![j; 8]).unwrap(), Some(vec![3; 64]));
+    }
+    drop(snapshot);
+
+    // the copies replay the same history on reload
+    drop(engine);
+    let mut engine = new_hash_engine(TEST_DIR, options);
+    check(&mut engine);
+}
+
+#[test]
+fn merge_progress() {
+    // --- custom ---
+    use kv_server::MergeProgress;
+
+    const TEST_DIR: &'static str = "tests/data/test-merge-progress";
+
+    let _ = remove_dir_all(TEST_DIR);
+    let mut engine = new_hash_engine(
+        TEST_DIR,
+        Options {
+            file_size_limit: 256,
+            keep_old_files: false,
+            durability: Durability::Buffered,
+            ..Default::default()
+        },
+    );
+    for i in 0..4u8 {
+        for j in 0..8u8 {
+            engine.put(vec![j; 8], vec![i; 64]).unwrap();
+        }
+    }
+    let file_count = engine.file_stats().unwrap().len();
+
+    // cancelled on the first report, nothing is merged
+    let mut reports = 0;
+    assert!(!engine
+        .merge_with_progress(&mut |_| {
+            reports += 1;
+            false
+        })
+        .unwrap());
+    assert_eq!(reports, 1);
+    assert_eq!(engine.file_stats().unwrap().len(), file_count);
+    assert!(!Path::new(&format!("{}/merge", TEST_DIR)).exists());
+
+    let mut reports: Vec<MergeProgress> = vec![];
+    assert!(engine
+        .merge_with_progress(&mut |progress| {
+            reports.push(progress.clone());
+            true
+        })
+        .unwrap());
+    assert_eq!(reports.len(), file_count);
+    for (i, progress) in reports.iter().enumerate() {
+        assert_eq!(progress.files_merged, i as u64 + 1);
+        assert_eq!(progress.files_total, file_count as u64);
+        assert!(progress.eta.is_some());
+    }
+    let last = reports.last().unwrap();
+    assert_eq!(last.eta, Some(Default::default()));
+    assert!(last.bytes_rewritten > 0 && last.bytes_reclaimed > last.bytes_rewritten);
+    for j in 0..8u8 {
+        assert_eq!(engine.get(&vec![j; 8]).unwrap(), Some(vec![3; 64]));
+    }
+}
+
+#[test]
+fn merge_recovery() {
+    // --- std ---
+    use std::fs::{copy, create_dir, create_dir_all, rename, write};
+
+    const TEST_DIR: &'static str = "tests/data/test-merge-recovery";
+    const SAVED_DIR: &'static str = "tests/data/test-merge-recovery-saved";
+
+    let options = Options {
+        file_size_limit: 256,
+        keep_old_files: false,
+        durability: Durability::Buffered,
+        ..Default::default()
+    };
+    let build = || new_hash_engine(TEST_DIR, options.clone());
+    let data_files = || {
+        let mut file_ids = read_dir(TEST_DIR)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .filter(|path| {
+                path.extension()
+                    .map_or(false, |extension| extension == "data")
+            })
+            .map(|path| path.file_stem().unwrap().to_str().unwrap().parse().unwrap())
+            .collect::<Vec<u64>>();
+        file_ids.sort();
+        file_ids
+    };
+    let check = |engine: &mut HashEngine| {
+        for j in 0..12u8 {
+            assert_eq!(engine.get(&vec![j; 8]).unwrap(), Some(vec![3; 64]));
+        }
+    };
+    let merge_dir = format!("{}/merge", TEST_DIR);
+
+    let _ = remove_dir_all(TEST_DIR);
+    let _ = remove_dir_all(SAVED_DIR);
+    {
+        let mut engine = build();
+        // never overwritten, the oldest files have records to copy
+        for j in 8..12u8 {
+            engine.put(vec![j; 8], vec![3; 64]).unwrap();
+        }
+        for i in 0..4u8 {
+            for j in 0..8u8 {
+                engine.put(vec![j; 8], vec![i; 64]).unwrap();
+            }
+        }
+    }
+
+    // a merge interrupted before its commit is rolled back
+    let file_ids = data_files();
+    create_dir(&merge_dir).unwrap();
+    write(format!("{}/{}.data", merge_dir, file_ids[0] + 1), b"torn").unwrap();
+    let mut engine = build();
+    check(&mut engine);
+    assert_eq!(data_files(), file_ids);
+    assert!(!Path::new(&merge_dir).exists());
+
+    // one interrupted after it, while installing the copies, is completed
+    let merged = &file_ids[..file_ids.len() / 2];
+    create_dir_all(SAVED_DIR).unwrap();
+    for file_id in merged {
+        for extension in &["data", "hint"] {
+            let name = format!("{}.{}", file_id, extension);
+            copy(
+                format!("{}/{}", TEST_DIR, name),
+                format!("{}/{}", SAVED_DIR, name),
+            )
+            .unwrap();
+        }
+    }
+    engine.merge_files(merged, &mut |_| true).unwrap();
+    drop(engine);
+    let merged_file_ids = data_files();
+    let copy_id = *merged_file_ids
+        .iter()
+        .find(|file_id| !file_ids.contains(file_id))
+        .unwrap();
+
+    for file_id in merged {
+        for extension in &["data", "hint"] {
+            let name = format!("{}.{}", file_id, extension);
+            copy(
+                format!("{}/{}", SAVED_DIR, name),
+                format!("{}/{}", TEST_DIR, name),
+            )
+            .unwrap();
+        }
+    }
+    create_dir(&merge_dir).unwrap();
+    for extension in &["data", "hint"] {
+        let name = format!("{}.{}", copy_id, extension);
+        rename(
+            format!("{}/{}", TEST_DIR, name),
+            format!("{}/{}", merge_dir, name),
+        )
+        .unwrap();
+    }
+    write(
+        format!("{}/MANIFEST", merge_dir),
+        merged
+            .iter()
+            .map(|file_id| format!("{}\n", file_id))
+            .collect::<String>(),
+    )
+    .unwrap();
+
+    let mut engine = build();
+    check(&mut engine);
+    assert_eq!(data_files(), merged_file_ids);
+    assert!(!Path::new(&merge_dir).exists());
+}
+
+#[test]
+fn corruption() {
+    // --- std ---
+    use std::{
+        fs::OpenOptions,
+        io::{Seek, SeekFrom, Write},
+    };
+    // --- external ---
+    use failure::Error;
+    // --- custom ---
+    use kv_server::hash::HashEngineError;
+
+    const N: u8 = 5;
+    const TEST_DIR: &'static str = "tests/data/test-corruption";
+
+    fn new_engine(truncate_torn_tail: bool) -> Result<HashEngine, Error> {
+        HashEngineBuilder::new()
+            .storage_dir(TEST_DIR)
+            .options(Options {
+                file_size_limit: 5 * 0x100000,
+                keep_old_files: false,
+                truncate_torn_tail,
+                ..Default::default()
+            })
+            .build()
+    }
+
+    fn data_file() -> std::fs::File {
+        let path = read_dir(TEST_DIR)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .find(|path| path.extension().map_or(false, |e| e == "data"))
+            .unwrap();
+
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)
+            .unwrap()
+    }
+
+    let _ = remove_dir_all(TEST_DIR);
+
+    {
+        let mut engine = new_engine(false).unwrap();
+        for i in 0..N {
+            engine.put(vec![i; 8], vec![i; 256]).unwrap();
+        }
+
+        // flip a byte in the value of the first record, right behind the file header
+        let mut file = data_file();
+        file.seek(SeekFrom::Start(8 + 21 + 8 + 10)).unwrap();
+        file.write_all(&[0xff]).unwrap();
+
+        match engine.get(&vec![0; 8]).unwrap_err().downcast() {
+            Ok(HashEngineError::Corruption { offset, .. }) => assert_eq!(offset, 8),
+            _ => panic!("expected corruption"),
+        }
+        assert_eq!(engine.get(&vec![1; 8]).unwrap().unwrap(), vec![1; 256]);
+
+        file.seek(SeekFrom::Start(8 + 21 + 8 + 10)).unwrap();
+        file.write_all(&[0]).unwrap();
+    }
+
+    // a record torn in the middle of its header
+    let len = {
+        let mut file = data_file();
+        let len = file.seek(SeekFrom::End(0)).unwrap();
+        file.write_all(&[0xff; 10]).unwrap();
+
+        len
+    };
+
+    assert!(new_engine(false).is_err());
+    let mut engine = new_engine(true).unwrap();
+    assert_eq!(data_file().metadata().unwrap().len(), len);
+    for i in 0..N {
+        assert_eq!(engine.get(&vec![i; 8]).unwrap().unwrap(), vec![i; 256]);
+    }
+}
+
+#[test]
+fn rebuild_hints() {
+    // --- std ---
+    use std::fs::{read, remove_file, write};
+
+    const N: u8 = 5;
+    const TEST_DIR: &'static str = "tests/data/test-rebuild-hints";
+
+    fn new_engine(verify_hints: bool) -> HashEngine {
+        new_hash_engine(
+            TEST_DIR,
+            Options {
+                file_size_limit: 5 * 0x100000,
+                keep_old_files: false,
+                verify_hints,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn hint_path() -> std::path::PathBuf {
+        read_dir(TEST_DIR)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .find(|path| path.extension().map_or(false, |e| e == "hint"))
+            .unwrap()
+    }
+
+    fn check(mut engine: HashEngine) {
+        for i in 0..N {
+            assert_eq!(
+                engine.get(&vec![i; 8]).unwrap(),
+                if i == 0 { None } else { Some(vec![i; 256]) }
+            );
+        }
+    }
+
+    let _ = remove_dir_all(TEST_DIR);
+
+    {
+        let mut engine = new_engine(false);
+        for i in 0..N {
+            engine.put(vec![i; 8], vec![i; 256]).unwrap();
+        }
+        engine.del(&vec![0; 8]).unwrap();
+        // nothing is hinted until the file is sealed, by a rotation or a merge
+        assert!(read_dir(TEST_DIR).unwrap().all(|e| e
+            .unwrap()
+            .path()
+            .extension()
+            .map_or(true, |e| e != "hint")));
+        engine.merge().unwrap();
+    }
+    let hints = read(hint_path()).unwrap();
+
+    // lost
+    remove_file(hint_path()).unwrap();
+    check(new_engine(false));
+    assert_eq!(read(hint_path()).unwrap(), hints);
+
+    // torn
+    write(hint_path(), &hints[..hints.len() / 2]).unwrap();
+    check(new_engine(false));
+    assert_eq!(read(hint_path()).unwrap(), hints);
+
+    // well formed but wrong, only caught by cross-checking with the data
+    let mut damaged_hints = hints.clone();
+    damaged_hints[0] ^= 0xff;
+    write(hint_path(), &damaged_hints).unwrap();
+    new_engine(false);
+    assert_eq!(read(hint_path()).unwrap(), damaged_hints);
+    check(new_engine(true));
+    assert_eq!(read(hint_path()).unwrap(), hints);
+}
+
+#[test]
+fn legacy_data_file() {
+    // --- std ---
+    use std::fs::{create_dir_all, read, write};
+    // --- external ---
+    use crc::crc32::checksum_ieee;
+
+    const N: u8 = 5;
+    const TEST_DIR: &'static str = "tests/data/test-legacy-data-file";
+
+    let _ = remove_dir_all(TEST_DIR);
+    create_dir_all(TEST_DIR).unwrap();
+
+    // the layout before the file header, deletes are hinted at position 0
+    let (mut data, mut hints) = (vec![], vec![]);
+    for i in 0..=N {
+        let (k, v) = if i == N {
+            (vec![0; 8], vec![])
+        } else {
+            (vec![i; 8], vec![i; 256])
+        };
+        let timestamp = i as u64 + 1;
+
+        let mut record = vec![0; 4];
+        record.extend_from_slice(&timestamp.to_le_bytes());
+        record.extend_from_slice(&(k.len() as u32).to_le_bytes());
+        let crc = checksum_ieee(&record[4..]);
+        record.extend_from_slice(&crc.to_le_bytes());
+        record.extend_from_slice(&k);
+        let value_position = if v.is_empty() {
+            0
+        } else {
+            (data.len() + record.len()) as u64
+        };
+        record.extend_from_slice(&v);
+        data.extend_from_slice(&record);
+
+        hints.extend_from_slice(&timestamp.to_le_bytes());
+        hints.extend_from_slice(&(k.len() as u32).to_le_bytes());
+        hints.extend_from_slice(&(v.len() as u32).to_le_bytes());
+        hints.extend_from_slice(&value_position.to_le_bytes());
+        hints.extend_from_slice(&k);
+    }
+    write(format!("{}/1.data", TEST_DIR), &data).unwrap();
+    write(format!("{}/1.hint", TEST_DIR), &hints).unwrap();
+
+    let mut server = Server::new(new_hash_engine(
+        TEST_DIR,
+        Options {
+            file_size_limit: 5 * 0x100000,
+            keep_old_files: false,
+            verify_hints: true,
+            ..Default::default()
+        },
+    ));
+    let check = |server: &mut Server<HashEngine>| {
+        for i in 0..N {
+            assert_eq!(
+                server.get(&vec![i; 8]).unwrap(),
+                if i == 0 { None } else { Some(vec![i; 256]) }
+            );
+        }
+    };
+
+    check(&mut server);
+    // new writes never go to a legacy file
+    assert_eq!(read(format!("{}/1.data", TEST_DIR)).unwrap(), data);
+
+    server.merge().unwrap();
+    check(&mut server);
+    for entry in read_dir(TEST_DIR).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().map_or(false, |e| e == "data") {
+            assert_eq!(&read(path).unwrap()[..4], b"KVDH");
+        }
+    }
+}
+
+#[test]
+fn tombstone() {
+    const TEST_DIR: &'static str = "tests/data/test-tombstone";
+
+    fn new_server() -> Server<HashEngine> {
+        Server::new(
+            HashEngineBuilder::new()
+                .storage_dir(TEST_DIR)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    let _ = remove_dir_all(TEST_DIR);
+
+    {
+        let mut server = new_server();
+        server.put(b"empty".to_vec(), vec![]).unwrap();
+        server.put(b"deleted".to_vec(), b"v".to_vec()).unwrap();
+        server.del(b"deleted").unwrap();
+    }
+
+    let mut server = new_server();
+    assert_eq!(server.get(b"empty").unwrap(), Some(vec![]));
+    assert_eq!(server.get(b"deleted").unwrap(), None);
+
+    server.merge().unwrap();
+    drop(server);
+
+    let mut server = new_server();
+    assert_eq!(server.get(b"empty").unwrap(), Some(vec![]));
+    assert_eq!(server.get(b"deleted").unwrap(), None);
+}
+
+#[test]
+fn snapshot() {
+    const TEST_DIR: &'static str = "tests/data/test-snapshot";
+
+    fn new_engine() -> HashEngine {
+        new_hash_engine(
+            TEST_DIR,
+            Options {
+                file_size_limit: 64,
+                keep_old_files: false,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn range_scanner() -> Scanner {
+        Scanner::RangeScanner(RangeScanner {
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+            order: Order::Asc,
+            limit: -1,
+            regex: None,
+        })
+    }
+
+    let _ = remove_dir_all(TEST_DIR);
+    let mut engine = new_engine();
+    engine.put(b"a".to_vec(), b"1".to_vec()).unwrap();
+    engine.put(b"b".to_vec(), b"1".to_vec()).unwrap();
+
+    let snapshot = engine.snapshot();
+    engine.put(b"a".to_vec(), b"2".to_vec()).unwrap();
+    engine.del(b"b").unwrap();
+    engine.put(b"c".to_vec(), b"2".to_vec()).unwrap();
+    let mut batch = WriteBatch::new();
+    batch.put(b"a".to_vec(), b"3".to_vec());
+    batch.put(b"b".to_vec(), b"3".to_vec());
+    engine.write_batch(batch).unwrap();
+    let later_snapshot = engine.snapshot();
+    engine.del(b"b").unwrap();
+
+    let check = |engine: &mut HashEngine| {
+        assert_eq!(snapshot.get(b"a").unwrap(), Some(b"1".to_vec()));
+        assert_eq!(snapshot.get(b"b").unwrap(), Some(b"1".to_vec()));
+        assert_eq!(snapshot.get(b"c").unwrap(), None);
+        assert_eq!(
+            snapshot.scan(range_scanner()).unwrap().1,
+            vec![
+                (b"a".to_vec(), b"1".to_vec()),
+                (b"b".to_vec(), b"1".to_vec())
+            ]
+        );
+        let (_, mut kvs) = snapshot.scan(engine.scanner(vec![], -1, None)).unwrap();
+        kvs.sort();
+        assert_eq!(
+            kvs,
+            vec![
+                (b"a".to_vec(), b"1".to_vec()),
+                (b"b".to_vec(), b"1".to_vec())
+            ]
+        );
+
+        assert_eq!(
+            later_snapshot.scan(range_scanner()).unwrap().1,
+            vec![
+                (b"a".to_vec(), b"3".to_vec()),
+                (b"b".to_vec(), b"3".to_vec()),
+                (b"c".to_vec(), b"2".to_vec())
+            ]
+        );
+
+        assert_eq!(
+            engine.scan(range_scanner()).unwrap().1,
+            vec![
+                (b"a".to_vec(), b"3".to_vec()),
+                (b"c".to_vec(), b"2".to_vec())
+            ]
+        );
+    };
+
+    check(&mut engine);
+    // the versions seen by the snapshots survive a merge
+    engine.merge().unwrap();
+    check(&mut engine);
+
+    drop(snapshot);
+    drop(later_snapshot);
+    let snapshot = engine.snapshot();
+    assert_eq!(snapshot.get(b"a").unwrap(), Some(b"3".to_vec()));
+    assert_eq!(snapshot.get(b"b").unwrap(), None);
+    drop(snapshot);
+    drop(engine);
+
+    // the old versions rewritten by the merge don't come back on reload
+    let mut engine = new_engine();
+    assert_eq!(
+        engine.scan(range_scanner()).unwrap().1,
+        vec![
+            (b"a".to_vec(), b"3".to_vec()),
+            (b"c".to_vec(), b"2".to_vec())
+        ]
+    );
+}
+
+#[test]
+fn ttl() {
+    // --- std ---
+    use std::{fs::read, thread, time::Duration};
+
+    const TEST_DIR: &'static str = "tests/data/test-ttl";
+
+    fn new_engine(verify_hints: bool) -> HashEngine {
+        new_hash_engine(
+            TEST_DIR,
+            Options {
+                file_size_limit: 5 * 0x100000,
+                keep_old_files: false,
+                verify_hints,
+                reap_interval: Some(Duration::from_millis(10)),
+                ..Default::default()
+            },
+        )
+    }
+
+    let _ = remove_dir_all(TEST_DIR);
+    let mut engine = new_engine(false);
+    engine
+        .put_with_ttl(
+            b"short-lived".to_vec(),
+            b"1".to_vec(),
+            Duration::from_millis(100),
+        )
+        .unwrap();
+    engine
+        .put_with_ttl(
+            b"long-lived".to_vec(),
+            b"1".to_vec(),
+            Duration::from_secs(3600),
+        )
+        .unwrap();
+    engine.put(b"forever".to_vec(), b"1".to_vec()).unwrap();
+    // far beyond what the clock counts, it saturates instead of wrapping to the past
+    engine
+        .put_with_ttl(
+            b"too-long-lived".to_vec(),
+            b"1".to_vec(),
+            Duration::from_secs(u64::max_value()),
+        )
+        .unwrap();
+    assert_eq!(engine.get(b"too-long-lived").unwrap(), Some(b"1".to_vec()));
+    engine.del(b"too-long-lived").unwrap();
+    assert_eq!(engine.get(b"short-lived").unwrap(), Some(b"1".to_vec()));
+
+    thread::sleep(Duration::from_millis(200));
+    assert_eq!(engine.get(b"short-lived").unwrap(), None);
+    // reaped, a write of the key in between keeps it
+    assert_eq!(engine.stats().keys, 2);
+    engine
+        .put_with_ttl(
+            b"renewed".to_vec(),
+            b"1".to_vec(),
+            Duration::from_millis(50),
+        )
+        .unwrap();
+    thread::sleep(Duration::from_millis(100));
+    engine.put(b"renewed".to_vec(), b"2".to_vec()).unwrap();
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(engine.get(b"renewed").unwrap(), Some(b"2".to_vec()));
+    engine.del(b"renewed").unwrap();
+    let (_, mut kvs) = engine.scan(engine.scanner(vec![], -1, None)).unwrap();
+    kvs.sort();
+    assert_eq!(
+        kvs,
+        vec![
+            (b"forever".to_vec(), b"1".to_vec()),
+            (b"long-lived".to_vec(), b"1".to_vec())
+        ]
+    );
+    drop(engine);
+
+    // the expiry is kept in the hints, and in the records they're rebuilt from
+    for &verify_hints in [false, true].iter() {
+        let mut engine = new_engine(verify_hints);
+        assert_eq!(engine.get(b"short-lived").unwrap(), None);
+        assert_eq!(engine.get(b"long-lived").unwrap(), Some(b"1".to_vec()));
+    }
+
+    // an expired key is absent for a compare-and-swap
+    let mut engine = new_engine(false);
+    engine
+        .put_with_ttl(
+            b"lease".to_vec(),
+            b"owner-1".to_vec(),
+            Duration::from_millis(50),
+        )
+        .unwrap();
+    assert!(!engine
+        .compare_and_swap(b"lease".to_vec(), None, Some(b"owner-2".to_vec()))
+        .unwrap());
+    thread::sleep(Duration::from_millis(100));
+    assert!(engine
+        .compare_and_swap(b"lease".to_vec(), None, Some(b"owner-2".to_vec()))
+        .unwrap());
+
+    // merge drops the expired records
+    engine.merge().unwrap();
+    for entry in read_dir(TEST_DIR).unwrap() {
+        let data = read(entry.unwrap().path()).unwrap();
+        assert!(!data.windows(11).any(|w| w == b"short-lived"));
+    }
+    drop(engine);
+
+    let mut engine = new_engine(false);
+    assert_eq!(engine.get(b"long-lived").unwrap(), Some(b"1".to_vec()));
+    assert_eq!(engine.get(b"lease").unwrap(), Some(b"owner-2".to_vec()));
+}
+
+#[test]
+fn key_dirs_layouts() {
+    // --- std ---
+    use std::{thread, time::Duration};
+
+    const N: u16 = 300;
+    const TEST_DIR: &'static str = "tests/data/test-key-dirs";
+
+    fn new_engine(key_dirs: KeyDirsLayout) -> HashEngine {
+        new_hash_engine(
+            TEST_DIR,
+            Options {
+                file_size_limit: 4096,
+                keep_old_files: false,
+                durability: Durability::Buffered,
+                reap_interval: Some(Duration::from_millis(10)),
+                key_dirs,
+                // a single shard, the arenas of many small ones would be mostly slack
+                key_dirs_shards: 1,
+                ..Default::default()
+            },
+        )
+    }
+
+    // long keys, sharing a prefix
+    let key = |i: u16| {
+        let mut k = vec![b'k'; 62];
+        k.extend_from_slice(&i.to_be_bytes());
+        k
+    };
+    let check = |engine: &mut HashEngine| {
+        for i in 0..N {
+            let v = match i {
+                i if i % 3 == 0 => None,
+                i if i % 2 == 0 => Some(vec![2; 16]),
+                _ => Some(vec![1; 16]),
+            };
+            assert_eq!(engine.get(&key(i)).unwrap(), v);
+        }
+
+        let live = (0..N).filter(|i| i % 3 != 0).map(key).collect::<Vec<_>>();
+        let mut keys = engine
+            .scan(engine.scanner(vec![], -1, None))
+            .unwrap()
+            .1
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys, live);
+
+        let kvs = engine
+            .scan(Scanner::RangeScanner(RangeScanner {
+                start: Bound::Included(key(100)),
+                end: Bound::Unbounded,
+                order: Order::Desc,
+                limit: 2,
+                regex: None,
+            }))
+            .unwrap()
+            .1;
+        assert_eq!(
+            kvs,
+            vec![(key(N - 1), vec![1; 16]), (key(N - 2), vec![2; 16])]
+        );
+        assert_eq!(engine.stats().keys, live.len() as u64);
+    };
+
+    let mut key_dirs_bytes = vec![];
+    for &layout in [
+        KeyDirsLayout::Standard,
+        KeyDirsLayout::Compact,
+        KeyDirsLayout::Hashed,
+    ]
+    .iter()
+    {
+        let _ = remove_dir_all(TEST_DIR);
+        let mut engine = new_engine(layout);
+        for i in 0..N {
+            engine.put(key(i), vec![1; 16]).unwrap();
+        }
+        let snapshot = engine.snapshot();
+        for i in (0..N).filter(|i| i % 2 == 0) {
+            engine.put(key(i), vec![2; 16]).unwrap();
+        }
+        for i in (0..N).filter(|i| i % 3 == 0) {
+            engine.del(&key(i)).unwrap();
+        }
+        engine
+            .put_with_ttl(b"short-lived".to_vec(), vec![0], Duration::from_millis(10))
+            .unwrap();
+        thread::sleep(Duration::from_millis(100));
+        check(&mut engine);
+        assert_eq!(snapshot.get(&key(0)).unwrap(), Some(vec![1; 16]));
+        assert_eq!(
+            snapshot
+                .scan(engine.scanner(vec![], -1, None))
+                .unwrap()
+                .1
+                .len(),
+            N as usize
+        );
+        key_dirs_bytes.push(engine.stats().key_dirs_bytes);
+
+        engine.merge().unwrap();
+        check(&mut engine);
+        assert_eq!(snapshot.get(&key(0)).unwrap(), Some(vec![1; 16]));
+        drop(snapshot);
+        drop(engine);
+
+        check(&mut new_engine(layout));
+    }
+
+    // the longer the keys, the more a compact layout saves
+    assert!(key_dirs_bytes[0] > key_dirs_bytes[1]);
+    assert!(key_dirs_bytes[1] > key_dirs_bytes[2]);
+}
+
+#[test]
+fn packed_key_dirs_file_ids() {
+    const N: u8 = 10;
+    const TEST_DIR: &'static str = "tests/data/test-packed-key-dirs-file-ids";
+
+    let _ = remove_dir_all(TEST_DIR);
+    let mut engine = new_hash_engine(
+        TEST_DIR,
+        Options {
+            // a file per record
+            file_size_limit: 1,
+            keep_old_files: false,
+            durability: Durability::Buffered,
+            key_dirs: KeyDirsLayout::Compact,
+            key_dirs_shards: 1,
+            ..Default::default()
+        },
+    );
+
+    // the ids of the merged files are dropped with them, they don't pile up merge after merge
+    let mut key_dirs_bytes = vec![];
+    for round in 0..3u8 {
+        for _ in 0..50 {
+            for i in 0..N {
+                engine.put(vec![i], vec![round]).unwrap();
+            }
+        }
+        let before = engine.stats().key_dirs_bytes;
+        engine.merge().unwrap();
+        key_dirs_bytes.push(engine.stats().key_dirs_bytes);
+        assert!(key_dirs_bytes[round as usize] < before);
+    }
+    assert!(key_dirs_bytes[2] <= key_dirs_bytes[0]);
+    for i in 0..N {
+        assert_eq!(engine.get(&[i]).unwrap(), Some(vec![2]));
+    }
+}
+
+#[test]
+fn sharded_key_dirs() {
+    // --- std ---
+    use std::thread;
+
+    const ROUNDS: u16 = 200;
+    const KEYS: u8 = 32;
+    const TEST_DIR: &'static str = "tests/data/test-sharded-key-dirs";
+
+    let _ = remove_dir_all(TEST_DIR);
+    let engine = new_hash_engine(
+        TEST_DIR,
+        Options {
+            file_size_limit: 0x10000,
+            keep_old_files: false,
+            durability: Durability::Buffered,
+            key_dirs_shards: 8,
+            ..Default::default()
+        },
+    );
+
+    // every round rewrites all the keys, spread over every shard, in a single batch
+    let writer = {
+        let mut engine = engine.clone();
+        thread::spawn(move || {
+            for round in 1..=ROUNDS {
+                let mut batch = WriteBatch::new();
+                for i in 0..KEYS {
+                    batch.put(vec![i; 8], round.to_be_bytes().to_vec());
+                }
+                engine.write_batch(batch).unwrap();
+            }
+        })
+    };
+    let readers = (0..4)
+        .map(|_| {
+            let mut engine = engine.clone();
+            thread::spawn(move || loop {
+                // a scan sees a whole round, never half of one
+                let kvs = engine.scan(engine.scanner(vec![], -1, None)).unwrap().1;
+                if let Some((_, v)) = kvs.first() {
+                    assert_eq!(kvs.len(), KEYS as usize);
+                    assert!(kvs.iter().all(|(_, other)| other == v));
+                }
+                // the gets of a key never go back in time
+                let mut last = 0;
+                for _ in 0..10 {
+                    if let Some(v) = engine.get(&vec![0; 8]).unwrap() {
+                        let round = u16::from_be_bytes([v[0], v[1]]);
+                        assert!(round >= last);
+                        last = round;
+                    }
+                }
+                if last == ROUNDS {
+                    break;
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    writer.join().unwrap();
+    for reader in readers {
+        reader.join().unwrap();
+    }
+    assert_eq!(engine.stats().keys, KEYS as u64);
+}
+
+#[test]
+fn parallel_reads() {
+    // --- std ---
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
+    };
+
+    const N: u16 = 2000;
+    const TEST_DIR: &'static str = "tests/data/test-parallel-reads";
+
+    let _ = remove_dir_all(TEST_DIR);
+    let mut engine = new_hash_engine(
+        TEST_DIR,
+        Options {
+            file_size_limit: 0x4000,
+            keep_old_files: false,
+            durability: Durability::Buffered,
+            ..Default::default()
+        },
+    );
+    for i in 0..N {
+        engine.put(key(i), value(i)).unwrap();
+    }
+
+    // the readers share the file handles, the active one included, with the writer
+    let done = Arc::new(AtomicBool::new(false));
+    let readers = (0..4)
+        .map(|r| {
+            let (mut engine, done) = (engine.clone(), done.clone());
+            thread::spawn(move || {
+                let mut i = r;
+                while !done.load(Ordering::Relaxed) {
+                    i = (i + 7) % (N * 2);
+                    if let Some(v) = engine.get(&key(i)).unwrap() {
+                        assert_eq!(v, value(i));
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for i in N..N * 2 {
+        engine.put(key(i), value(i)).unwrap();
+    }
+    done.store(true, Ordering::Relaxed);
+    for reader in readers {
+        reader.join().unwrap();
+    }
+
+    for i in 0..N * 2 {
+        assert_eq!(engine.get(&key(i)).unwrap(), Some(value(i)));
+    }
+    engine.merge().unwrap();
+    for i in 0..N * 2 {
+        assert_eq!(engine.get(&key(i)).unwrap(), Some(value(i)));
+    }
+}
+
+#[test]
+fn mmap_sealed_files() {
+    const N: u16 = 500;
+    const TEST_DIR: &'static str = "tests/data/test-mmap-sealed-files";
+
+    fn new_engine() -> HashEngine {
+        new_hash_engine(
+            TEST_DIR,
+            Options {
+                file_size_limit: 0x1000,
+                keep_old_files: false,
+                durability: Durability::Buffered,
+                mmap_sealed_files: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    let _ = remove_dir_all(TEST_DIR);
+    let mut engine = new_engine();
+    for i in 0..N {
+        engine.put(key(i), value(i)).unwrap();
+    }
+    for i in (0..N).filter(|i| i % 2 == 0) {
+        engine.del(&key(i)).unwrap();
+    }
+
+    // sealed long ago, a slice of the mapping, the active file is read as usual
+    let mapped = engine.get_value(&key(1)).unwrap().unwrap();
+    match mapped {
+        Value::Mapped(..) => (),
+        Value::Owned(_) => panic!("a sealed file isn't mapped"),
+    }
+    assert_eq!(&mapped[..], &value(1)[..]);
+    engine.put(b"last".to_vec(), b"active".to_vec()).unwrap();
+    match engine.get_value(b"last").unwrap().unwrap() {
+        Value::Owned(v) => assert_eq!(v, b"active"),
+        Value::Mapped(..) => panic!("the active file is mapped"),
+    }
+    // so are the gets and scans of the server, the values are only copied into the responses
+    let mut server = Server::new(engine.clone());
+    match server.get_value(&key(1)).unwrap().unwrap() {
+        Value::Mapped(..) => (),
+        Value::Owned(_) => panic!("a sealed file isn't mapped"),
+    }
+    let scanner = server.scanner(vec![], -1, None);
+    for (k, v) in server.scan_values(scanner).unwrap().1 {
+        match v {
+            Value::Mapped(..) if k != b"last" => (),
+            Value::Owned(_) if k == b"last" => (),
+            _ => panic!("{:?} isn't read from where it's stored", k),
+        }
+    }
+    drop(server);
+
+    let check = |engine: &mut HashEngine| {
+        for i in 0..N {
+            let v = if i % 2 == 0 { None } else { Some(value(i)) };
+            assert_eq!(engine.get(&key(i)).unwrap(), v);
+        }
+        assert_eq!(
+            engine
+                .scan(engine.scanner(vec![], -1, None))
+                .unwrap()
+                .1
+                .len(),
+            N as usize / 2 + 1
+        );
+    };
+    check(&mut engine);
+
+    // the merged files are gone, the value held still reads from the mapping
+    engine.merge().unwrap();
+    assert_eq!(&mapped[..], &value(1)[..]);
+    assert_eq!(mapped.into_vec(), value(1));
+    check(&mut engine);
+    drop(engine);
+
+    check(&mut new_engine());
+}
+
+#[test]
+fn max_open_files() {
+    const N: u16 = 600;
+    const MAX_OPEN_FILES: u64 = 4;
+    const TEST_DIR: &'static str = "tests/data/test-max-open-files";
+
+    let _ = remove_dir_all(TEST_DIR);
+    let mut engine = new_hash_engine(
+        TEST_DIR,
+        Options {
+            file_size_limit: 0x1000,
+            keep_old_files: false,
+            durability: Durability::Buffered,
+            max_open_files: MAX_OPEN_FILES as _,
+            ..Default::default()
+        },
+    );
+    for i in 0..N {
+        engine.put(key(i), value(i)).unwrap();
+    }
+    assert!(read_dir(TEST_DIR).unwrap().count() as u64 > MAX_OPEN_FILES * 2);
+
+    // every file read in turn, twice, some have to be closed in between
+    for _ in 0..2 {
+        for i in 0..N {
+            assert_eq!(engine.get(&key(i)).unwrap(), Some(value(i)));
+        }
+    }
+    let stats = engine.stats();
+    assert!(stats.open_files <= MAX_OPEN_FILES);
+    assert!(stats.file_cache_evictions > 0);
+    assert!(stats.file_cache_hits > stats.file_cache_misses);
+    // the recent keys are in the active file, it's never closed
+    let hits = stats.file_cache_hits;
+    engine.get(&key(N - 1)).unwrap();
+    assert_eq!(engine.stats().file_cache_hits, hits + 1);
+
+    // the merged files are closed with the merge, the copies are opened on demand
+    for i in (0..N).filter(|i| i % 2 == 0) {
+        engine.del(&key(i)).unwrap();
+    }
+    engine.merge().unwrap();
+    assert_eq!(engine.stats().open_files, 0);
+    for i in 0..N {
+        let v = if i % 2 == 0 { None } else { Some(value(i)) };
+        assert_eq!(engine.get(&key(i)).unwrap(), v);
+    }
+    assert!(engine.stats().open_files <= MAX_OPEN_FILES);
+}
+
+#[test]
+fn selective_merge() {
+    // --- std ---
+    use std::{thread, time::Duration};
+
+    const TEST_DIR: &'static str = "tests/data/test-selective-merge";
+
+    fn new_engine() -> HashEngine {
+        new_hash_engine(
+            TEST_DIR,
+            Options {
+                // a file per record
+                file_size_limit: 1,
+                keep_old_files: false,
+                durability: Durability::Buffered,
+                ..Default::default()
+            },
+        )
+    }
+
+    let _ = remove_dir_all(TEST_DIR);
+    let mut engine = new_engine();
+    // the file the last record went to
+    let last_file = |engine: &HashEngine| engine.file_stats().unwrap().last().unwrap().file_id;
+
+    engine.put(b"k".to_vec(), b"z".to_vec()).unwrap();
+    engine.del(b"k").unwrap();
+    let tombstone = last_file(&engine);
+    engine.put(b"k".to_vec(), b"b".to_vec()).unwrap();
+    engine.put(b"x".to_vec(), b"1".to_vec()).unwrap();
+    let overwritten = last_file(&engine);
+    engine.put(b"x".to_vec(), b"2".to_vec()).unwrap();
+    engine.put(b"y".to_vec(), b"old".to_vec()).unwrap();
+    engine
+        .put_with_ttl(b"y".to_vec(), b"new".to_vec(), Duration::from_millis(10))
+        .unwrap();
+    let expired = last_file(&engine);
+    let mut run = vec![];
+    for i in 0..3u8 {
+        engine.put(vec![b'r', i], vec![i]).unwrap();
+        run.push(last_file(&engine));
+    }
+    engine.put(b"last".to_vec(), b"1".to_vec()).unwrap();
+    thread::sleep(Duration::from_millis(50));
+
+    let check = |engine: &mut HashEngine| {
+        // the tombstone stays older than the put of an unmerged file after it
+        assert_eq!(engine.get(b"k").unwrap(), Some(b"b".to_vec()));
+        assert_eq!(engine.get(b"x").unwrap(), Some(b"2".to_vec()));
+        // the expired record is replaced by a tombstone, the older value doesn't come back
+        assert_eq!(engine.get(b"y").unwrap(), None);
+        for i in 0..3u8 {
+            assert_eq!(engine.get(&[b'r', i]).unwrap(), Some(vec![i]));
+        }
+    };
+
+    // not contiguous, unmerged files in between
+    let mut file_ids = vec![tombstone, overwritten, expired];
+    file_ids.extend_from_slice(&run);
+    assert!(engine.merge_files(&file_ids, &mut |_| true).unwrap());
+    check(&mut engine);
+    drop(engine);
+    let mut engine = new_engine();
+    check(&mut engine);
+
+    // the copies of the run went to consecutive files, merging one of them alone still
+    // finds room for its copies, twice in a row
+    for _ in 0..2 {
+        let copies = engine
+            .file_stats()
+            .unwrap()
+            .into_iter()
+            .map(|file| file.file_id)
+            .filter(|file_id| *file_id > expired && *file_id < last_file(&engine))
+            .collect::<Vec<_>>();
+        let copy = copies[copies.len() - 2];
+        assert!(engine.merge_files(&[copy], &mut |_| true).unwrap());
+        check(&mut engine);
+        drop(engine);
+        engine = new_engine();
+        check(&mut engine);
+    }
+}
+
+#[test]
+fn data_file_scale_up() {
+    const N: usize = 200;
+    const TEST_DIR: &'static str = "tests/data/test-data-file-scale-up";
+
+    fn count_files() -> usize {
+        let mut count = 0;
+
+        for entry in read_dir(TEST_DIR).unwrap() {
+            let entry = entry.unwrap();
+            if let Some(extension) = entry.path().extension() {
+                if extension == "data" || extension == "hint" {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    let _ = remove_dir_all(TEST_DIR);
+
+    let mut result_1 = HashMap::new();
+    {
+        let mut server = Server::new(new_hash_engine(
+            TEST_DIR,
+            Options {
+                file_size_limit: 2,
+                keep_old_files: false,
+                ..Default::default()
+            },
+        ));
+
+        for i in 0..N {
+            server.put(vec![i as _], vec![i as _]).unwrap();
+        }
+        // the active file has no hint file until it's sealed
+        assert_eq!(count_files(), N * 2 - 1);
+
+        for i in 0..N {
+            let k = vec![i as _];
+            let v = server.get(&k).unwrap();
+            assert!(v.is_some());
+            let v = v.unwrap();
+            result_1.insert(k, v);
+        }
+    }
+
+    let mut result_2 = HashMap::new();
+    {
+        let mut server = Server::new(new_hash_engine(
+            TEST_DIR,
+            Options {
+                file_size_limit: 5 * 0x100000,
+                keep_old_files: false,
+                ..Default::default()
+            },
+        ));
+
+        server.merge().unwrap();
+        assert_eq!(count_files(), 2);
+
+        for i in 0..N {
+            let k = vec![i as _];
+            let v = server.get(&k).unwrap();
+            assert!(v.is_some());
+            let v = v.unwrap();
+            result_2.insert(k, v);
+        }
+    }
+
+    assert_eq!(result_1, result_2);
+}
+
+#[test]
+fn data_file_scale_down() {
+    const N: usize = 200;
+    const TEST_DIR: &'static str = "tests/data/test-data-file-scale-down";
+
+    fn count_files() -> usize {
+        let mut count = 0;
+
+        for entry in read_dir(TEST_DIR).unwrap() {
+            let entry = entry.unwrap();
+            if let Some(extension) = entry.path().extension() {
+                if extension == "data" || extension == "hint" {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    let _ = remove_dir_all(TEST_DIR);
+
+    let mut result_1 = HashMap::new();
+    {
+        let mut server = Server::new(new_hash_engine(
+            TEST_DIR,
+            Options {
+                file_size_limit: 5 * 0x100000,
+                keep_old_files: false,
+                ..Default::default()
+            },
+        ));
+
+        for i in 0..N {
+            server.put(vec![i as _], vec![i as _]).unwrap();
+        }
+        // the active file alone, without a hint file
+        assert_eq!(count_files(), 1);
+
+        for i in 0..N {
+            let k = vec![i as _];
+            let v = server.get(&k).unwrap();
+            assert!(v.is_some());
+            let v = v.unwrap();
+            result_1.insert(k, v);
+        }
+    }
+
+    let mut result_2 = HashMap::new();
+    {
+        let mut server = Server::new(new_hash_engine(
+            TEST_DIR,
+            Options {
+                file_size_limit: 2,
+                keep_old_files: false,
+                ..Default::default()
+            },
+        ));
+
+        server.merge().unwrap();
+        assert_eq!(count_files(), N * 2);
+
+        for i in 0..N {
+            let k = vec![i as _];
+            let v = server.get(&k).unwrap();
+            assert!(v.is_some());
+            let v = v.unwrap();
+            result_2.insert(k, v);
+        }
+    }
+
+    assert_eq!(result_1, result_2);
+}
+
+fn new_btree_server(dir: &str) -> Server<BTreeEngine> {
+    let _ = remove_dir_all(dir);
+
+    Server::new(BTreeEngineBuilder::new().storage_dir(dir).build().unwrap())
+}
+
+#[test]
+fn btree_put_get_del() {
+    const N: u16 = 1000;
+    const TEST_DIR: &'static str = "tests/data/test-btree-put-get-del";
+
+    let mut server = new_btree_server(TEST_DIR);
+
+    for i in 0..N {
+        server
+            .put(i.to_be_bytes().to_vec(), vec![i as u8; 256])
+            .unwrap();
+    }
+    for i in 0..N {
+        assert_eq!(
+            server.get(&i.to_be_bytes()).unwrap().unwrap(),
+            vec![i as u8; 256]
+        );
+    }
+    for i in (0..N).filter(|i| i % 2 == 0) {
+        server.del(&i.to_be_bytes()).unwrap();
+    }
+    for i in 0..N {
+        assert_eq!(server.get(&i.to_be_bytes()).unwrap().is_some(), i % 2 == 1);
+    }
+}
+
+#[test]
+fn btree_scan() {
+    const N: u16 = 1000;
+    const TEST_DIR: &'static str = "tests/data/test-btree-scan";
+
+    let mut server = new_btree_server(TEST_DIR);
+
+    for i in (0..N).rev() {
+        server
+            .put(i.to_be_bytes().to_vec(), vec![i as u8; 256])
+            .unwrap();
+    }
+
+    let scanner = Scanner::BTreeScanner(BTreeScanner {
+        cursor: vec![],
+        range: 20,
+        regex: None,
+    });
+    let kvs = server.scan(scanner).unwrap().1;
+    assert_eq!(
+        kvs.into_iter().map(|(k, _)| k).collect::<Vec<_>>(),
+        (0..20u16)
+            .map(|i| i.to_be_bytes().to_vec())
+            .collect::<Vec<_>>()
+    );
+
+    let scanner = Scanner::BTreeScanner(BTreeScanner {
+        cursor: vec![],
+        range: -1,
+        regex: None,
+    });
+    let kvs = server.scan(scanner).unwrap().1;
+    assert_eq!(kvs.len(), N as usize);
+    assert!(kvs.windows(2).all(|kvs| kvs[0].0 < kvs[1].0));
+
+    let scanner = Scanner::HashScanner(HashScanner {
+        cursor: vec![],
+        range: -1,
+        regex: None,
+    });
+    assert!(server.scan(scanner).is_err());
+}
+
+#[test]
+fn btree_recover_and_merge() {
+    const N: u16 = 1000;
+    const TEST_DIR: &'static str = "tests/data/test-btree-recover-and-merge";
+
+    {
+        let mut server = new_btree_server(TEST_DIR);
+
+        for i in 0..N {
+            server
+                .put(i.to_be_bytes().to_vec(), vec![i as u8; 256])
+                .unwrap();
+        }
+        for i in 0..N / 2 {
+            server.del(&i.to_be_bytes()).unwrap();
+        }
+    }
+
+    let mut server = Server::new(
+        BTreeEngineBuilder::new()
+            .storage_dir(TEST_DIR)
+            .build()
+            .unwrap(),
+    );
+    let db_size = || {
+        std::fs::metadata(format!("{}/btree.db", TEST_DIR))
+            .unwrap()
+            .len()
+    };
+    let size = db_size();
+
+    server.merge().unwrap();
+    assert!(db_size() < size);
+
+    for i in 0..N {
+        assert_eq!(server.get(&i.to_be_bytes()).unwrap().is_some(), i >= N / 2);
+    }
+
+    drop(server);
+
+    // the writes made while the tree is rebuilt aren't lost with the swap
+    let mut engine = BTreeEngineBuilder::new()
+        .storage_dir(TEST_DIR)
+        .build()
+        .unwrap();
+    let mut writer = engine.clone();
+    assert!(engine
+        .merge_with_progress(&mut |_| {
+            writer.put(b"during".to_vec(), b"merge".to_vec()).unwrap();
+            writer.del(&(N - 1).to_be_bytes()).unwrap();
+            assert_eq!(writer.get(b"during").unwrap(), Some(b"merge".to_vec()));
+            true
+        })
+        .unwrap());
+    assert_eq!(engine.get(b"during").unwrap(), Some(b"merge".to_vec()));
+    assert_eq!(engine.get(&(N - 1).to_be_bytes()).unwrap(), None);
+    assert!(engine.get(&(N - 2).to_be_bytes()).unwrap().is_some());
+}
+
+#[test]
+fn btree_overflow() {
+    // --- custom ---
+    use kv_server::btree::{BTreeEngineError, Options};
+
+    const N: u16 = 100;
+    const TEST_DIR: &'static str = "tests/data/test-btree-overflow";
+
+    let options = |page_size| Options {
+        page_size,
+        ..Default::default()
+    };
+    let new_engine = |page_size| {
+        BTreeEngineBuilder::new()
+            .storage_dir(TEST_DIR)
+            .options(options(page_size))
+            .build()
+    };
+    let _ = remove_dir_all(TEST_DIR);
+    for page_size in [0, 64, 1000, 4095].iter() {
+        match new_engine(*page_size).err().unwrap().downcast() {
+            Ok(BTreeEngineError::InvalidPageSize { .. }) => (),
+            e => panic!("unexpected: {:?}", e),
+        }
+    }
+
+    // values from a few bytes to many pages, next to the inline ones
+    let large = |i: u16| vec![i as u8; i as usize * 97];
+    {
+        let mut engine = new_engine(512).unwrap();
+        for i in 0..N {
+            engine.put(key(i), large(i)).unwrap();
+        }
+        for i in (0..N).filter(|i| i % 3 == 0) {
+            engine.put(key(i), value(i)).unwrap();
+        }
+        for i in (0..N).filter(|i| i % 3 == 1) {
+            engine.del(&key(i)).unwrap();
+        }
+        match engine.put(vec![0; 512], vec![]).err().unwrap().downcast() {
+            Ok(BTreeEngineError::KeyTooLarge { .. }) => (),
+            e => panic!("unexpected: {:?}", e),
+        }
+    }
+
+    let mut engine = new_engine(512).unwrap();
+    let check = |engine: &mut BTreeEngine| {
+        for i in 0..N {
+            let v = match i % 3 {
+                0 => Some(value(i)),
+                1 => None,
+                _ => Some(large(i)),
+            };
+            assert_eq!(engine.get(&key(i)).unwrap(), v);
+        }
+    };
+    check(&mut engine);
+    engine.merge().unwrap();
+    check(&mut engine);
+
+    // the pages replaced by the overwrites are reused
+    let db_size = || {
+        std::fs::metadata(format!("{}/btree.db", TEST_DIR))
+            .unwrap()
+            .len()
+    };
+    let size = db_size();
+    for _ in 0..10 {
+        for i in 0..N {
+            engine.put(key(i), large(i)).unwrap();
+        }
+    }
+    let size = db_size().max(size);
+    for _ in 0..10 {
+        for i in 0..N {
+            engine.put(key(i), large(i)).unwrap();
+        }
+    }
+    assert_eq!(db_size(), size);
+    for i in 0..N {
+        assert_eq!(engine.get(&key(i)).unwrap(), Some(large(i)));
+    }
+}
+
+fn new_lsm_tree_engine(dir: &str) -> LSMTreeEngine {
+    LSMTreeEngineBuilder::new()
+        .storage_dir(dir)
+        .options(lsm_tree::Options {
+            memtable_size: 0x1000,
+            table_size: 0x2000,
+            index_interval: 4,
+            level0_limit: 2,
+            level_size_base: 0x4000,
+            level_size_multiplier: 4,
+        })
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn lsm_tree_put_get_del() {
+    const N: u16 = 1000;
+    const TEST_DIR: &'static str = "tests/data/test-lsm-tree-put-get-del";
+
+    let _ = remove_dir_all(TEST_DIR);
+
+    {
+        let mut server = Server::new(new_lsm_tree_engine(TEST_DIR));
+
+        for i in 0..N {
+            server
+                .put(i.to_be_bytes().to_vec(), vec![i as u8; 64])
+                .unwrap();
+        }
+        for i in (0..N).filter(|i| i % 3 == 0) {
+            server.del(&i.to_be_bytes()).unwrap();
+        }
+        for i in 0..N {
+            assert_eq!(server.get(&i.to_be_bytes()).unwrap().is_some(), i % 3 != 0);
+        }
+    }
+
+    // recover the memtable from the write-ahead log
+    let mut server = Server::new(new_lsm_tree_engine(TEST_DIR));
+    for i in 0..N {
+        let v = server.get(&i.to_be_bytes()).unwrap();
+        if i % 3 == 0 {
+            assert_eq!(v, None);
+        } else {
+            assert_eq!(v.unwrap(), vec![i as u8; 64]);
+        }
+    }
+}
+
+#[test]
+fn lsm_tree_scan_and_merge() {
+    const N: u16 = 1000;
+    const TEST_DIR: &'static str = "tests/data/test-lsm-tree-scan-and-merge";
+
+    let _ = remove_dir_all(TEST_DIR);
+
+    let mut server = Server::new(new_lsm_tree_engine(TEST_DIR));
+
+    for i in (0..N).rev() {
+        server
+            .put(i.to_be_bytes().to_vec(), vec![i as u8; 64])
+            .unwrap();
+    }
+    for i in 0..N / 2 {
+        server.del(&i.to_be_bytes()).unwrap();
+    }
+
+    let scanner = Scanner::LSMTreeScanner(LSMTreeScanner {
+        cursor: vec![],
+        range: -1,
+        regex: None,
+    });
+    let kvs = server.scan(scanner).unwrap().1;
+    assert_eq!(
+        kvs.into_iter().map(|(k, _)| k).collect::<Vec<_>>(),
+        (N / 2..N)
+            .map(|i| i.to_be_bytes().to_vec())
+            .collect::<Vec<_>>()
+    );
+
+    server.merge().unwrap();
+
+    let scanner = Scanner::LSMTreeScanner(LSMTreeScanner {
+        cursor: vec![],
+        range: 10,
         regex: None,
     });
     let kvs = server.scan(scanner).unwrap().1;
-    assert_eq!(kvs.len(), 0);
+    assert_eq!(kvs.len(), 10);
+    assert_eq!(kvs[0].0, (N / 2).to_be_bytes().to_vec());
+    for i in 0..N {
+        assert_eq!(server.get(&i.to_be_bytes()).unwrap().is_some(), i >= N / 2);
+    }
 }
 
 #[test]
-fn merge() {
-    const N: u8 = 5;
-
-    let mut server = new_server(Options {
-        file_size_limit: 200,
-        keep_old_files: true,
-        merge_policy: MergePolicy::Test,
-    });
+fn range_scan() {
+    const N: u16 = 500;
 
-    for i in 0..N {
-        server.put(vec![i; 8], vec![i; 256]).unwrap();
-    }
-    for i in 0..N {
-        server.put(vec![i; 8], vec![i; 256]).unwrap();
-    }
+    fn check<E: Engine>(mut server: Server<E>) {
+        let keys =
+            |kvs: Vec<(Vec<u8>, Vec<u8>)>| kvs.into_iter().map(|(k, _)| k).collect::<Vec<_>>();
 
-    server.merge().unwrap();
+        for i in (0..N).rev() {
+            server.put(key(i), vec![i as u8; 64]).unwrap();
+        }
 
-    for i in 0..N {
-        //        assert!(server.get(&vec![i; 8]).is_ok());
-        assert_eq!(server.get(&vec![i; 8]).unwrap().unwrap(), vec![i; 256]);
+        let scanner = Scanner::RangeScanner(RangeScanner {
+            start: Bound::Included(key(100)),
+            end: Bound::Excluded(key(200)),
+            order: Order::Asc,
+            limit: 60,
+            regex: None,
+        });
+        let (scanner, kvs) = server.scan(scanner).unwrap();
+        assert_eq!(keys(kvs), (100..160).map(key).collect::<Vec<_>>());
+
+        // resume from the returned scanner
+        let scanner = match scanner {
+            Scanner::RangeScanner(mut scanner) => {
+                scanner.limit = 60;
+                Scanner::RangeScanner(scanner)
+            }
+            _ => unreachable!(),
+        };
+        let kvs = server.scan(scanner).unwrap().1;
+        assert_eq!(keys(kvs), (160..200).map(key).collect::<Vec<_>>());
+
+        let scanner = Scanner::RangeScanner(RangeScanner {
+            start: Bound::Excluded(key(100)),
+            end: Bound::Included(key(200)),
+            order: Order::Desc,
+            limit: -1,
+            regex: None,
+        });
+        let kvs = server.scan(scanner).unwrap().1;
+        assert_eq!(keys(kvs), (101..=200).rev().map(key).collect::<Vec<_>>());
+
+        let scanner = Scanner::RangeScanner(RangeScanner {
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+            order: Order::Desc,
+            limit: 3,
+            regex: None,
+        });
+        let kvs = server.scan(scanner).unwrap().1;
+        assert_eq!(keys(kvs), vec![key(N - 1), key(N - 2), key(N - 3)]);
     }
 
-    assert_eq!(
-        read_dir(STORAGE_DIR)
-            .unwrap()
-            .into_iter()
-            .map(|e| e.unwrap())
-            .filter(|e| if let Some(extension) = e.path().extension() {
-                if extension == "data" || extension == "hint" {
-                    true
-                } else {
-                    false
-                }
-            } else {
-                false
-            })
-            .count(),
-        N as usize * 2
-    );
+    const TEST_DIR: &'static str = "tests/data/test-range-scan";
+
+    check(new_btree_server(&format!("{}-btree", TEST_DIR)));
+    let _ = remove_dir_all(format!("{}-hash", TEST_DIR));
+    check(Server::new(
+        HashEngineBuilder::new()
+            .storage_dir(&format!("{}-hash", TEST_DIR))
+            .build()
+            .unwrap(),
+    ));
+    let _ = remove_dir_all(format!("{}-lsm-tree", TEST_DIR));
+    check(Server::new(new_lsm_tree_engine(&format!(
+        "{}-lsm-tree",
+        TEST_DIR
+    ))));
 }
 
 #[test]
-fn data_file_scale_up() {
-    const N: usize = 200;
-    const TEST_DIR: &'static str = "tests/data/test-data-file-scale-up";
+fn scan_cursor() {
+    const N: u16 = 300;
 
-    fn count_files() -> usize {
-        let mut count = 0;
+    fn check<E: Engine>(mut server: Server<E>) {
+        // --- std ---
+        use std::collections::HashSet;
 
-        for entry in read_dir(TEST_DIR).unwrap() {
-            let entry = entry.unwrap();
-            if let Some(extension) = entry.path().extension() {
-                if extension == "data" || extension == "hint" {
-                    count += 1;
+        for i in 0..N {
+            server.put(key(i), vec![i as u8; 64]).unwrap();
+        }
+        server.put(vec![], b"empty".to_vec()).unwrap();
+
+        let mut seen = HashSet::new();
+        let mut cursor = vec![];
+        let mut rounds = 0;
+        loop {
+            // the first round of an ordered engine ends on the empty key, it's not the last one
+            let range = if rounds == 0 { 1 } else { 7 };
+            let scanner = server.scanner(cursor, range, None);
+            let (scanner, kvs) = server.scan(scanner).unwrap();
+            seen.extend(kvs.into_iter().map(|(k, _)| k));
+            cursor = scanner.cursor().to_vec();
+            rounds += 1;
+
+            // grow the key dirs in the middle of the scan
+            if rounds == 5 {
+                for i in N..N * 4 {
+                    server.put(key(i), vec![i as u8; 64]).unwrap();
                 }
             }
+            if cursor.is_empty() {
+                break;
+            }
         }
 
-        count
+        assert!(rounds > 5);
+        assert!(seen.contains(&vec![]));
+        for i in 0..N {
+            assert!(seen.contains(&key(i)));
+        }
     }
 
-    let _ = remove_dir_all(TEST_DIR);
+    const TEST_DIR: &'static str = "tests/data/test-scan-cursor";
 
-    let mut result_1 = HashMap::new();
-    {
-        let mut server = Server::new(
-            HashEngineBuilder::new()
-                .storage_dir(TEST_DIR)
-                .options(Options {
-                    file_size_limit: 2,
-                    keep_old_files: false,
-                    merge_policy: MergePolicy::Test,
-                })
-                .build()
-                .unwrap(),
-        );
+    check(new_btree_server(&format!("{}-btree", TEST_DIR)));
+    let _ = remove_dir_all(format!("{}-hash", TEST_DIR));
+    let server = Server::new(
+        HashEngineBuilder::new()
+            .storage_dir(&format!("{}-hash", TEST_DIR))
+            .build()
+            .unwrap(),
+    );
+    let scanner = server.scanner(vec![0; 3], 7, None);
+    assert!(server.clone().scan(scanner).is_err());
+    check(server);
+    let _ = remove_dir_all(format!("{}-lsm-tree", TEST_DIR));
+    check(Server::new(new_lsm_tree_engine(&format!(
+        "{}-lsm-tree",
+        TEST_DIR
+    ))));
+}
 
-        for i in 0..N {
-            server.put(vec![i as _], vec![i as _]).unwrap();
+#[test]
+fn write_batch() {
+    const N: u8 = 50;
+
+    fn batch(range: std::ops::Range<u8>, del: u8) -> WriteBatch {
+        let mut batch = WriteBatch::new();
+        for i in range {
+            batch.put(vec![i; 8], vec![i; 64]);
         }
-        assert_eq!(count_files(), N * 2);
+        batch.del(vec![del; 8]);
+
+        batch
+    }
 
+    fn check<E: Engine>(mut server: Server<E>) {
+        server.write_batch(batch(0..N, 0)).unwrap();
         for i in 0..N {
-            let k = vec![i as _];
-            let v = server.get(&k).unwrap();
-            assert!(v.is_some());
-            let v = v.unwrap();
-            result_1.insert(k, v);
+            assert_eq!(server.get(&vec![i; 8]).unwrap().is_some(), i != 0);
         }
     }
 
-    let mut result_2 = HashMap::new();
+    const TEST_DIR: &'static str = "tests/data/test-write-batch";
+
+    check(new_btree_server(&format!("{}-btree", TEST_DIR)));
+    let _ = remove_dir_all(format!("{}-lsm-tree", TEST_DIR));
+    check(Server::new(new_lsm_tree_engine(&format!(
+        "{}-lsm-tree",
+        TEST_DIR
+    ))));
+
+    // a batch torn by a crash is rolled back as a whole
+    let hash_dir = format!("{}-hash", TEST_DIR);
+    let new_engine = || {
+        new_hash_engine(
+            &hash_dir,
+            Options {
+                file_size_limit: 5 * 0x100000,
+                keep_old_files: false,
+                truncate_torn_tail: true,
+                ..Default::default()
+            },
+        )
+    };
+    let _ = remove_dir_all(&hash_dir);
+    check(Server::new(new_engine()));
     {
-        let mut server = Server::new(
-            HashEngineBuilder::new()
-                .storage_dir(TEST_DIR)
-                .options(Options {
-                    file_size_limit: 5 * 0x100000,
-                    keep_old_files: false,
-                    merge_policy: MergePolicy::Test,
-                })
-                .build()
-                .unwrap(),
-        );
+        // --- std ---
+        use std::fs::OpenOptions;
 
-        server.merge().unwrap();
-        assert_eq!(count_files(), 2);
+        let mut engine = new_engine();
+        engine.write_batch(batch(N..N * 2, 1)).unwrap();
 
-        for i in 0..N {
-            let k = vec![i as _];
-            let v = server.get(&k).unwrap();
-            assert!(v.is_some());
-            let v = v.unwrap();
-            result_2.insert(k, v);
-        }
+        let path = read_dir(&hash_dir)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .filter(|path| path.extension().map_or(false, |e| e == "data"))
+            .max_by_key(|path| path.file_stem().unwrap().to_owned())
+            .unwrap();
+        let file = OpenOptions::new().write(true).open(path).unwrap();
+        let len = file.metadata().unwrap().len();
+        file.set_len(len - 10).unwrap();
     }
 
-    assert_eq!(result_1, result_2);
+    let mut engine = new_engine();
+    for i in 0..N * 2 {
+        assert_eq!(engine.get(&vec![i; 8]).unwrap().is_some(), i != 0 && i < N);
+    }
 }
 
 #[test]
-fn data_file_scale_down() {
-    const N: usize = 200;
-    const TEST_DIR: &'static str = "tests/data/test-data-file-scale-down";
+fn durability() {
+    // --- std ---
+    use std::{thread, time::Duration};
+
+    const TEST_DIR: &'static str = "tests/data/test-durability";
+    const THREADS: u8 = 8;
+    const N: u8 = 20;
+
+    fn check(durability: Durability) -> Server<HashEngine> {
+        let new_server = || {
+            Server::new(new_hash_engine(
+                TEST_DIR,
+                Options {
+                    file_size_limit: 1024,
+                    keep_old_files: false,
+                    durability,
+                    ..Default::default()
+                },
+            ))
+        };
 
-    fn count_files() -> usize {
-        let mut count = 0;
+        let _ = remove_dir_all(TEST_DIR);
+        let server = new_server();
 
-        for entry in read_dir(TEST_DIR).unwrap() {
-            let entry = entry.unwrap();
-            if let Some(extension) = entry.path().extension() {
-                if extension == "data" || extension == "hint" {
-                    count += 1;
-                }
-            }
+        // concurrent writers share the active file through the clones
+        let handles = (0..THREADS)
+            .map(|t| {
+                let mut server = server.clone();
+                thread::spawn(move || {
+                    for i in 0..N {
+                        server.put(vec![t, i], vec![t; 64]).unwrap();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
         }
 
-        count
-    }
-
-    let _ = remove_dir_all(TEST_DIR);
-
-    let mut result_1 = HashMap::new();
-    {
-        let mut server = Server::new(
-            HashEngineBuilder::new()
-                .storage_dir(TEST_DIR)
-                .options(Options {
-                    file_size_limit: 5 * 0x100000,
-                    keep_old_files: false,
-                    merge_policy: MergePolicy::Test,
-                })
-                .build()
-                .unwrap(),
-        );
+        let stats = server.stats();
+        assert_eq!(stats.engine, "hash");
+        assert_eq!(stats.durability, durability.to_string());
+        assert_eq!(stats.writes, THREADS as u64 * N as u64);
 
-        for i in 0..N {
-            server.put(vec![i as _], vec![i as _]).unwrap();
+        let mut reopened = new_server();
+        for t in 0..THREADS {
+            for i in 0..N {
+                assert_eq!(reopened.get(&[t, i]).unwrap(), Some(vec![t; 64]));
+            }
         }
-        assert_eq!(count_files(), 2);
 
-        for i in 0..N {
-            let k = vec![i as _];
-            let v = server.get(&k).unwrap();
-            assert!(v.is_some());
-            let v = v.unwrap();
-            result_1.insert(k, v);
-        }
+        server
     }
 
-    let mut result_2 = HashMap::new();
-    {
-        let mut server = Server::new(
-            HashEngineBuilder::new()
-                .storage_dir(TEST_DIR)
-                .options(Options {
-                    file_size_limit: 2,
-                    keep_old_files: false,
-                    merge_policy: MergePolicy::Test,
+    let stats = check(Durability::Sync).stats();
+    assert!(stats.syncs >= stats.writes);
+
+    let stats = check(Durability::GroupCommit {
+        window: Duration::from_millis(5),
+    })
+    .stats();
+    assert!(stats.syncs > 0 && stats.syncs < stats.writes);
+
+    let interval = Duration::from_millis(5);
+    let server = check(Durability::Periodic { interval });
+    thread::sleep(interval * 4);
+    assert!(server.stats().syncs > 0);
+
+    // only the rotated files are synced, before their hints, the last one got its hint
+    // when it was reopened
+    let syncs = check(Durability::Buffered).stats().syncs;
+    let hints = read_dir(TEST_DIR)
+        .unwrap()
+        .filter(|e| {
+            e.as_ref()
+                .unwrap()
+                .path()
+                .extension()
+                .map_or(false, |e| e == "hint")
+        })
+        .count();
+    assert_eq!(syncs, hints as u64 - 1);
+}
+
+#[test]
+fn transaction() {
+    // --- std ---
+    use std::thread;
+
+    const TEST_DIR: &'static str = "tests/data/test-transaction";
+    const THREADS: u64 = 4;
+    const N: u64 = 25;
+
+    fn check<E: Engine + Clone + Send + 'static>(mut server: Server<E>) {
+        // absent
+        assert!(!server
+            .compare_and_swap(b"k".to_vec(), Some(b"1".to_vec()), Some(b"2".to_vec()))
+            .unwrap());
+        assert!(server
+            .compare_and_swap(b"k".to_vec(), None, Some(b"1".to_vec()))
+            .unwrap());
+        assert!(!server
+            .compare_and_swap(b"k".to_vec(), None, Some(b"2".to_vec()))
+            .unwrap());
+        assert!(server
+            .compare_and_swap(b"k".to_vec(), Some(b"1".to_vec()), None)
+            .unwrap());
+        assert_eq!(server.get(b"k").unwrap(), None);
+
+        // a counter incremented concurrently never loses an increment
+        server
+            .put(b"counter".to_vec(), 0u64.to_le_bytes().to_vec())
+            .unwrap();
+        let handles = (0..THREADS)
+            .map(|_| {
+                let mut server = server.clone();
+                thread::spawn(move || {
+                    for _ in 0..N {
+                        loop {
+                            let v = server.get(b"counter").unwrap().unwrap();
+                            let mut counter = [0; 8];
+                            counter.copy_from_slice(&v);
+                            let counter = u64::from_le_bytes(counter) + 1;
+                            if server
+                                .compare_and_swap(
+                                    b"counter".to_vec(),
+                                    Some(v),
+                                    Some(counter.to_le_bytes().to_vec()),
+                                )
+                                .unwrap()
+                            {
+                                break;
+                            }
+                        }
+                    }
                 })
-                .build()
-                .unwrap(),
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(
+            server.get(b"counter").unwrap(),
+            Some((THREADS * N).to_le_bytes().to_vec())
         );
 
-        server.merge().unwrap();
-        assert_eq!(count_files(), N * 2);
-
-        for i in 0..N {
-            let k = vec![i as _];
-            let v = server.get(&k).unwrap();
-            assert!(v.is_some());
-            let v = v.unwrap();
-            result_2.insert(k, v);
-        }
+        // a transaction whose reads changed writes nothing
+        server.put(b"a".to_vec(), b"1".to_vec()).unwrap();
+        let mut transaction = Transaction::new();
+        transaction.read(b"a".to_vec(), server.get(b"a").unwrap());
+        transaction.read(b"b".to_vec(), server.get(b"b").unwrap());
+        transaction.put(b"a".to_vec(), b"2".to_vec());
+        transaction.put(b"b".to_vec(), b"2".to_vec());
+        server.put(b"b".to_vec(), b"1".to_vec()).unwrap();
+        assert!(!server.commit(transaction.clone()).unwrap());
+        assert_eq!(server.get(b"a").unwrap(), Some(b"1".to_vec()));
+        assert_eq!(server.get(b"b").unwrap(), Some(b"1".to_vec()));
+
+        server.del(b"b").unwrap();
+        assert!(server.commit(transaction).unwrap());
+        assert_eq!(server.get(b"a").unwrap(), Some(b"2".to_vec()));
+        assert_eq!(server.get(b"b").unwrap(), Some(b"2".to_vec()));
     }
 
-    assert_eq!(result_1, result_2);
+    check(new_btree_server(&format!("{}-btree", TEST_DIR)));
+    let _ = remove_dir_all(format!("{}-lsm-tree", TEST_DIR));
+    check(Server::new(new_lsm_tree_engine(&format!(
+        "{}-lsm-tree",
+        TEST_DIR
+    ))));
+    let hash_dir = format!("{}-hash", TEST_DIR);
+    let _ = remove_dir_all(&hash_dir);
+    check(Server::new(
+        HashEngineBuilder::new()
+            .storage_dir(&hash_dir)
+            .build()
+            .unwrap(),
+    ));
 }
 
 fn new_client() -> KvServerClient {
@@ -342,12 +2415,72 @@ fn client() {
             }
             "scan" => {
                 let mut scan_request = ScanRequest::new();
-                scan_request.set_range(s[1].parse().unwrap());
-                if s.len() > 2 {
-                    scan_request.set_regex(s[2].to_owned());
+                // `-` to start a new scan
+                if s[1] != "-" {
+                    scan_request.set_cursor(
+                        (0..s[1].len())
+                            .step_by(2)
+                            .map(|i| u8::from_str_radix(&s[1][i..i + 2], 16).unwrap())
+                            .collect(),
+                    );
+                }
+                scan_request.set_range(s[2].parse().unwrap());
+                if s.len() > 3 {
+                    scan_request.set_regex(s[3].to_owned());
                 }
+                let mut cursor = vec![];
 
                 let mut buffer = vec![client.scan(&scan_request).unwrap()];
+                loop {
+                    let f = buffer.pop().unwrap().into_future();
+                    match f.wait() {
+                        Ok((Some(scan_response), next)) => {
+                            buffer.push(next);
+                            println!(
+                                "> {:?}, K: {}, V: {}",
+                                scan_response.status, scan_response.key, scan_response.value
+                            );
+                            cursor = scan_response.cursor;
+                        }
+                        Ok((None, _)) => break,
+                        Err(_) => (),
+                    }
+                }
+                if cursor.is_empty() {
+                    println!("> Cursor: -");
+                } else {
+                    println!(
+                        "> Cursor: {}",
+                        cursor
+                            .iter()
+                            .map(|b| format!("{:02x}", b))
+                            .collect::<String>()
+                    );
+                }
+
+                continue;
+            }
+            "rscan" => {
+                let bound = |s: &str, inclusive| {
+                    let mut key_bound = KeyBound::new();
+                    key_bound.set_key(s.to_owned());
+                    key_bound.set_inclusive(inclusive);
+                    key_bound
+                };
+
+                let mut range_scan_request = RangeScanRequest::new();
+                if s[1] != "-" {
+                    range_scan_request.set_start(bound(s[1], true));
+                }
+                if s[2] != "-" {
+                    range_scan_request.set_end(bound(s[2], false));
+                }
+                range_scan_request.set_limit(s[3].parse().unwrap());
+                if s.len() > 4 && s[4] == "desc" {
+                    range_scan_request.set_order(kv_server::protos::kv_server::Order::DESC);
+                }
+
+                let mut buffer = vec![client.range_scan(&range_scan_request).unwrap()];
                 loop {
                     let f = buffer.pop().unwrap().into_future();
                     match f.wait() {
@@ -448,3 +2581,159 @@ fn del_with_client() {
         assert_eq!(response.status, Status::NotFound);
     }
 }
+
+#[test]
+fn typed_rpcs_with_client() {
+    // --- custom ---
+    use kv_server::protos::kv_server::{DeleteRequest, GetRequest, PutRequest};
+
+    let client = new_client();
+
+    let mut request = PutRequest::new();
+    request.set_key("typed-v1".to_string());
+    request.set_value("1".to_string());
+    request.set_return_previous(true);
+    client.put(&request).unwrap();
+    request.set_value("2".to_string());
+    let response = client.put(&request).unwrap();
+    assert_eq!(response.status, Status::OK);
+    assert!(response.existed);
+    assert_eq!(response.previous_value, "1");
+
+    let mut request = GetRequest::new();
+    request.set_key("typed-v1".to_string());
+    let response = client.get(&request).unwrap();
+    assert!(response.found);
+    assert_eq!(response.value, "2");
+
+    let mut request = DeleteRequest::new();
+    request.set_key("typed-v1".to_string());
+    request.set_return_previous(true);
+    let response = client.delete(&request).unwrap();
+    assert!(response.existed);
+    assert_eq!(response.previous_value, "2");
+    let response = client.delete(&request).unwrap();
+    assert_eq!(response.status, Status::OK);
+    assert!(!response.existed);
+}
+
+#[test]
+fn binary_with_client_v2() {
+    // --- external ---
+    use grpcio::{ChannelBuilder, EnvBuilder};
+    // --- custom ---
+    use kv_server::protos::{kv_server_v2, kv_server_v2_grpc::KvServerClient};
+
+    const N: u8 = 200;
+
+    let client = KvServerClient::new(
+        ChannelBuilder::new(Arc::new(EnvBuilder::new().build())).connect("127.0.0.1:23333"),
+    );
+    // not valid UTF-8
+    let key = |i: u8| vec![0xff, 0xfe, i];
+    let value = |i: u8| vec![0xc0, i, 0x00, 0x80];
+
+    for i in 0..N {
+        let mut request = kv_server_v2::Request::new();
+        request.set_operation(Operation::PUT);
+        request.set_key(key(i));
+        request.set_value(value(i));
+
+        let response = client.serve(&request).unwrap();
+        assert_eq!(response.status, Status::OK);
+    }
+
+    for i in 0..N {
+        let mut request = kv_server_v2::Request::new();
+        request.set_operation(Operation::GET);
+        request.set_key(key(i));
+
+        let response = client.serve(&request).unwrap();
+        assert_eq!(response.status, Status::OK);
+        assert_eq!(response.value, value(i));
+    }
+}
+
+#[test]
+fn typed_rpcs_with_client_v2() {
+    // --- external ---
+    use grpcio::{ChannelBuilder, EnvBuilder};
+    // --- custom ---
+    use kv_server::protos::{kv_server_v2, kv_server_v2_grpc::KvServerClient};
+
+    let client = KvServerClient::new(
+        ChannelBuilder::new(Arc::new(EnvBuilder::new().build())).connect("127.0.0.1:23333"),
+    );
+
+    let mut request = kv_server_v2::PutRequest::new();
+    request.set_key(b"typed".to_vec());
+    request.set_value(b"1".to_vec());
+    request.set_return_previous(true);
+    client.put(&request).unwrap();
+    request.set_value(b"2".to_vec());
+    let response = client.put(&request).unwrap();
+    assert_eq!(response.status, Status::OK);
+    assert!(response.existed);
+    assert_eq!(response.previous_value, b"1");
+
+    let mut request = kv_server_v2::GetRequest::new();
+    request.set_key(b"typed".to_vec());
+    let response = client.get(&request).unwrap();
+    assert!(response.found);
+    assert_eq!(response.value, b"2");
+
+    let mut request = kv_server_v2::DeleteRequest::new();
+    request.set_key(b"typed".to_vec());
+    request.set_return_previous(true);
+    let response = client.delete(&request).unwrap();
+    assert!(response.existed);
+    assert_eq!(response.previous_value, b"2");
+    let response = client.delete(&request).unwrap();
+    assert_eq!(response.status, Status::OK);
+    assert!(!response.existed);
+
+    let mut request = kv_server_v2::GetRequest::new();
+    request.set_key(b"typed".to_vec());
+    let response = client.get(&request).unwrap();
+    assert_eq!(response.status, Status::OK);
+    assert!(!response.found);
+}
+
+#[test]
+fn transaction_with_client_v2() {
+    // --- external ---
+    use grpcio::{ChannelBuilder, EnvBuilder};
+    // --- custom ---
+    use kv_server::protos::{kv_server_v2, kv_server_v2_grpc::KvServerClient};
+
+    let client = KvServerClient::new(
+        ChannelBuilder::new(Arc::new(EnvBuilder::new().build())).connect("127.0.0.1:23333"),
+    );
+
+    let mut request = kv_server_v2::DeleteRequest::new();
+    request.set_key(b"cas".to_vec());
+    client.delete(&request).unwrap();
+
+    let mut request = kv_server_v2::CompareAndSwapRequest::new();
+    request.set_key(b"cas".to_vec());
+    request.set_expect_absent(true);
+    request.set_value(b"1".to_vec());
+    let response = client.compare_and_swap(&request).unwrap();
+    assert_eq!(response.status, Status::OK);
+    assert!(response.swapped);
+    assert!(!client.compare_and_swap(&request).unwrap().swapped);
+
+    let mut read = kv_server_v2::TransactionRead::new();
+    read.set_key(b"cas".to_vec());
+    read.set_value(b"1".to_vec());
+    let mut write = kv_server_v2::BatchWrite::new();
+    write.set_key(b"cas".to_vec());
+    write.set_delete(true);
+    let mut request = kv_server_v2::CommitRequest::new();
+    request.mut_reads().push(read);
+    request.mut_writes().push(write);
+    let response = client.commit(&request).unwrap();
+    assert_eq!(response.status, Status::OK);
+    assert!(response.committed);
+    assert!(!client.commit(&request).unwrap().committed);
+}